@@ -0,0 +1,86 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Compares `sort` with `--threads 1` against more threads, on a corpus of
+//! `generate`d versions, checking the outputs are identical.
+//!
+//! Run with `cargo bench --bench parallel`. The corpus size can be set with
+//! `SEM_TOOL_BENCH_VERSIONS` (default 500000).
+use std::fs::File;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+const BIN: &str = env!("CARGO_BIN_EXE_sem-tool");
+const RUNS: usize = 3;
+
+fn generate(count: usize) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("sem-tool-bench-{count}.txt"));
+    let output = Command::new(BIN)
+        .args(["-o", "text", "generate", "--small", &count.to_string()])
+        .stdout(File::create(&path).expect("unable to create the corpus"))
+        .status()
+        .expect("unable to run sem-tool generate");
+    assert!(output.success(), "sem-tool generate failed");
+    path
+}
+
+/// The best of several runs, and the output.
+fn sort(corpus: &std::path::Path, threads: usize) -> (Duration, Vec<u8>) {
+    let mut best = Duration::MAX;
+    let mut stdout = Vec::new();
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let output = Command::new(BIN)
+            .args(["-o", "json", "--threads", &threads.to_string()])
+            .args(["sort", "--lexical-sorting"])
+            .stdin(File::open(corpus).expect("unable to open the corpus"))
+            .stderr(Stdio::inherit())
+            .output()
+            .expect("unable to run sem-tool sort");
+        best = best.min(start.elapsed());
+        assert!(output.status.success(), "sem-tool sort failed");
+        stdout = output.stdout;
+    }
+    (best, stdout)
+}
+
+fn main() {
+    let count = std::env::var("SEM_TOOL_BENCH_VERSIONS")
+        .ok()
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(500_000);
+    let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let corpus = generate(count);
+
+    println!("sort --lexical-sorting, {count} versions, best of {RUNS}");
+    let (baseline, expected) = sort(&corpus, 1);
+    println!("{:>8} threads: {baseline:>10.2?}", 1);
+
+    let mut threads = 2;
+    while threads <= available.max(2) {
+        let (elapsed, output) = sort(&corpus, threads);
+        assert!(
+            output == expected,
+            "output with {threads} threads differs from 1 thread"
+        );
+        println!(
+            "{threads:>8} threads: {elapsed:>10.2?} ({:.2}x)",
+            baseline.as_secs_f64() / elapsed.as_secs_f64()
+        );
+        threads *= 2;
+    }
+
+    std::fs::remove_file(corpus).ok();
+}
@@ -0,0 +1,1772 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! This source file doesn't contain much more than just the basics of
+//! CLI documentation, and routing to the appropriate place.
+use clap::builder::PossibleValuesParser;
+use clap::{error::ErrorKind, CommandFactory, FromArgMatches, Parser, Subcommand};
+use semver::Version;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::Termination;
+
+use crate::constraint::{Dialect, Filter};
+use crate::convert::{ConvertTarget, RevisionPolicy};
+use crate::document::VersionPath;
+use crate::merge::{MergeError, Merger};
+use crate::misc::*;
+use crate::parallel::ParseFailure;
+use crate::record::RecordKey;
+use crate::results::*;
+use crate::scheme::calver::{CalverFormat, Date};
+use crate::scheme::nuget::{Nuget, Profile};
+use crate::scheme::semantic::Semver;
+use crate::scheme::{DynScheme, Scheme, SchemeError, SchemeRegistry, VersionScheme};
+use crate::spill::{SpillFile, Spilled, Spiller};
+use crate::stream::GroupWriter;
+use crate::translate::RangeSyntax;
+use crate::{
+    constraint, document, extract, key, misc, parallel, record, scheme, spill, stream, translate,
+};
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+pub struct Args {
+    #[command(subcommand)]
+    cmd: Commands,
+
+    #[clap(long, short = 'o', value_enum, default_value_t=OutputFormat::Yaml)]
+    out: OutputFormat,
+
+    /// Threads to parse versions read from stdin, and to sort with.
+    ///
+    /// Output is identical regardless of the number of threads (other than
+    /// the order of versions only differing by build metadata, which is
+    /// random unless lexically sorted).
+    #[clap(long, short = 'j', default_value_t = NonZeroUsize::MIN)]
+    threads: NonZeroUsize,
+
+    /// The versioning scheme of the versions given to `explain`, `compare`,
+    /// `sort`, `filter-test` & `validate`.
+    ///
+    /// For schemes other than `semver`, `compare`'s Semantic result is the
+    /// scheme's precedence, and the Lexical result breaks ties by comparing
+    /// the versions as written. `sort` groups versions of equal precedence
+    /// (under the lexically least of them), and keeps them as written. Only
+    /// `--lexical-sorting`, `--reverse`, `--flatten` and
+    /// `--fail-if-potentially-ambiguous` apply to them.
+    ///
+    /// `filter-test` filters of the `pep440` scheme are PEP 440 specifiers,
+    /// like `~=1.4.5, !=1.4.7`, matched the way pip does: `===` compares the
+    /// normalized version as a string, `==` and `!=` allow a trailing `.*`
+    /// wildcard, and pre-releases only match when a specifier names one.
+    /// Those of the `maven` scheme are version ranges, like
+    /// `[1.0,2.0),[3.0,)`, matching if any interval does. As in Maven, a bare
+    /// version is a "soft" requirement, which every version matches.
+    /// `--dialect` only applies to the `semver` scheme.
+    ///
+    /// Versions of the `calver` scheme are numbers separated by `.`, `-` or
+    /// `_`, ordered number by number, unless `--format` is given.
+    ///
+    /// References:
+    /// - https://packaging.python.org/en/latest/specifications/version-specifiers/
+    /// - https://maven.apache.org/enforcer/enforcer-rules/versionRanges.html
+    #[clap(long, global = true, default_value = "semver")]
+    scheme: String,
+
+    /// The Calendar Versioning (CalVer) format of `--scheme calver`, and of
+    /// `calver next`, like `YYYY.0M.0D`.
+    ///
+    /// A format is tokens separated by `.`, `-` or `_`, like `YYYY.0M.0D`,
+    /// `YY.0M.MICRO` or `YYYY.WW`. Tokens are `YYYY` (full year), `YY` &
+    /// `0Y` (years since 2000), `MM` & `0M` (month), `WW` & `0W` (week, where
+    /// week 1 starts on January 1st), `DD` & `0D` (day), and `MAJOR`,
+    /// `MINOR` & `MICRO`. Tokens starting with `0` are zero-padded.
+    ///
+    /// Versions must be real dates, with exactly the padding the format asks
+    /// for. They are ordered by their components, in the format's order, and
+    /// short & zero-padded years are years since 2000, so `106.01` comes
+    /// after `99.12`.
+    ///
+    /// References:
+    /// - https://calver.org/
+    #[clap(long, global = true)]
+    format: Option<CalverFormat>,
+}
+
+/// All commands available
+#[derive(Subcommand, Debug, Clone)]
+pub enum Commands {
+    /// Explain a valid Semantic Version as parsed by the spec.
+    ///
+    /// Breaks apart the Semantic Version, into it's individual components.
+    ///
+    /// All values are returned as strings, because the unsigned integer
+    /// types are not necessarily bound by a numeric type that is parsable
+    /// by common libraries.
+    ///
+    /// It is worth noting, Semver 2.0.0 §11.4.1 & §11.4.2 pre-release &
+    /// metadata dot separated values, cannot be negative numbers, since
+    /// they cannot be represented with hyphens.
+    ///
+    /// Reference: https://semver.org/#spec-item-11
+    ///
+    /// Versions of other schemes are broken apart the same way, for
+    /// example a Debian version into its epoch, upstream version and
+    /// revision, or a PEP 440 version into its release segments, pre, post
+    /// and dev releases, and local label.
+    Explain { semantic_version: String },
+    /// Compare 2 Semantic Versions.
+    ///
+    /// Results are provided in the form
+    /// "A is {Greater,Equals,Less} {to,than} B", with both Semantic results
+    /// (meaningful results under Semantic Versioning), as well as Lexical
+    /// results (meaningless, but handy for sorting text lists).
+    Compare {
+        /// If you want some slightly complex exit status codes for this dual
+        /// compare, you can turn them on with this flag.
+        ///
+        /// When both Semantic and Lexical comparisons are Equal, the command
+        /// will end with an exit status of 0 (Success).
+        ///
+        /// All other outcomes, are returned with an exit status of the form: 1XY [between 100-122].
+        ///
+        ///   - With X being (0 if Less, 1 if Equal, 2 if Greater) on the Semantic Compare
+        ///
+        ///   - With Y being (0 if Less, 1 if Equal, 2 if Greater) on the Lexical Compare
+        ///
+        /// The non-0 exit status codes, should be considered UNSTABLE, because something
+        /// better can probably be figured out.
+        #[clap(long, short = 'e', action)]
+        set_exit_status: bool,
+        /// Always exit with success when Semantic Versions are Equal.
+        ///
+        /// Mostly impacts the output when the flag `set_exit_status` is set.
+        #[clap(long, short = 's', action)]
+        semantic_exit_status: bool,
+        /// The base version used for comparison.
+        a: String,
+        /// The version we are comparing against.
+        b: String,
+    },
+    /// Sort a list of valid Semantic Versions, with either Semantic or Lexical ordering.
+    ///
+    /// Results are grouped by default, under the meaningful components of Semantic
+    /// Versioning (without build metadata), then enumerated under that component.
+    Sort {
+        #[clap(long, short = 'f', default_value = None)]
+        /// Only emit versions that match a filter.
+        ///
+        /// These filter rules are described by the semver crate `VersionReq``
+        /// documentation, and more generally in the cargo book.
+        ///
+        /// In particular, note the warnings around pre-releases in the
+        /// VersionReq documentation.
+        ///
+        /// References:
+        /// - https://docs.rs/semver/1.0.25/semver/struct.VersionReq.html
+        /// - https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html
+        #[clap(value_parser = any_dialect_filter)]
+        filter: Option<String>,
+
+        #[clap(long, short = 'd', value_enum, default_value_t)]
+        /// The requirement dialect the filter is written in.
+        ///
+        /// See `sem-tool filter-test --help` for how the dialects differ.
+        dialect: Dialect,
+
+        #[clap(long, action)]
+        /// Lexical Sorting (aka Total Order).
+        ///
+        /// WARNING: This may lead to bad choices surrounding semantic
+        /// versioning,
+        ///
+        /// This is bound to be controversial, but worth understanding.
+        ///
+        /// Semver 2.0.0 §10 states that:
+        /// "Build metadata MUST be ignored when determining version
+        /// precedence."
+        ///
+        /// This has been set to the default behavior of emulating undefined
+        /// behavior, because it MUST be ignored. It is quite common, for
+        /// people to accidentally choose the sorting order of their favorite
+        /// or most familiar tool, and not the specification itself. This
+        /// enforces by default, the ignoring of the version precedence.
+        ///
+        /// Additionally, we must interpret the following statement as
+        /// undefined ordering for the case where Build Metadata may be `None`
+        /// or `Some`:
+        ///
+        /// "Thus two versions that differ only in the build metadata, have
+        /// the same precedence."
+        ///
+        /// References:
+        /// - https://semver.org/#spec-item-10
+        lexical_sorting: bool,
+
+        #[clap(long, short = 'r', action)]
+        /// Reverses ordering.
+        ///
+        /// Note, "reversing" always effects the comparable versions being
+        /// ordered, but is ignored when NOT lexically sorted, for the list of
+        /// semantically identical versions (aka, different metadata). Since by
+        /// default they are randomly sorted, there is no point.
+        reverse: bool,
+
+        #[clap(long, action)]
+        /// Flatten the map, and provide a list of versions.
+        ///
+        /// WARNING: This may lead to bad choices surrounding semantic
+        /// versioning.
+        flatten: bool,
+
+        #[clap(long, action)]
+        /// Fail, if potentially ambiguous precedence may emerge from these
+        /// versions (multiple matching M.M.P-PR, but non-matching metadata).
+        fail_if_potentially_ambiguous: bool,
+
+        #[clap(
+            long,
+            short = 'c',
+            action,
+            conflicts_with_all = ["flatten", "with_key", "field", "key_regex", "path"]
+        )]
+        /// Check the versions are already sorted, rather than sorting them
+        /// (like `sort -c`).
+        ///
+        /// Respects `--reverse`, `--filter` (unmatched versions are skipped),
+        /// and `--lexical-sorting` (versions of the same precedence must also
+        /// be lexically ordered).
+        ///
+        /// Reports the first out of order pair of versions, the first
+        /// duplicate pair, and the first pair only differing by build metadata
+        /// (potentially ambiguous), with their line numbers (or argument
+        /// positions). Any of these exits with a non-zero Status Code.
+        check: bool,
+
+        #[clap(long, action)]
+        /// Provide a flat list of versions, alongside their sortable keys
+        /// (see `sem-tool key --help`).
+        ///
+        /// Implies `--flatten`. As text, each line is the key and the version,
+        /// separated by a tab.
+        with_key: bool,
+
+        #[clap(long, short = 'k', conflicts_with = "versions")]
+        /// Sort whole records (lines read from stdin), by the version found in
+        /// this 1-based field, similar to `sort -k`.
+        ///
+        /// Records are emitted intact, and YAML/JSON output includes both the
+        /// parsed version, and the record. Blank lines are ignored.
+        field: Option<usize>,
+
+        #[clap(long, short = 't', requires = "field", conflicts_with = "versions")]
+        /// The field delimiter for `--field`. By default, fields are separated
+        /// by runs of whitespace.
+        delimiter: Option<String>,
+
+        #[clap(long, conflicts_with_all = ["versions", "field"], value_parser = record::key_regex)]
+        /// Sort whole records (lines read from stdin), by the version matched
+        /// by this regex: the first capture group if there is one, otherwise
+        /// the whole match.
+        ///
+        /// The regex is NOT Unicode aware, so `\d`, `\w` & `\S` are ASCII
+        /// classes, `.` matches any byte, and non-ASCII literals won't match.
+        ///
+        /// Example: `--key-regex 'myapp-(\d+\.\d+\.\d+[^-]*)-linux'`
+        key_regex: Option<::regex::bytes::Regex>,
+
+        #[clap(long, short = 'p', conflicts_with_all = ["versions", "field", "key_regex"])]
+        /// Sort the elements of an array, in a JSON or YAML document read
+        /// from stdin, by the version selected with this path.
+        ///
+        /// Paths are a small subset of `jq` paths: object keys separated by
+        /// `.`, with exactly one `[]` marking the array to sort, such as
+        /// `.releases[].version`, or `.[].version` for a top level array.
+        ///
+        /// Elements are emitted intact, in place of each version.
+        path: Option<VersionPath>,
+
+        #[clap(
+            long,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "exact",
+            value_enum,
+            conflicts_with_all = ["check", "with_key"]
+        )]
+        /// Drop duplicate versions, keeping one of each (like `sort -u`).
+        ///
+        /// `exact` (the default) collapses identical versions. `precedence`
+        /// collapses versions only differing by build metadata, which also
+        /// removes any potential ambiguity.
+        ///
+        /// The output is a flat list, with how many entries each version
+        /// stands for, and how many were collapsed in total. With records,
+        /// duplicates are judged by their versions.
+        unique: Option<Unique>,
+
+        #[clap(long, value_enum, default_value_t)]
+        /// Which of a set of duplicates to keep, with `--unique` or `--count`.
+        pick: UniquePick,
+
+        #[clap(long, action, conflicts_with_all = ["check", "with_key"])]
+        /// Prefix each version with how many entries it stands for, like
+        /// `uniq -c`. Implies `--unique`.
+        count: bool,
+
+        #[clap(long, action, conflicts_with_all = ["check", "unique", "count"])]
+        /// Only emit stable versions (without a pre-release).
+        stable_only: bool,
+
+        #[clap(long, value_enum, conflicts_with_all = ["check", "unique", "count"])]
+        /// Only emit the latest version of each major (or minor) release
+        /// line.
+        ///
+        /// "Latest" is by precedence, so all versions differing only by build
+        /// metadata are kept. Pre-releases are candidates, unless
+        /// `--stable-only`.
+        latest_per: Option<LatestPer>,
+
+        #[clap(long, conflicts_with_all = ["check", "unique", "count"])]
+        /// Only emit the N latest versions (by precedence), after any other
+        /// selection, such as the latest 3 majors with `--latest-per major
+        /// --limit 3`.
+        ///
+        /// The output order is unchanged, so `--reverse` lists the latest
+        /// first.
+        limit: Option<usize>,
+
+        #[clap(
+            long,
+            action,
+            conflicts_with_all = ["flatten", "with_key", "check", "unique", "count"]
+        )]
+        /// Nest versions by MAJOR, then MINOR, then PATCH, then the variants
+        /// of each (pre-releases & build metadata), with how many versions
+        /// are at each level, and the latest stable version of each.
+        ///
+        /// Filters, selections, and ordering apply as usual.
+        tree: bool,
+
+        #[clap(
+            long,
+            short = 'm',
+            num_args = 1..,
+            conflicts_with_all = ["versions", "check", "with_key", "field", "key_regex", "path", "unique", "count", "stable_only", "latest_per", "limit", "tree"]
+        )]
+        /// Merge files that are already sorted (one version per line, `-` for
+        /// stdin, which can only be given once), like `sort -m`.
+        ///
+        /// The output is identical to sorting the concatenated files, but is
+        /// written as it is produced, and only one version per file (plus
+        /// versions of the same precedence) is held in memory.
+        ///
+        /// Each file must be ordered by precedence (descending with
+        /// `--reverse`). Versions differing only in build metadata may be in
+        /// any order. A file that isn't sorted is an error, reporting the file
+        /// and line, though output may already have been written.
+        ///
+        /// With `--fail-if-potentially-ambiguous`, the failure comes after the
+        /// output.
+        merge: Vec<PathBuf>,
+
+        #[clap(
+            long,
+            short = 'S',
+            value_parser = spill::memory_size,
+            conflicts_with_all = ["check", "with_key", "field", "key_regex", "path", "merge", "unique", "count", "stable_only", "latest_per", "limit", "tree"]
+        )]
+        /// Sort within a memory budget (in bytes, with an optional K, M or G
+        /// suffix), spilling sorted runs to temporary files and merging them,
+        /// like `sort -S`.
+        ///
+        /// The output is identical to sorting in memory, but is written as it
+        /// is produced, once the merge begins. With
+        /// `--fail-if-potentially-ambiguous`, the failure comes after the
+        /// output.
+        ///
+        /// The budget is approximate, and only applies to versions being
+        /// sorted. If it is never exceeded, nothing is written to disk.
+        buffer_size: Option<usize>,
+
+        #[clap(long, short = 'T', requires = "buffer_size")]
+        /// The directory for temporary files with `--buffer-size`, rather
+        /// than the system default.
+        temporary_directory: Option<PathBuf>,
+
+        /// If no versions are present, then the tool will read from stdin, one
+        /// version per line.
+        versions: Option<Vec<String>>,
+    },
+    /// Test a Semantic Version against a filter
+    FilterTest {
+        /// The requirement dialect the filter is written in.
+        ///
+        /// - `cargo`: The semver crate `VersionReq`, as described in the
+        ///   cargo book.
+        ///
+        /// - `terraform`: Terraform / RubyGems constraints. Comparators are
+        ///   comma separated and ALL must match. `!=` is supported, and
+        ///   missing components are 0 (`= 1.2` is exactly `1.2.0`).
+        ///
+        /// The pessimistic operator `~>` is NOT Cargo's `~`. It allows the
+        /// last written component to increase: `~> 1.2` is `>=1.2.0, <2.0.0`,
+        /// and `~> 1.2.3` is `>=1.2.3, <1.3.0`. Cargo's `~1.2` is
+        /// `>=1.2.0, <1.3.0`. `~> 1` follows RubyGems, and is `>=1.0.0, <2.0.0`.
+        ///
+        /// Pre-release handling follows `hashicorp/go-version`: pre-releases
+        /// only match a comparator with a pre-release on the same
+        /// MAJOR.MINOR.PATCH, except for `=` and `!=`.
+        ///
+        /// `sem-tool range translate` will print the Cargo equivalent.
+        ///
+        /// References:
+        /// - https://developer.hashicorp.com/terraform/language/expressions/version-constraints
+        /// - https://guides.rubygems.org/patterns/#pessimistic-version-constraint
+        #[clap(long, short = 'd', value_enum, default_value_t)]
+        dialect: Dialect,
+
+        /// Filter to test against a specific Semantic Version.
+        ///
+        /// These filter rules are described by the semver crate `VersionReq``
+        /// documentation, and more generally in the cargo book.
+        ///
+        /// In particular, note the warnings around pre-releases in the
+        /// VersionReq documentation.
+        ///
+        /// The Status Code will be 0 if it passes, non-zero if it fails.
+        ///
+        /// References:
+        /// - https://docs.rs/semver/1.0.25/semver/struct.VersionReq.html
+        /// - https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html
+        filter: String,
+
+        /// Version to test
+        semantic_version: String,
+    },
+    /// Simply validates an argument, to confirm it is a valid Semantic Version
+    ///
+    /// The Status Code will be 0 if it is valid, non-zero if it is not.
+    Validate {
+        /// Version to validate
+        version: String,
+
+        /// "Small" will ensure the MAJOR, MINOR & PATCH components are under [u64::MAX].
+        ///
+        /// Only applies to the `semver` scheme.
+        #[clap(long, short = 's', action)]
+        small: bool,
+
+        /// A Go module path, which must have the major version suffix the
+        /// version needs (`example.com/mod/v2` for `v2.x.x`, and none for
+        /// `v0`, `v1` or `+incompatible` versions).
+        ///
+        /// Only applies to the `go` scheme.
+        ///
+        /// Reference: https://go.dev/ref/mod#major-version-suffixes
+        #[clap(long)]
+        module_path: Option<String>,
+    },
+    /// Infer the narrowest requirement that accepts a set of versions.
+    ///
+    /// Candidates are `=MIN` (for a single version), `~MIN` and `^MIN`
+    /// (narrowest first), and finally `>=MIN, <=MAX`. Each is checked
+    /// against every version, and the first that accepts all of them, and
+    /// rejects all `--exclude` versions, is chosen.
+    ///
+    /// Requirements are Cargo `VersionReq`s, so the pre-release warnings in
+    /// the VersionReq documentation apply.
+    ///
+    /// The Status Code will be 0 if a requirement was found, non-zero if not.
+    InferReq {
+        /// The shape of requirement to infer.
+        #[clap(long, short = 's', value_enum, default_value_t)]
+        style: RequirementStyle,
+
+        /// Versions which must NOT be accepted by the requirement.
+        #[clap(long, short = 'x')]
+        exclude: Vec<Version>,
+
+        /// If no versions are present, then the tool will read from stdin, one
+        /// version per line.
+        versions: Option<Vec<Version>>,
+    },
+    /// Report how a proposed release would affect dependents.
+    ///
+    /// For each dependent requirement, reports whether it matched the current
+    /// latest version, whether it matches the candidate, and whether the
+    /// candidate would be automatically selected (it matches, and is newer
+    /// than the latest, or the latest didn't match).
+    ///
+    /// Requirements are read one per line, from a file, or from stdin. A line
+    /// may name the dependent with a prefix (`my-crate: ^1.2`). Blank lines,
+    /// and lines starting with `#` are ignored.
+    ReleaseImpact {
+        /// The current latest release.
+        #[clap(long, short = 'l')]
+        latest: Version,
+
+        /// The proposed release.
+        #[clap(long, short = 'c')]
+        candidate: Version,
+
+        /// The requirement dialect the requirements are written in.
+        ///
+        /// See `sem-tool filter-test --help` for how the dialects differ.
+        #[clap(long, short = 'd', value_enum, default_value_t)]
+        dialect: Dialect,
+
+        /// Fail, if any dependent that matched the latest release, does not
+        /// match the candidate.
+        #[clap(long, action)]
+        fail_if_pinned_out: bool,
+
+        /// A file of requirements. If not present, then the tool will read
+        /// from stdin.
+        requirements: Option<PathBuf>,
+    },
+    /// Find Semantic Versions in arbitrary text (think `grep` for versions).
+    ///
+    /// Scans files (or stdin) for anything matching the Semantic Versioning
+    /// 2.0.0 regex, with an optional `v` / `V` prefix, reporting the file,
+    /// line, column (1-based, in bytes, including any prefix), the version
+    /// (without any prefix), and some surrounding context.
+    ///
+    /// A match must stand on its own: `1.2.3.4`, `a1.2.3`, and `dev1.2.3`
+    /// contain no versions, but `myapp-1.2.3.tar` and `(v1.2.3)` do. Note
+    /// that text following a `-` is a valid pre-release, so
+    /// `myapp-1.2.3-linux.tar.gz` contains `1.2.3-linux.tar.gz`.
+    ///
+    /// With `--flatten`, the output is a plain list of versions, ready to
+    /// pipe into `sort`.
+    Extract {
+        /// Only emit versions with MAJOR, MINOR & PATCH components under
+        /// [u64::MAX] (what `sort` & `filter-test` accept).
+        #[clap(long, short = 's', action)]
+        small: bool,
+
+        /// How many bytes of context, either side of a version, to report.
+        #[clap(long, short = 'C', default_value_t = 20)]
+        context: usize,
+
+        /// Only provide the list of versions found.
+        #[clap(long, action)]
+        flatten: bool,
+
+        /// Files to scan. If not present, then the tool will read from stdin.
+        files: Vec<PathBuf>,
+    },
+    /// Encode Semantic Versions into sortable keys.
+    ///
+    /// A key is an ASCII string, whose byte-wise lexicographic order is the
+    /// same as Semantic Versioning precedence. Plain `sort(1)` (with
+    /// `LC_ALL=C`), or a database column with a binary / "C" collation, will
+    /// order keys correctly.
+    ///
+    /// Build metadata has no precedence, so it is not part of the key.
+    /// Numbers are not bound by any numeric type.
+    ///
+    /// `sem-tool decode-key` reverses the encoding.
+    Key {
+        /// If no versions are present, then the tool will read from stdin, one
+        /// version per line.
+        #[clap(value_parser = sortable_version)]
+        versions: Option<Vec<KeyedVersion>>,
+    },
+    /// Decode sortable keys back into Semantic Versions.
+    ///
+    /// The versions will not have build metadata, since it is not part of
+    /// the key.
+    DecodeKey {
+        /// If no keys are present, then the tool will read from stdin, one
+        /// key per line.
+        #[clap(value_parser = decodable_key)]
+        keys: Option<Vec<KeyedVersion>>,
+    },
+    /// Convert Semantic Versions into the equivalent versions of another
+    /// scheme, for publishing the same release into several ecosystems.
+    ///
+    /// Pre-releases are mapped the way each ecosystem conventionally writes
+    /// them, so `1.2.3-rc.1` becomes `1.2.3rc1` (pep440), `1.2.3~rc1`
+    /// (debian), `1.2.3-0.rc1` (rpm, where releases get a `-1` release),
+    /// `1.2.3-rc1` (maven), or stays `1.2.3-rc.1` (nuget).
+    ///
+    /// Only NuGet has build metadata, so it is dropped for every other
+    /// scheme, and the conversion is marked as `lossy`. Pre-releases with no
+    /// PEP 440 equivalent (anything but alpha, beta & rc) aren't converted.
+    ///
+    /// The conversions are also checked to be ordered the same way as the
+    /// Semantic Versions, as each ecosystem orders pre-releases differently.
+    /// Any lossy, failed, or reordered conversion gives a non-zero Status
+    /// Code.
+    Convert {
+        /// The scheme to convert from (only `semver`, for now).
+        #[clap(long, value_enum, default_value_t)]
+        from: Scheme,
+
+        /// The scheme to convert into.
+        #[clap(long, value_enum)]
+        to: ConvertTarget,
+
+        /// If no versions are present, then the tool will read from stdin, one
+        /// version per line.
+        #[clap(value_parser = clap::value_parser!(Version))]
+        versions: Option<Vec<Version>>,
+    },
+    /// Work with version requirements (aka filters).
+    Range {
+        #[command(subcommand)]
+        cmd: RangeCommands,
+    },
+    /// Work with Calendar Versions (CalVer), in the format given by
+    /// `--format`.
+    ///
+    /// Use `--scheme calver` to explain, compare, sort & validate them.
+    ///
+    /// References:
+    /// - https://calver.org/
+    Calver {
+        #[command(subcommand)]
+        cmd: CalverCommands,
+    },
+    /// Work with four-part versions (`major.minor.patch.revision`), as NuGet
+    /// packages, .NET assemblies & Windows installers use.
+    ///
+    /// Versions are parsed & ordered as NuGet does (the same as
+    /// `--scheme nuget`), so missing parts are 0, pre-releases are compared
+    /// ignoring case, and build metadata is ignored.
+    ///
+    /// References:
+    /// - https://learn.microsoft.com/en-us/nuget/concepts/package-versioning
+    /// - https://learn.microsoft.com/en-us/windows/win32/msi/productversion
+    FourPart {
+        #[command(subcommand)]
+        cmd: FourPartCommands,
+    },
+    /// Generate random & valid Semantic Version Strings
+    Generate {
+        /// "Small" will ensure the MAJOR, MINOR & PATCH components are under [u64::MAX].
+        #[clap(long, short = 's', action)]
+        small: bool,
+
+        /// How many to create (default 1)
+        #[clap(default_value_t = 1)]
+        count: usize,
+    },
+}
+
+/// Subcommands of `sem-tool range`.
+#[derive(Subcommand, Debug, Clone)]
+pub enum RangeCommands {
+    /// Translate a requirement into the equivalent expression in another
+    /// ecosystem's syntax.
+    ///
+    /// Exact translations are frequently impossible, mostly because every
+    /// ecosystem has different rules for when pre-releases match. When the
+    /// translation isn't exact, it is marked as `lossy`, `notes` describe the
+    /// lossy parts, and the Status Code will be non-zero.
+    ///
+    /// See `sem-tool filter-test --help` for how the input dialects differ.
+    ///
+    /// References:
+    /// - https://github.com/npm/node-semver#ranges
+    /// - https://maven.apache.org/enforcer/enforcer-rules/versionRanges.html
+    /// - https://peps.python.org/pep-0440/#version-specifiers
+    /// - https://go.dev/ref/mod#minimal-version-selection
+    Translate {
+        /// The requirement dialect to translate from.
+        #[clap(long, value_enum)]
+        from: Dialect,
+
+        /// The syntax to translate into.
+        #[clap(long, value_enum, default_value_t)]
+        to: RangeSyntax,
+
+        /// The requirement to translate.
+        #[clap(value_parser = any_dialect_filter)]
+        requirement: String,
+    },
+}
+
+/// Subcommands of `sem-tool calver`.
+#[derive(Subcommand, Debug, Clone)]
+pub enum CalverCommands {
+    /// Generate the next version in the format, for a date.
+    ///
+    /// Without a current version, `MAJOR`, `MINOR` & `MICRO` are 0. If the
+    /// current version is from an earlier date, they are reset to 0, and if
+    /// it is from the same date, the last of them is incremented. A current
+    /// version from a later date is an error.
+    Next {
+        /// The date of the release, as `YYYY-MM-DD` (default today, in UTC).
+        #[clap(long, short = 'd')]
+        date: Option<Date>,
+
+        /// The current (latest) version.
+        current: Option<String>,
+    },
+}
+
+/// Subcommands of `sem-tool four-part`.
+#[derive(Subcommand, Debug, Clone)]
+pub enum FourPartCommands {
+    /// Normalize versions the way nuget.org does.
+    ///
+    /// Leading zeros are removed, a revision of 0 is dropped, the pre-release
+    /// is kept as written, and build metadata is dropped. So
+    /// `1.01.0.0-RC.1+abc` is normalized to `1.1.0-RC.1`.
+    Normalize {
+        /// If no versions are present, then the tool will read from stdin, one
+        /// version per line.
+        #[clap(value_parser = four_part_version)]
+        versions: Option<Vec<String>>,
+    },
+    /// Validate a version, optionally within the limits of a profile.
+    ///
+    /// The Status Code will be 0 if it is valid, non-zero if it is not.
+    Validate {
+        /// Limits the version must also be within.
+        #[clap(long, value_enum)]
+        profile: Option<Profile>,
+
+        version: String,
+    },
+    /// Convert four-part versions into Semantic Versions.
+    ///
+    /// Pre-releases & build metadata are kept. Semantic Versions have no
+    /// revision, so `--revision` decides what happens to revisions other
+    /// than 0.
+    ///
+    /// Any lossy, failed, or reordered conversion gives a non-zero Status
+    /// Code.
+    ToSemver {
+        /// What happens to the revision.
+        #[clap(long, value_enum, default_value_t)]
+        revision: RevisionPolicy,
+
+        /// If no versions are present, then the tool will read from stdin, one
+        /// version per line.
+        #[clap(value_parser = four_part_version)]
+        versions: Option<Vec<String>>,
+    },
+    /// Convert Semantic Versions into four-part versions.
+    ///
+    /// The revision is 0, unless `--revision build` finds a trailing
+    /// `revision.N` in the build metadata, as `to-semver` writes it.
+    ///
+    /// Any failed, or reordered conversion gives a non-zero Status Code.
+    FromSemver {
+        /// Where the revision comes from.
+        #[clap(long, value_enum, default_value_t)]
+        revision: RevisionPolicy,
+
+        /// Limits the converted versions must be within.
+        #[clap(long, value_enum)]
+        profile: Option<Profile>,
+
+        /// If no versions are present, then the tool will read from stdin, one
+        /// version per line.
+        #[clap(value_parser = clap::value_parser!(Version))]
+        versions: Option<Vec<Version>>,
+    },
+}
+
+/// Run `sem-tool`, with the versioning schemes of `registry` available to
+/// `--scheme`.
+pub fn run(registry: SchemeRegistry) -> Result<impl Termination, Box<dyn Error>> {
+    let matches = Args::command()
+        .mut_arg("scheme", |arg| {
+            arg.value_parser(PossibleValuesParser::new(registry.possible_values()))
+        })
+        .get_matches();
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    let registered = registry
+        .get(&args.scheme)
+        .expect("--scheme only accepts registered schemes");
+    let semver = registered.builtin == Some(Scheme::Semver);
+    let scheme: &dyn DynScheme = match (registered.builtin, &args.format) {
+        (Some(Scheme::Calver), Some(format)) => format,
+        (_, Some(_)) => match matches.subcommand_name() {
+            Some(name) if name != "calver" => exit_with_error(
+                &[name],
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "the argument '--format <FORMAT>' cannot be used with '--scheme {}'",
+                    args.scheme
+                ),
+            ),
+            _ => registered.scheme.as_ref(),
+        },
+        (_, None) => registered.scheme.as_ref(),
+    };
+    match matches.subcommand_name() {
+        Some("explain" | "compare" | "sort" | "filter-test" | "validate") => {}
+        Some(name) if !semver => exit_with_error(
+            &[name],
+            ErrorKind::ArgumentConflict,
+            format!("the argument '--scheme <SCHEME>' cannot be used with '{name}'"),
+        ),
+        _ => {}
+    }
+
+    let mut ignore_exit_status_from_output = false;
+    let threads = args.threads.get();
+
+    let application_output: ApplicationOutput = match args.cmd {
+        Commands::Explain { semantic_version } => {
+            let version = scheme_arg(&["explain"], "<SEMANTIC_VERSION>", scheme, semantic_version);
+            scheme.explain(&version)?.into()
+        }
+        Commands::Compare {
+            set_exit_status,
+            semantic_exit_status,
+            a,
+            b,
+        } => {
+            // If we don't consider non-equivalence an error, don't report one
+            // on process exit.
+            if !set_exit_status {
+                ignore_exit_status_from_output = true;
+            }
+            let res = scheme.compare(
+                &scheme_arg(&["compare"], "<A>", scheme, a),
+                &scheme_arg(&["compare"], "<B>", scheme, b),
+            )?;
+
+            if semantic_exit_status && res.semantic_ordering() == &SerializableOrdering::Equal {
+                ignore_exit_status_from_output = true
+            }
+
+            res.into()
+        }
+        Commands::Sort {
+            versions,
+            lexical_sorting,
+            reverse,
+            flatten,
+            fail_if_potentially_ambiguous,
+            filter,
+            check,
+            with_key,
+            field,
+            key_regex,
+            path,
+            unique,
+            count,
+            stable_only,
+            latest_per,
+            limit,
+            tree,
+            merge,
+            buffer_size,
+            ..
+        } if !semver => {
+            semver_only(
+                &["sort"],
+                &[
+                    ("--filter", filter.is_some()),
+                    ("--check", check),
+                    ("--with-key", with_key),
+                    ("--field", field.is_some()),
+                    ("--key-regex", key_regex.is_some()),
+                    ("--path", path.is_some()),
+                    ("--unique", unique.is_some()),
+                    ("--count", count),
+                    ("--stable-only", stable_only),
+                    ("--latest-per", latest_per.is_some()),
+                    ("--limit", limit.is_some()),
+                    ("--tree", tree),
+                    ("--merge", !merge.is_empty()),
+                    ("--buffer-size", buffer_size.is_some()),
+                ],
+            );
+
+            let versions = match versions {
+                Some(versions) => versions
+                    .into_iter()
+                    .map(|version| scheme_arg(&["sort"], "[VERSIONS]...", scheme, version))
+                    .collect(),
+                None => read_lines_from_stdin("version", |line| scheme.check(line))?,
+            };
+            let sorted = scheme.sort(versions, lexical_sorting, reverse, flatten)?;
+
+            if fail_if_potentially_ambiguous && sorted.potentially_ambiguous {
+                return Err(Box::new(misc::ApplicationError::FailedRequirementError {
+                    err: "Potential Ambiguity Detected".to_string(),
+                }));
+            }
+            sorted.output
+        }
+        Commands::Sort {
+            check: true,
+            versions,
+            filter,
+            dialect,
+            lexical_sorting,
+            reverse,
+            ..
+        } => {
+            let versions = match versions {
+                Some(versions) => semver_args("sort", "versions", versions),
+                None => read_versions_from_stdin(threads)?,
+            };
+            let filter =
+                filter.map(|filter| parse_filter(&["sort"], "--filter <FILTER>", dialect, &filter));
+            let lines: Vec<(usize, Version)> = (1..).zip(versions).collect();
+            SortCheckResult::check(&lines, &filter, lexical_sorting, reverse).into()
+        }
+        Commands::Sort {
+            merge,
+            filter,
+            dialect,
+            lexical_sorting,
+            reverse,
+            flatten,
+            fail_if_potentially_ambiguous,
+            ..
+        } if !merge.is_empty() => {
+            if merge.iter().filter(|file| file.as_os_str() == "-").count() > 1 {
+                exit_with_error(
+                    &["sort"],
+                    ErrorKind::ArgumentConflict,
+                    "the argument '--merge <MERGE>...' can only read stdin ('-') once".to_string(),
+                )
+            }
+            let filter =
+                filter.map(|filter| parse_filter(&["sort"], "--filter <FILTER>", dialect, &filter));
+            let potentially_ambiguous = sort_merge(
+                &merge,
+                &filter,
+                lexical_sorting,
+                reverse,
+                GroupWriter::new(io::stdout().lock(), args.out.clone(), flatten),
+            )
+            .map_err(|e| {
+                eprintln!("unable to merge: {e}");
+                e
+            })?;
+
+            if fail_if_potentially_ambiguous && potentially_ambiguous {
+                return Err(Box::new(misc::ApplicationError::FailedRequirementError {
+                    err: "Potential Ambiguity Detected".to_string(),
+                }));
+            }
+            ApplicationOutput::Streamed
+        }
+        Commands::Sort {
+            buffer_size: Some(buffer_size),
+            temporary_directory,
+            versions,
+            filter,
+            dialect,
+            lexical_sorting,
+            reverse,
+            flatten,
+            fail_if_potentially_ambiguous,
+            ..
+        } => {
+            let filter =
+                filter.map(|filter| parse_filter(&["sort"], "--filter <FILTER>", dialect, &filter));
+            let potentially_ambiguous = sort_external(
+                versions.map(|versions| semver_args("sort", "versions", versions)),
+                &filter,
+                Spiller::new(
+                    temporary_directory.unwrap_or_else(std::env::temp_dir),
+                    buffer_size,
+                    reverse,
+                    threads,
+                ),
+                lexical_sorting,
+                reverse,
+                GroupWriter::new(io::stdout().lock(), args.out.clone(), flatten),
+            )
+            .map_err(|e| {
+                eprintln!("unable to sort: {e}");
+                e
+            })?;
+
+            if fail_if_potentially_ambiguous && potentially_ambiguous {
+                return Err(Box::new(misc::ApplicationError::FailedRequirementError {
+                    err: "Potential Ambiguity Detected".to_string(),
+                }));
+            }
+            ApplicationOutput::Streamed
+        }
+        Commands::Sort {
+            versions,
+            filter,
+            dialect,
+            lexical_sorting,
+            reverse,
+            flatten,
+            fail_if_potentially_ambiguous,
+            check: _,
+            merge: _,
+            buffer_size: _,
+            temporary_directory: _,
+            unique,
+            pick,
+            count,
+            stable_only,
+            latest_per,
+            limit,
+            tree,
+            with_key,
+            field,
+            delimiter,
+            key_regex,
+            path,
+        } => {
+            let record_key = match (field, key_regex) {
+                (Some(field), _) => Some(RecordKey::Field { field, delimiter }),
+                (None, Some(re)) => Some(RecordKey::Regex(re)),
+                (None, None) => None,
+            };
+
+            // Read from stdin, or pass forward the pre-parsed list from the arguments
+            let entries = match (versions, record_key, path) {
+                (Some(versions), _, _) => semver_args("sort", "versions", versions)
+                    .into_iter()
+                    .map(SortEntry::from)
+                    .collect(),
+                (None, Some(record_key), _) => read_records_from_stdin(&record_key)?,
+                (None, None, Some(path)) => read_document_from_stdin(&path)?,
+                (None, None, None) => read_versions_from_stdin(threads)?
+                    .into_iter()
+                    .map(SortEntry::from)
+                    .collect(),
+            };
+
+            let filter =
+                filter.map(|filter| parse_filter(&["sort"], "--filter <FILTER>", dialect, &filter));
+
+            if unique.is_some() || count {
+                let unique_versions = UniqueVersionsList::collapse(
+                    entries,
+                    &filter,
+                    unique.unwrap_or_default(),
+                    pick,
+                    lexical_sorting,
+                    reverse,
+                )
+                .map_err(|e| misc::ApplicationError::FailedRequirementError {
+                    err: e.to_string(),
+                })?;
+
+                if fail_if_potentially_ambiguous && unique_versions.potentially_ambiguous() {
+                    return Err(Box::new(misc::ApplicationError::FailedRequirementError {
+                        err: "Potential Ambiguity Detected".to_string(),
+                    }));
+                }
+                unique_versions.with_counts(count).into()
+            } else {
+                let mut ordered_version_list =
+                    sort(entries, &filter, lexical_sorting, reverse, threads);
+                if stable_only {
+                    ordered_version_list.retain_stable();
+                }
+                if let Some(per) = latest_per {
+                    ordered_version_list.retain_latest_per(per);
+                }
+                if let Some(limit) = limit {
+                    ordered_version_list.retain_latest(limit);
+                }
+
+                if fail_if_potentially_ambiguous && ordered_version_list.potentially_ambiguous() {
+                    return Err(Box::new(misc::ApplicationError::FailedRequirementError {
+                        err: "Potential Ambiguity Detected".to_string(),
+                    }));
+                }
+
+                match (with_key, flatten, tree) {
+                    (true, _, _) => KeyedVersionsList::from(&mut ordered_version_list).into(),
+                    (false, true, _) => FlatVersionsList::from(&mut ordered_version_list).into(),
+                    (false, false, true) => VersionTree::from(&mut ordered_version_list).into(),
+                    (false, false, false) => ordered_version_list.into(),
+                }
+            }
+        }
+        Commands::FilterTest {
+            dialect,
+            filter,
+            semantic_version,
+        } if semver => {
+            let filter = parse_filter(&["filter-test"], "<FILTER>", dialect, &filter);
+            let version = scheme_arg(
+                &["filter-test"],
+                "<SEMANTIC_VERSION>",
+                scheme,
+                semantic_version,
+            );
+            FilterTestResult::test::<Semver>(&filter, &Semver::parse(&version)?).into()
+        }
+        Commands::FilterTest {
+            dialect,
+            filter,
+            semantic_version,
+        } => {
+            semver_only(
+                &["filter-test"],
+                &[("--dialect", dialect != Dialect::Cargo)],
+            );
+            if let Err(e) = scheme.check_requirement(&filter) {
+                exit_with_error(
+                    &["filter-test"],
+                    ErrorKind::ValueValidation,
+                    format!("invalid value '{filter}' for '<FILTER>': {e}"),
+                )
+            }
+            let version = scheme_arg(
+                &["filter-test"],
+                "<SEMANTIC_VERSION>",
+                scheme,
+                semantic_version,
+            );
+            scheme.filter_test(&filter, &version)?.into()
+        }
+        Commands::InferReq {
+            style,
+            exclude,
+            versions,
+        } => {
+            let versions = match versions {
+                Some(versions) => versions,
+                None => read_versions_from_stdin(threads)?,
+            };
+            infer_req(&versions, &exclude, style).into()
+        }
+        Commands::ReleaseImpact {
+            latest,
+            candidate,
+            dialect,
+            fail_if_pinned_out,
+            requirements,
+        } => {
+            let report = release_impact(latest, candidate, dialect, requirements)?;
+
+            if fail_if_pinned_out && report.pinned_out() > 0 {
+                return Err(Box::new(misc::ApplicationError::FailedRequirementError {
+                    err: format!("{} dependents pinned out", report.pinned_out()),
+                }));
+            }
+            report.into()
+        }
+        Commands::Extract {
+            small,
+            context,
+            flatten,
+            files,
+        } => {
+            let result = extract(&files, small, context)?;
+            match flatten {
+                true => FlatStringList::from(result).into(),
+                false => result.into(),
+            }
+        }
+        Commands::Key { versions } => {
+            let versions = match versions {
+                Some(versions) => versions,
+                None => read_lines_from_stdin("version", sortable_version)?,
+            };
+            KeyedVersionsList::encoded(versions).into()
+        }
+        Commands::DecodeKey { keys } => {
+            let keys = match keys {
+                Some(keys) => keys,
+                None => read_lines_from_stdin("key", decodable_key)?,
+            };
+            KeyedVersionsList::decoded(keys).into()
+        }
+        Commands::Convert { from, to, versions } => {
+            if from != Scheme::Semver {
+                exit_with_error(
+                    &["convert"],
+                    ErrorKind::InvalidValue,
+                    "only 'semver' versions can be converted from, for now".to_string(),
+                )
+            }
+            let versions = match versions {
+                Some(versions) => versions,
+                None => read_versions_from_stdin(threads)?,
+            };
+            ConversionResult::convert(&versions, to).into()
+        }
+        Commands::Range { cmd } => match cmd {
+            RangeCommands::Translate {
+                from,
+                to,
+                requirement,
+            } => {
+                let filter =
+                    parse_filter(&["range", "translate"], "<REQUIREMENT>", from, &requirement);
+                translate(from, to, &filter, requirement).into()
+            }
+        },
+        Commands::Calver { cmd } => match cmd {
+            CalverCommands::Next { date, current } => {
+                let Some(format) = &args.format else {
+                    exit_with_error(
+                        &["calver", "next"],
+                        ErrorKind::MissingRequiredArgument,
+                        "the following required arguments were not provided:\n  --format <FORMAT>"
+                            .to_string(),
+                    )
+                };
+                let current =
+                    current.map(|c| scheme_arg(&["calver", "next"], "[CURRENT]", format, c));
+                format
+                    .next(date.unwrap_or_else(Date::today), current.as_deref())
+                    .unwrap_or_else(|e| {
+                        exit_with_error(
+                            &["calver", "next"],
+                            ErrorKind::ValueValidation,
+                            e.to_string(),
+                        )
+                    })
+                    .into()
+            }
+        },
+        Commands::FourPart { cmd } => match cmd {
+            FourPartCommands::Normalize { versions } => {
+                let versions = match versions {
+                    Some(versions) => versions,
+                    None => read_lines_from_stdin("version", four_part_version)?,
+                };
+                versions
+                    .iter()
+                    .map(|version| Ok(Nuget::parse(version)?.normalized()))
+                    .collect::<Result<Vec<String>, SchemeError>>()
+                    .map(FlatStringList::from)?
+                    .into()
+            }
+            FourPartCommands::Validate { profile, version } => {
+                let checked = Nuget::parse(&version)
+                    .and_then(|parsed| profile.map_or(Ok(()), |profile| profile.check(&parsed)));
+                match checked {
+                    Ok(()) => ValidateResult::from(true).into(),
+                    Err(e) => {
+                        eprintln!("{e}");
+                        ValidateResult::from(false).into()
+                    }
+                }
+            }
+            FourPartCommands::ToSemver { revision, versions } => {
+                let versions = match versions {
+                    Some(versions) => versions,
+                    None => read_lines_from_stdin("version", four_part_version)?,
+                };
+                ConversionResult::four_part_to_semver(&versions, revision).into()
+            }
+            FourPartCommands::FromSemver {
+                revision,
+                profile,
+                versions,
+            } => {
+                let versions = match versions {
+                    Some(versions) => versions,
+                    None => read_versions_from_stdin(threads)?,
+                };
+                ConversionResult::semver_to_four_part(&versions, revision, profile).into()
+            }
+        },
+        Commands::Validate {
+            version,
+            module_path: Some(module_path),
+            ..
+        } if registered.builtin == Some(Scheme::Go) => {
+            match scheme::go::check_path_major(&module_path, &version) {
+                Ok(()) => ValidateResult::from(true).into(),
+                Err(e) => {
+                    eprintln!("{e}");
+                    ValidateResult::from(false).into()
+                }
+            }
+        }
+        Commands::Validate {
+            module_path: Some(_),
+            ..
+        } => exit_with_error(
+            &["validate"],
+            ErrorKind::ArgumentConflict,
+            "the argument '--module-path <MODULE_PATH>' can only be used with '--scheme go'"
+                .to_string(),
+        ),
+        // Semantic Versions too big for `--small` still match the spec.
+        Commands::Validate {
+            version,
+            small: true,
+            ..
+        } if semver => ValidateResult::from(scheme.check(&version).is_ok()).into(),
+        Commands::Validate { version, .. } => scheme.validate(&version).into(),
+        Commands::Generate { small, count } => generate(small, count).into(),
+    };
+
+    match args.out {
+        _ if matches!(application_output, ApplicationOutput::Streamed) => {}
+        OutputFormat::Text => print!("{application_output}"),
+        OutputFormat::Yaml => {
+            println!("---");
+            let yaml = serde_yaml::to_string(&application_output)
+                .map_err(|e| ApplicationError::OutputFormatError { err: e.to_string() })?;
+            print!("{yaml}");
+        }
+        OutputFormat::Json => {
+            let json = serde_json::to_string(&application_output)
+                .map_err(|e| ApplicationError::OutputFormatError { err: e.to_string() })?;
+            println!("{json}");
+        }
+    }
+
+    Ok(ApplicationTermination::new(
+        application_output,
+        ignore_exit_status_from_output,
+    ))
+}
+
+/// Read one version per line from stdin, parsing on `threads` threads.
+fn read_versions_from_stdin(threads: usize) -> Result<Vec<Version>, Box<dyn Error>> {
+    if threads > 1 {
+        let input = io::read_to_string(io::stdin()).map_err(|e| {
+            eprintln!("unable to read from stdin: {e}");
+            ApplicationError::InvalidArgument {
+                expected: "to be able to read from stdin".to_string(),
+                found: e.to_string(),
+            }
+        })?;
+        return parallel::parse_lines(&input, threads).map_err(|failure| {
+            let ParseFailure { line_no, line, err } = failure;
+            eprintln!("unable to parse an enumerated version: line {line_no}: {line}: {err}");
+            err.into()
+        });
+    }
+
+    let mut parsed_versions = Vec::new();
+    for (line_no, line) in (1..).zip(io::stdin().lines()) {
+        match line {
+            Ok(line) => {
+                let line = line.trim();
+                parsed_versions.push(Version::parse(line).map_err(|e| {
+                    eprintln!("unable to parse an enumerated version: line {line_no}: {line}: {e}");
+                    e
+                })?);
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("unable to read from stdin: {e}");
+                Err(ApplicationError::InvalidArgument {
+                    expected: "to be able to read from stdin".to_string(),
+                    found: e.to_string(),
+                })
+            }
+        }?
+    }
+    Ok(parsed_versions)
+}
+
+/// Read one record per line from stdin, each keyed by the version within.
+fn read_records_from_stdin(record_key: &RecordKey) -> Result<Vec<SortEntry>, Box<dyn Error>> {
+    let mut entries = Vec::new();
+    for (line_no, line) in (1..).zip(io::stdin().lines()) {
+        let line = line.map_err(|e| {
+            eprintln!("unable to read from stdin: {e}");
+            e
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let found = record_key.find(&line).ok_or_else(|| {
+            eprintln!("unable to find a version in a record: line {line_no}: {line}");
+            ApplicationError::InvalidArgument {
+                expected: "a version in every record".to_string(),
+                found: line.clone(),
+            }
+        })?;
+        let version = Version::parse(found).map_err(|e| {
+            eprintln!("unable to parse an enumerated version: line {line_no}: {found}: {e}");
+            e
+        })?;
+        entries.push(SortEntry::with_record(version, Record::Line(line)));
+    }
+    Ok(entries)
+}
+
+/// Read a JSON / YAML document from stdin, selecting versions with `path`.
+fn read_document_from_stdin(path: &VersionPath) -> Result<Vec<SortEntry>, Box<dyn Error>> {
+    let input = io::read_to_string(io::stdin()).map_err(|e| {
+        eprintln!("unable to read from stdin: {e}");
+        e
+    })?;
+    let selected = document::parse(&input)
+        .and_then(|document| path.select(document))
+        .map_err(|e| {
+            eprintln!("unable to select versions from the document: {e}");
+            e
+        })?;
+    Ok(selected
+        .into_iter()
+        .map(|(version, element)| SortEntry::with_record(version, Record::Document(element)))
+        .collect())
+}
+
+/// Read one value per line from stdin, checking each with `check`.
+fn read_lines_from_stdin<T, E: Error + 'static>(
+    what: &str,
+    check: impl Fn(&str) -> Result<T, E>,
+) -> Result<Vec<T>, Box<dyn Error>> {
+    let mut values = Vec::new();
+    for (line_no, line) in (1..).zip(io::stdin().lines()) {
+        let line = line.map_err(|e| {
+            eprintln!("unable to read from stdin: {e}");
+            e
+        })?;
+        let line = line.trim();
+        values.push(check(line).map_err(|e| {
+            eprintln!("unable to parse an enumerated {what}: line {line_no}: {line}: {e}");
+            e
+        })?);
+    }
+    Ok(values)
+}
+
+/// Accept any valid Semantic Version, without a numeric bound, encoding it
+/// into its key.
+fn sortable_version(input: &str) -> Result<KeyedVersion, key::KeyError> {
+    KeyedVersion::encode(input)
+}
+
+/// Accept any valid four-part (NuGet) version.
+fn four_part_version(input: &str) -> Result<String, SchemeError> {
+    Nuget::parse(input).map(|_| input.to_string())
+}
+
+/// Accept any key produced by [key::encode], decoding it into its version.
+fn decodable_key(input: &str) -> Result<KeyedVersion, key::KeyError> {
+    KeyedVersion::decode(input)
+}
+
+/// Accept a filter that is valid in at least one dialect.
+///
+/// The dialect is a separate flag, so the filter is re-parsed with
+/// [parse_filter] once all arguments are known. Checking early keeps clap's
+/// error reporting for obviously bad filters, before anything else.
+fn any_dialect_filter(input: &str) -> Result<String, constraint::ConstraintError> {
+    match Filter::parse(Dialect::Cargo, input) {
+        Ok(_) => Ok(input.to_string()),
+        Err(e) => Filter::parse(Dialect::Terraform, input)
+            .map(|_| input.to_string())
+            .map_err(|_| e),
+    }
+}
+
+/// Report an error the same way clap would have, against the (sub)command
+/// found at `path`.
+fn exit_with_error(path: &[&str], kind: ErrorKind, message: String) -> ! {
+    let mut cmd = Args::command();
+    cmd.build();
+    let mut cmd = &mut cmd;
+    for name in path {
+        cmd = cmd
+            .find_subcommand_mut(name)
+            .expect("subcommand path should exist");
+    }
+    cmd.error(kind, message).exit()
+}
+
+/// Parse a filter in a specific dialect, reporting failures the same way
+/// clap would have, against the (sub)command found at `path`.
+fn parse_filter(path: &[&str], arg: &str, dialect: Dialect, input: &str) -> Filter {
+    Filter::parse(dialect, input).unwrap_or_else(|e| {
+        exit_with_error(
+            path,
+            ErrorKind::ValueValidation,
+            format!("invalid value '{input}' for '{arg}': {e}"),
+        )
+    })
+}
+
+/// Parse Semantic Version arguments of a subcommand.
+///
+/// Versions are taken as strings, since other schemes accept other
+/// versions. If any are invalid, the arguments are parsed again with the
+/// argument typed, so the error is exactly the one clap reports.
+fn semver_args(subcommand: &str, arg: &str, inputs: Vec<String>) -> Vec<Version> {
+    inputs
+        .iter()
+        .map(|input| Version::parse(input))
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|_| {
+            let matches = Args::command()
+                .mut_subcommand(subcommand, |cmd| {
+                    cmd.mut_arg(arg, |arg| arg.value_parser(clap::value_parser!(Version)))
+                })
+                .try_get_matches();
+            match matches {
+                Err(e) => e.exit(),
+                Ok(_) => unreachable!("'{arg}' should not have parsed"),
+            }
+        })
+}
+
+/// Check a version argument of another scheme, reporting failures the same
+/// way clap would have.
+fn scheme_arg(path: &[&str], arg: &str, scheme: &dyn DynScheme, input: String) -> String {
+    scheme.check(&input).unwrap_or_else(|e| {
+        exit_with_error(
+            path,
+            ErrorKind::ValueValidation,
+            format!("invalid value '{input}' for '{arg}': {e}"),
+        )
+    })
+}
+
+/// Fail if options only supported for Semantic Versions were used with
+/// another scheme, the same way clap reports conflicting arguments.
+fn semver_only(path: &[&str], options: &[(&str, bool)]) {
+    if let Some((option, _)) = options.iter().find(|(_, used)| *used) {
+        exit_with_error(
+            path,
+            ErrorKind::ArgumentConflict,
+            format!("the argument '--scheme <SCHEME>' cannot be used with '{option}'"),
+        )
+    }
+}
+
+fn sort(
+    mut entries: Vec<SortEntry>,
+    filter: &Option<Filter>,
+    lexical_sorting: bool,
+    reverse: bool,
+    threads: usize,
+) -> OrderedVersionMap {
+    // Already sorted input is cheap to sort again, within the map.
+    if threads > 1 {
+        parallel::sort(&mut entries, threads);
+    }
+    OrderedVersionMap::from_entries(entries, filter, lexical_sorting, reverse)
+}
+
+/// Merge sorted files, writing the output as it is produced.
+///
+/// Returns if the output was potentially ambiguous.
+fn sort_merge<W: io::Write>(
+    files: &[PathBuf],
+    filter: &Option<Filter>,
+    lexical_sorting: bool,
+    reverse: bool,
+    mut writer: GroupWriter<W>,
+) -> Result<bool, MergeError> {
+    let inputs = files
+        .iter()
+        .map(|file| {
+            let name = file.display().to_string();
+            let reader: Box<dyn BufRead> = match name.as_str() {
+                "-" => Box::new(io::stdin().lock()),
+                _ => Box::new(BufReader::new(File::open(file).map_err(|err| {
+                    MergeError::Io {
+                        file: name.clone(),
+                        err,
+                    }
+                })?)),
+            };
+            Ok((name, reader))
+        })
+        .collect::<Result<Vec<_>, MergeError>>()?;
+
+    let merger = Merger::new(inputs, reverse)?;
+    let potentially_ambiguous =
+        stream::write_grouped(merger, filter, lexical_sorting, reverse, &mut writer)?;
+    writer.finish(potentially_ambiguous)?;
+    Ok(potentially_ambiguous)
+}
+
+/// Sort versions within a memory budget, spilling to disk as needed, and
+/// writing the output as it is produced.
+///
+/// Returns if the output was potentially ambiguous.
+fn sort_external<W: io::Write>(
+    versions: Option<Vec<Version>>,
+    filter: &Option<Filter>,
+    mut spiller: Spiller,
+    lexical_sorting: bool,
+    reverse: bool,
+    mut writer: GroupWriter<W>,
+) -> Result<bool, MergeError> {
+    let keep = |version: &Version| filter.as_ref().is_none_or(|filter| filter.matches(version));
+
+    match versions {
+        Some(versions) => {
+            for version in versions.into_iter().filter(keep) {
+                spiller.push(version)?;
+            }
+        }
+        None => {
+            for (line_no, line) in (1..).zip(io::stdin().lines()) {
+                let line = line.map_err(|err| MergeError::Io {
+                    file: "-".to_string(),
+                    err,
+                })?;
+                let line = line.trim();
+                let version = Version::parse(line).map_err(|err| MergeError::Parse {
+                    file: "-".to_string(),
+                    line: line_no,
+                    version: line.to_string(),
+                    err,
+                })?;
+                if keep(&version) {
+                    spiller.push(version)?;
+                }
+            }
+        }
+    }
+
+    let potentially_ambiguous = match spiller.finish()? {
+        Spilled::InMemory(versions) => stream::write_grouped(
+            versions
+                .into_iter()
+                .map(|version| Ok::<_, MergeError>(SortEntry::from(version))),
+            &None,
+            lexical_sorting,
+            reverse,
+            &mut writer,
+        )?,
+        Spilled::OnDisk(runs) => {
+            let inputs = runs.iter().map(SpillFile::open).collect::<Result<_, _>>()?;
+            stream::write_grouped(
+                Merger::new(inputs, reverse)?,
+                &None,
+                lexical_sorting,
+                reverse,
+                &mut writer,
+            )?
+        }
+    };
+    writer.finish(potentially_ambiguous)?;
+    Ok(potentially_ambiguous)
+}
+
+fn infer_req(
+    versions: &[Version],
+    exclude: &[Version],
+    style: RequirementStyle,
+) -> InferredRequirement {
+    InferredRequirement::infer(versions, exclude, style)
+}
+
+fn release_impact(
+    latest: Version,
+    candidate: Version,
+    dialect: Dialect,
+    requirements: Option<PathBuf>,
+) -> Result<ReleaseImpactReport, Box<dyn Error>> {
+    let reader: Box<dyn BufRead> = match requirements {
+        Some(path) => Box::new(BufReader::new(File::open(&path).map_err(|e| {
+            eprintln!("unable to open {}: {e}", path.display());
+            e
+        })?)),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let mut dependents = Vec::new();
+    for (line_no, line) in (1..).zip(reader.lines()) {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (name, requirement) = match line.split_once(':') {
+            Some((name, requirement)) => (Some(name.trim().to_string()), requirement.trim()),
+            None => (None, line),
+        };
+        let filter = Filter::parse(dialect, requirement).map_err(|e| {
+            eprintln!("unable to parse a requirement: line {line_no}: {requirement}: {e}");
+            e
+        })?;
+        dependents.push(DependentImpact::new(
+            name,
+            line_no,
+            requirement.to_string(),
+            &filter,
+            &latest,
+            &candidate,
+        ));
+    }
+
+    Ok(ReleaseImpactReport::new(latest, candidate, dependents))
+}
+
+fn extract(
+    files: &[PathBuf],
+    small: bool,
+    context: usize,
+) -> Result<ExtractResult, Box<dyn Error>> {
+    let extractor = extract::Extractor::new();
+    let mut result = ExtractResult::default();
+
+    let mut scan = |name: &str, mut reader: Box<dyn BufRead>| -> io::Result<()> {
+        // Arbitrary text isn't always UTF-8, so be lenient.
+        let mut buf = Vec::new();
+        for line_no in 1.. {
+            buf.clear();
+            if reader.read_until(b'\n', &mut buf)? == 0 {
+                break;
+            }
+            let line = String::from_utf8_lossy(&buf);
+            let line = line.trim_end_matches(['\n', '\r']);
+            result.scan_line(&extractor, name, line_no, line, small, context);
+        }
+        Ok(())
+    };
+
+    if files.is_empty() {
+        scan("-", Box::new(io::stdin().lock()))?;
+    }
+    for path in files {
+        let file = File::open(path).map_err(|e| {
+            eprintln!("unable to open {}: {e}", path.display());
+            e
+        })?;
+        scan(&path.display().to_string(), Box::new(BufReader::new(file)))?;
+    }
+    Ok(result)
+}
+
+fn translate(
+    from: Dialect,
+    to: RangeSyntax,
+    filter: &Filter,
+    requirement: String,
+) -> TranslationResult {
+    let translation = match (filter, to) {
+        // Nothing to do, other than normalize.
+        (Filter::Cargo(req), RangeSyntax::Cargo) => translate::Translation {
+            translation: Some(req.to_string()),
+            lossy: false,
+            notes: Vec::new(),
+        },
+        (filter, to) => translate::render(translate::lower(filter), to),
+    };
+    TranslationResult::new(from, to, requirement, translation)
+}
+
+fn generate(small: bool, count: usize) -> GenerateResult {
+    GenerateResult::new(small, count)
+}
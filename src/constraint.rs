@@ -0,0 +1,377 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Requirement "dialects" other than the semver crate's `VersionReq`.
+//!
+//! NOTE(canardleteer): The Terraform / RubyGems dialect looks a lot like
+//!                     Cargo's, but the pessimistic operator `~>` is closer
+//!                     to Cargo's `^` than to Cargo's `~`, which is the
+//!                     whole reason this exists.
+use std::{cmp::Ordering, fmt};
+
+use clap::ValueEnum;
+use semver::{Prerelease, Version, VersionReq};
+use serde::Serialize;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub(crate) enum ConstraintError {
+    /// The requirement was rejected by the semver crate.
+    #[error(transparent)]
+    Cargo(#[from] semver::Error),
+
+    /// There was nothing to parse.
+    #[error("empty constraint")]
+    Empty,
+
+    /// A version inside of a constraint could not be understood.
+    #[error("unable to parse version {version:?} in constraint: {reason}")]
+    InvalidVersion { version: String, reason: String },
+}
+
+/// Requirement syntaxes we know how to match against.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Dialect {
+    /// The semver crate's `VersionReq` (Cargo).
+    #[default]
+    Cargo,
+    /// Terraform / RubyGems style constraints (`~>`, `!=`, comma-AND).
+    Terraform,
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dialect::Cargo => write!(f, "cargo"),
+            Dialect::Terraform => write!(f, "terraform"),
+        }
+    }
+}
+
+/// A parsed requirement, in any of the supported dialects.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Filter {
+    Cargo(VersionReq),
+    Terraform(PessimisticReq),
+}
+
+impl Filter {
+    pub(crate) fn parse(dialect: Dialect, input: &str) -> Result<Filter, ConstraintError> {
+        match dialect {
+            Dialect::Cargo => Ok(Filter::Cargo(VersionReq::parse(input)?)),
+            Dialect::Terraform => Ok(Filter::Terraform(PessimisticReq::parse(input)?)),
+        }
+    }
+
+    pub(crate) fn matches(&self, version: &Version) -> bool {
+        match self {
+            Filter::Cargo(req) => req.matches(version),
+            Filter::Terraform(req) => req.matches(version),
+        }
+    }
+}
+
+impl From<VersionReq> for Filter {
+    fn from(value: VersionReq) -> Self {
+        Filter::Cargo(value)
+    }
+}
+
+/// Operators available in the Terraform / RubyGems dialect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PessimisticOp {
+    Exact,
+    NotEqual,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Pessimistic,
+}
+
+impl PessimisticOp {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PessimisticOp::Exact => "=",
+            PessimisticOp::NotEqual => "!=",
+            PessimisticOp::Greater => ">",
+            PessimisticOp::GreaterEq => ">=",
+            PessimisticOp::Less => "<",
+            PessimisticOp::LessEq => "<=",
+            PessimisticOp::Pessimistic => "~>",
+        }
+    }
+}
+
+/// A single `op version` term of a Terraform / RubyGems constraint.
+///
+/// `version` always has all 3 components filled in (missing components are
+/// 0), and `specified` remembers how many of them were actually written,
+/// since that is what `~>` keys off of.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PessimisticComparator {
+    pub(crate) op: PessimisticOp,
+    pub(crate) version: Version,
+    pub(crate) specified: usize,
+}
+
+impl PessimisticComparator {
+    /// The exclusive upper bound of a `~>` comparator.
+    ///
+    /// We follow RubyGems here: the last written component is dropped, and
+    /// the one before it is bumped. A single component (`~> 1`) is treated
+    /// as `~> 1.0`.
+    pub(crate) fn pessimistic_upper_bound(&self) -> Version {
+        if self.specified < 3 {
+            Version::new(self.version.major + 1, 0, 0)
+        } else {
+            Version::new(self.version.major, self.version.minor + 1, 0)
+        }
+    }
+
+    /// Mirrors the pre-release handling of `hashicorp/go-version`, which is
+    /// what Terraform uses.
+    ///
+    /// - A pre-release version only matches a comparator with a pre-release
+    ///   on the same MAJOR.MINOR.PATCH.
+    /// - `=` and `!=` skip this check entirely.
+    fn prerelease_check(&self, version: &Version) -> bool {
+        match (self.version.pre.is_empty(), version.pre.is_empty()) {
+            (false, false) => same_release(&self.version, version),
+            (true, false) => false,
+            _ => true,
+        }
+    }
+
+    pub(crate) fn matches(&self, version: &Version) -> bool {
+        let ordering = version.cmp_precedence(&self.version);
+        match self.op {
+            PessimisticOp::Exact => ordering == Ordering::Equal,
+            PessimisticOp::NotEqual => ordering != Ordering::Equal,
+            PessimisticOp::Greater => {
+                self.prerelease_check(version) && ordering == Ordering::Greater
+            }
+            PessimisticOp::GreaterEq => {
+                self.prerelease_check(version) && ordering != Ordering::Less
+            }
+            PessimisticOp::Less => self.prerelease_check(version) && ordering == Ordering::Less,
+            PessimisticOp::LessEq => {
+                self.prerelease_check(version) && ordering != Ordering::Greater
+            }
+            PessimisticOp::Pessimistic => {
+                // `~>` with a pre-release, never matches a release.
+                let upper = self.pessimistic_upper_bound();
+                self.prerelease_check(version)
+                    && (self.version.pre.is_empty() || !version.pre.is_empty())
+                    && ordering != Ordering::Less
+                    && (version.major, version.minor, version.patch)
+                        < (upper.major, upper.minor, upper.patch)
+            }
+        }
+    }
+}
+
+impl fmt::Display for PessimisticComparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.op.as_str(), self.version.major)?;
+        if self.specified > 1 {
+            write!(f, ".{}", self.version.minor)?;
+        }
+        if self.specified > 2 {
+            write!(f, ".{}", self.version.patch)?;
+        }
+        if !self.version.pre.is_empty() {
+            write!(f, "-{}", self.version.pre)?;
+        }
+        Ok(())
+    }
+}
+
+/// A Terraform / RubyGems constraint, which is a comma separated list of
+/// comparators, that all must match.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PessimisticReq {
+    pub(crate) comparators: Vec<PessimisticComparator>,
+}
+
+impl PessimisticReq {
+    pub(crate) fn parse(input: &str) -> Result<PessimisticReq, ConstraintError> {
+        let mut comparators = Vec::new();
+        for term in input.split(',') {
+            let term = term.trim();
+            if term.is_empty() {
+                return Err(ConstraintError::Empty);
+            }
+            // Order matters, 2 character operators first.
+            let (op, rest) = [
+                ("~>", PessimisticOp::Pessimistic),
+                (">=", PessimisticOp::GreaterEq),
+                ("<=", PessimisticOp::LessEq),
+                ("!=", PessimisticOp::NotEqual),
+                (">", PessimisticOp::Greater),
+                ("<", PessimisticOp::Less),
+                ("=", PessimisticOp::Exact),
+            ]
+            .iter()
+            .find_map(|(prefix, op)| term.strip_prefix(prefix).map(|rest| (*op, rest)))
+            .unwrap_or((PessimisticOp::Exact, term));
+
+            let (version, specified) = parse_partial_version(rest.trim())?;
+            comparators.push(PessimisticComparator {
+                op,
+                version,
+                specified,
+            });
+        }
+        Ok(PessimisticReq { comparators })
+    }
+
+    pub(crate) fn matches(&self, version: &Version) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+}
+
+impl fmt::Display for PessimisticReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = self.comparators.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", terms.join(", "))
+    }
+}
+
+/// Parse a `1`, `1.2`, `1.2.3` or `1.2.3-pre` style version, with an optional
+/// leading `v`. Build metadata is accepted, and ignored.
+fn parse_partial_version(input: &str) -> Result<(Version, usize), ConstraintError> {
+    let invalid = |reason: &str| ConstraintError::InvalidVersion {
+        version: input.to_string(),
+        reason: reason.to_string(),
+    };
+
+    let stripped = input.strip_prefix('v').unwrap_or(input);
+    let stripped = stripped.split_once('+').map_or(stripped, |(v, _)| v);
+    let (release, pre) = match stripped.split_once('-') {
+        Some((release, pre)) => (
+            release,
+            Prerelease::new(pre).map_err(|e| invalid(&e.to_string()))?,
+        ),
+        None => (stripped, Prerelease::EMPTY),
+    };
+
+    if release.is_empty() {
+        return Err(invalid("missing version"));
+    }
+
+    let mut numbers = Vec::new();
+    for part in release.split('.') {
+        numbers.push(
+            part.parse::<u64>()
+                .map_err(|e| invalid(&format!("{part:?}: {e}")))?,
+        );
+    }
+    if numbers.len() > 3 {
+        return Err(invalid("more than 3 numeric components"));
+    }
+    if !pre.is_empty() && numbers.len() != 3 {
+        return Err(invalid("pre-release requires MAJOR.MINOR.PATCH"));
+    }
+
+    let specified = numbers.len();
+    numbers.resize(3, 0);
+    let mut version = Version::new(numbers[0], numbers[1], numbers[2]);
+    version.pre = pre;
+    Ok((version, specified))
+}
+
+fn same_release(a: &Version, b: &Version) -> bool {
+    (a.major, a.minor, a.patch) == (b.major, b.minor, b.patch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_pessimistic_parse() {
+        let req = PessimisticReq::parse("~> 1.2, != 1.4.1, < 2").unwrap();
+        assert_eq!(req.comparators.len(), 3);
+        assert_eq!(req.comparators[0].op, PessimisticOp::Pessimistic);
+        assert_eq!(req.comparators[0].specified, 2);
+        assert_eq!(req.comparators[1].op, PessimisticOp::NotEqual);
+        assert_eq!(req.comparators[2].version, v("2.0.0"));
+        assert_eq!(req.to_string(), "~> 1.2, != 1.4.1, < 2");
+
+        // No operator means exact.
+        let req = PessimisticReq::parse("1.2.3").unwrap();
+        assert_eq!(req.comparators[0].op, PessimisticOp::Exact);
+
+        assert!(PessimisticReq::parse("").is_err());
+        assert!(PessimisticReq::parse("~> 1.2,").is_err());
+        assert!(PessimisticReq::parse("~> a.b").is_err());
+        assert!(PessimisticReq::parse("~> 1.2.3.4").is_err());
+        assert!(PessimisticReq::parse("~> 1.2-rc1").is_err());
+    }
+
+    #[test]
+    fn test_pessimistic_matches() {
+        let req = PessimisticReq::parse("~> 1.2").unwrap();
+        assert!(req.matches(&v("1.2.0")));
+        assert!(req.matches(&v("1.9.9")));
+        assert!(!req.matches(&v("2.0.0")));
+        assert!(!req.matches(&v("1.1.9")));
+        assert!(!req.matches(&v("1.3.0-rc.1")));
+
+        let req = PessimisticReq::parse("~> 1.2.3").unwrap();
+        assert!(req.matches(&v("1.2.3")));
+        assert!(req.matches(&v("1.2.99")));
+        assert!(!req.matches(&v("1.3.0")));
+
+        let req = PessimisticReq::parse("~> 1").unwrap();
+        assert!(req.matches(&v("1.99.0")));
+        assert!(!req.matches(&v("2.0.0")));
+
+        let req = PessimisticReq::parse(">= 1.0, != 1.4.1, < 2").unwrap();
+        assert!(req.matches(&v("1.4.0")));
+        assert!(!req.matches(&v("1.4.1")));
+        assert!(!req.matches(&v("2.0.0")));
+
+        // Pre-releases only match comparators with a pre-release on the same
+        // MAJOR.MINOR.PATCH, except for `=`.
+        let req = PessimisticReq::parse(">= 1.2.3-beta").unwrap();
+        assert!(req.matches(&v("1.2.3-rc.1")));
+        assert!(!req.matches(&v("1.2.4-rc.1")));
+        let req = PessimisticReq::parse("= 1.2.3-beta").unwrap();
+        assert!(req.matches(&v("1.2.3-beta+build")));
+
+        // `~>` with a pre-release rejects non pre-release versions.
+        let req = PessimisticReq::parse("~> 1.2.3-beta").unwrap();
+        assert!(req.matches(&v("1.2.3-beta.2")));
+        assert!(!req.matches(&v("1.2.3")));
+    }
+
+    #[test]
+    fn test_filter() {
+        let cargo = Filter::parse(Dialect::Cargo, "~1.2").unwrap();
+        let terraform = Filter::parse(Dialect::Terraform, "~> 1.2").unwrap();
+        assert!(cargo.matches(&v("1.2.5")));
+        assert!(!cargo.matches(&v("1.3.0")));
+        assert!(terraform.matches(&v("1.3.0")));
+
+        assert!(Filter::parse(Dialect::Cargo, "~> 1.2").is_err());
+    }
+}
@@ -0,0 +1,504 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Conversion of Semantic Versions into other versioning schemes.
+//!
+//! Each target has a canonical mapping, for publishing the same release into
+//! several ecosystems:
+//!
+//! | Semantic Version | PEP 440    | Debian      | RPM           | Maven       | NuGet        |
+//! |------------------|------------|-------------|---------------|-------------|--------------|
+//! | `1.2.3`          | `1.2.3`    | `1.2.3`     | `1.2.3-1`     | `1.2.3`     | `1.2.3`      |
+//! | `1.2.3-rc.1`     | `1.2.3rc1` | `1.2.3~rc1` | `1.2.3-0.rc1` | `1.2.3-rc1` | `1.2.3-rc.1` |
+//!
+//! Only NuGet has build metadata, so it is dropped (and the conversion is
+//! lossy) for every other target. Since every target orders pre-releases in
+//! its own way, a list of conversions is also checked to be in the same order
+//! as the Semantic Versions were.
+//!
+//! Four-part versions (`major.minor.patch.revision`, as NuGet, .NET
+//! assemblies & Windows installers use) convert to and from Semantic
+//! Versions too, with a [RevisionPolicy] deciding what happens to the
+//! revision, which Semantic Versioning has no place for.
+use std::cmp::Ordering;
+use std::fmt;
+
+use clap::ValueEnum;
+use semver::{BuildMetadata, Prerelease, Version};
+use serde::Serialize;
+
+use crate::scheme::nuget::{Nuget, Profile};
+use crate::scheme::{Scheme, VersionScheme};
+use crate::translate::pep440_prerelease;
+
+/// Versioning schemes we know how to convert into.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConvertTarget {
+    /// Python package versions (PEP 440).
+    Pep440,
+    /// Debian package versions.
+    Debian,
+    /// RPM package versions, with a release (`0.` prefixed for
+    /// pre-releases).
+    Rpm,
+    /// Maven artifact versions.
+    Maven,
+    /// NuGet package versions (SemVer 2.0.0).
+    Nuget,
+}
+
+impl fmt::Display for ConvertTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertTarget::Pep440 => write!(f, "pep440"),
+            ConvertTarget::Debian => write!(f, "debian"),
+            ConvertTarget::Rpm => write!(f, "rpm"),
+            ConvertTarget::Maven => write!(f, "maven"),
+            ConvertTarget::Nuget => write!(f, "nuget"),
+        }
+    }
+}
+
+impl From<ConvertTarget> for Scheme {
+    fn from(value: ConvertTarget) -> Self {
+        match value {
+            ConvertTarget::Pep440 => Scheme::Pep440,
+            ConvertTarget::Debian => Scheme::Debian,
+            ConvertTarget::Rpm => Scheme::Rpm,
+            ConvertTarget::Maven => Scheme::Maven,
+            ConvertTarget::Nuget => Scheme::Nuget,
+        }
+    }
+}
+
+/// Where the revision (the fourth part) of a four-part version goes, in a
+/// Semantic Version.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum RevisionPolicy {
+    /// Nowhere, so versions with a revision other than 0 aren't converted.
+    #[default]
+    Reject,
+    /// Nowhere, so a revision other than 0 is dropped (and the conversion
+    /// is lossy).
+    Drop,
+    /// In build metadata, as `revision.N`. Build metadata doesn't affect
+    /// precedence, so versions differing only by revision become equal.
+    Build,
+}
+
+/// A single version, converted into another scheme.
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct Conversion {
+    pub(crate) version: String,
+    pub(crate) converted: Option<String>,
+    pub(crate) lossy: bool,
+    pub(crate) notes: Vec<String>,
+}
+
+/// Join pre-release identifiers, gluing a number onto the label before it,
+/// so `rc.1` becomes `rc1`, as most ecosystems write it.
+fn joined_prerelease(pre: &Prerelease) -> String {
+    let mut joined = String::new();
+    let mut previous_numeric = true;
+    for identifier in pre.as_str().split('.') {
+        let numeric = identifier.bytes().all(|b| b.is_ascii_digit());
+        if !joined.is_empty() && (previous_numeric || !numeric) {
+            joined.push('.');
+        }
+        joined.push_str(identifier);
+        previous_numeric = numeric;
+    }
+    joined
+}
+
+/// Convert a Semantic Version, with its canonical mapping into `to`.
+pub(crate) fn convert(version: &Version, to: ConvertTarget) -> Conversion {
+    let mut notes = Vec::new();
+    let release = format!("{}.{}.{}", version.major, version.minor, version.patch);
+
+    if !version.build.is_empty() && to != ConvertTarget::Nuget {
+        notes.push(format!(
+            "build metadata `{}` has no {to} equivalent, and was dropped",
+            version.build
+        ));
+    }
+    // Neither Debian (without a revision) nor RPM allow a hyphen here.
+    let mut pre = joined_prerelease(&version.pre);
+    if matches!(to, ConvertTarget::Debian | ConvertTarget::Rpm) && pre.contains('-') {
+        pre = pre.replace('-', ".");
+        notes.push(format!(
+            "`-` in the pre-release `{}` is not allowed in {to}, and was replaced by `.`",
+            version.pre
+        ));
+    }
+
+    let converted = match (to, version.pre.is_empty()) {
+        (ConvertTarget::Nuget, _) => Some(version.to_string()),
+        (ConvertTarget::Rpm, true) => Some(format!("{release}-1")),
+        (_, true) => Some(release),
+        (ConvertTarget::Pep440, false) => match pep440_prerelease(&version.pre) {
+            Some(pre) => Some(format!("{release}{pre}")),
+            None => {
+                notes.push(format!(
+                    "the pre-release `{}` has no PEP 440 equivalent",
+                    version.pre
+                ));
+                None
+            }
+        },
+        (ConvertTarget::Debian, false) => Some(format!("{release}~{pre}")),
+        (ConvertTarget::Rpm, false) => Some(format!("{release}-0.{pre}")),
+        (ConvertTarget::Maven, false) => Some(format!("{release}-{pre}")),
+    };
+
+    Conversion {
+        version: version.to_string(),
+        lossy: converted.is_some() && !notes.is_empty(),
+        converted,
+        notes,
+    }
+}
+
+/// Convert a four-part version into a Semantic Version.
+pub(crate) fn four_part_to_semver(input: &str, policy: RevisionPolicy) -> Conversion {
+    let mut notes = Vec::new();
+    let converted = match Nuget::parse(input) {
+        Ok(version) => {
+            let mut build = version.metadata.clone();
+            let revision = match (version.revision, policy) {
+                (0, _) => Some(()),
+                (revision, RevisionPolicy::Reject) => {
+                    notes.push(format!(
+                        "the revision `{revision}` has no Semantic Versioning equivalent"
+                    ));
+                    None
+                }
+                (revision, RevisionPolicy::Drop) => {
+                    notes.push(format!("the revision `{revision}` was dropped"));
+                    Some(())
+                }
+                (revision, RevisionPolicy::Build) => {
+                    let metadata = build.as_deref().map_or(String::new(), |m| format!("{m}."));
+                    build = Some(format!("{metadata}revision.{revision}"));
+                    Some(())
+                }
+            };
+            revision.map(|_| {
+                Version {
+                    major: version.major,
+                    minor: version.minor,
+                    patch: version.patch,
+                    // NuGet labels are also valid Semantic Versioning
+                    // identifiers.
+                    pre: Prerelease::new(&version.release.join("."))
+                        .expect("NuGet pre-releases are valid"),
+                    build: BuildMetadata::new(build.as_deref().unwrap_or_default())
+                        .expect("NuGet build metadata is valid"),
+                }
+                .to_string()
+            })
+        }
+        Err(e) => {
+            notes.push(e.to_string());
+            None
+        }
+    };
+
+    Conversion {
+        version: input.to_string(),
+        lossy: converted.is_some() && !notes.is_empty(),
+        converted,
+        notes,
+    }
+}
+
+/// Split a trailing `revision.N` off of build metadata.
+fn split_revision(build: &str) -> (&str, Option<&str>) {
+    let (rest, revision) = match build.rsplit_once(".revision.") {
+        Some(split) => split,
+        None => match build.strip_prefix("revision.") {
+            Some(revision) => ("", revision),
+            None => return (build, None),
+        },
+    };
+    match !revision.is_empty() && revision.bytes().all(|b| b.is_ascii_digit()) {
+        true => (rest, Some(revision)),
+        false => (build, None),
+    }
+}
+
+/// Convert a Semantic Version into a four-part version, within the limits
+/// of `profile`, if any.
+pub(crate) fn semver_to_four_part(
+    version: &Version,
+    policy: RevisionPolicy,
+    profile: Option<Profile>,
+) -> Conversion {
+    let (build, revision) = match policy {
+        RevisionPolicy::Build => split_revision(version.build.as_str()),
+        RevisionPolicy::Reject | RevisionPolicy::Drop => (version.build.as_str(), None),
+    };
+    let mut four_part = format!(
+        "{}.{}.{}.{}",
+        version.major,
+        version.minor,
+        version.patch,
+        revision.unwrap_or("0")
+    );
+    if !version.pre.is_empty() {
+        four_part.push_str(&format!("-{}", version.pre));
+    }
+    if !build.is_empty() {
+        four_part.push_str(&format!("+{build}"));
+    }
+
+    // NuGet's parts are smaller than Semantic Versioning's, and a profile
+    // may limit them further.
+    let checked = Nuget::parse(&four_part)
+        .and_then(|parsed| profile.map_or(Ok(()), |profile| profile.check(&parsed)));
+    let (converted, notes) = match checked {
+        Ok(()) => (Some(four_part), Vec::new()),
+        Err(e) => (None, vec![e.to_string()]),
+    };
+    Conversion {
+        version: version.to_string(),
+        lossy: false,
+        converted,
+        notes,
+    }
+}
+
+fn symbol(ordering: Ordering) -> &'static str {
+    match ordering {
+        Ordering::Less => "<",
+        Ordering::Equal => "=",
+        Ordering::Greater => ">",
+    }
+}
+
+/// Check that conversions from `from` are ordered the same way in `to`,
+/// reporting every neighbouring pair (by precedence) that isn't.
+pub(crate) fn check_order(conversions: &[Conversion], from: Scheme, to: Scheme) -> Vec<String> {
+    // The versions were accepted by `from` before they were converted.
+    let expected = |a: &str, b: &str| from.cmp(a, b).expect("converted versions should be valid");
+    let mut converted: Vec<(&str, &str)> = conversions
+        .iter()
+        .filter_map(|c| Some((c.version.as_str(), c.converted.as_deref()?)))
+        .collect();
+    converted.sort_by(|(a, _), (b, _)| expected(a, b).then_with(|| a.cmp(b)));
+
+    let mut notes = Vec::new();
+    for pair in converted.windows(2) {
+        let [(a, converted_a), (b, converted_b)] = pair else {
+            unreachable!("windows of 2");
+        };
+        let expected = expected(a, b);
+        match to.cmp(converted_a, converted_b) {
+            Ok(ordering) if ordering == expected => {}
+            Ok(ordering) => notes.push(format!(
+                "`{a}` {} `{b}`, but `{converted_a}` {} `{converted_b}` in {to}",
+                symbol(expected),
+                symbol(ordering),
+            )),
+            Err(_) => notes.push(format!(
+                "`{converted_a}` and `{converted_b}` can't be compared in {to}"
+            )),
+        }
+    }
+    notes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert_str(version: &str, to: ConvertTarget) -> Conversion {
+        convert(&Version::parse(version).unwrap(), to)
+    }
+
+    fn check_order_str(versions: &[&str], to: ConvertTarget) -> Vec<String> {
+        let versions: Vec<Version> = versions
+            .iter()
+            .map(|v| Version::parse(v).unwrap())
+            .collect();
+        let conversions: Vec<Conversion> = versions.iter().map(|v| convert(v, to)).collect();
+        check_order(&conversions, Scheme::Semver, to.into())
+    }
+
+    #[test]
+    fn test_joined_prerelease() {
+        let joined = |s: &str| joined_prerelease(&Prerelease::new(s).unwrap());
+        assert_eq!(joined("rc.1"), "rc1");
+        assert_eq!(joined("rc1"), "rc1");
+        assert_eq!(joined("alpha.beta.2"), "alpha.beta2");
+        assert_eq!(joined("1.rc.2.3"), "1.rc2.3");
+    }
+
+    #[test]
+    fn test_convert() {
+        for (version, to, converted, lossy) in [
+            ("1.2.3-rc.1", ConvertTarget::Pep440, Some("1.2.3rc1"), false),
+            (
+                "1.2.3-rc.1",
+                ConvertTarget::Debian,
+                Some("1.2.3~rc1"),
+                false,
+            ),
+            ("1.2.3-rc.1", ConvertTarget::Rpm, Some("1.2.3-0.rc1"), false),
+            ("1.2.3-rc.1", ConvertTarget::Maven, Some("1.2.3-rc1"), false),
+            (
+                "1.2.3-rc.1",
+                ConvertTarget::Nuget,
+                Some("1.2.3-rc.1"),
+                false,
+            ),
+            ("1.2.3", ConvertTarget::Rpm, Some("1.2.3-1"), false),
+            ("1.2.3+build.5", ConvertTarget::Debian, Some("1.2.3"), true),
+            (
+                "1.2.3+build.5",
+                ConvertTarget::Nuget,
+                Some("1.2.3+build.5"),
+                false,
+            ),
+            ("1.2.3-rc-1", ConvertTarget::Rpm, Some("1.2.3-0.rc.1"), true),
+            ("1.2.3-nightly", ConvertTarget::Pep440, None, false),
+        ] {
+            let conversion = convert_str(version, to);
+            assert_eq!(conversion.converted.as_deref(), converted, "{version} {to}");
+            assert_eq!(conversion.lossy, lossy, "{version} {to}");
+        }
+    }
+
+    #[test]
+    fn test_check_order() {
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+        ];
+        for to in ConvertTarget::value_variants() {
+            assert_eq!(
+                check_order_str(&versions, *to),
+                Vec::<String>::new(),
+                "{to}"
+            );
+        }
+
+        // Numeric identifiers sort before alphanumeric ones in semver, but
+        // after them in rpm.
+        assert_eq!(
+            check_order_str(&["1.0.0-alpha", "1.0.0-1"], ConvertTarget::Rpm),
+            ["`1.0.0-1` < `1.0.0-alpha`, but `1.0.0-0.1` > `1.0.0-0.alpha` in rpm"]
+        );
+        // Unknown qualifiers sort after releases in Maven.
+        assert_eq!(
+            check_order_str(&["1.0.0", "1.0.0-nightly"], ConvertTarget::Maven).len(),
+            1
+        );
+        // NuGet ignores case.
+        assert_eq!(
+            check_order_str(&["1.0.0-RC", "1.0.0-rc"], ConvertTarget::Nuget),
+            ["`1.0.0-RC` < `1.0.0-rc`, but `1.0.0-RC` = `1.0.0-rc` in nuget"]
+        );
+    }
+
+    #[test]
+    fn test_four_part_to_semver() {
+        for (version, policy, converted, lossy) in [
+            ("1.2", RevisionPolicy::Reject, Some("1.2.0"), false),
+            (
+                "1.2.3.0-rc.1+abc",
+                RevisionPolicy::Reject,
+                Some("1.2.3-rc.1+abc"),
+                false,
+            ),
+            ("1.2.3.4", RevisionPolicy::Reject, None, false),
+            ("1.2.3.4", RevisionPolicy::Drop, Some("1.2.3"), true),
+            (
+                "1.2.3.4",
+                RevisionPolicy::Build,
+                Some("1.2.3+revision.4"),
+                false,
+            ),
+            (
+                "1.2.3.4+abc",
+                RevisionPolicy::Build,
+                Some("1.2.3+abc.revision.4"),
+                false,
+            ),
+        ] {
+            let conversion = four_part_to_semver(version, policy);
+            assert_eq!(conversion.converted.as_deref(), converted, "{version}");
+            assert_eq!(conversion.lossy, lossy, "{version}");
+        }
+    }
+
+    #[test]
+    fn test_semver_to_four_part() {
+        let convert = |version: &str, policy, profile| {
+            semver_to_four_part(&Version::parse(version).unwrap(), policy, profile).converted
+        };
+        for (version, policy, converted) in [
+            ("1.2.3-rc.1", RevisionPolicy::Reject, Some("1.2.3.0-rc.1")),
+            (
+                "1.2.3+revision.4",
+                RevisionPolicy::Reject,
+                Some("1.2.3.0+revision.4"),
+            ),
+            ("1.2.3+revision.4", RevisionPolicy::Build, Some("1.2.3.4")),
+            (
+                "1.2.3+abc.revision.4",
+                RevisionPolicy::Build,
+                Some("1.2.3.4+abc"),
+            ),
+            (
+                "1.2.3+revision.a",
+                RevisionPolicy::Build,
+                Some("1.2.3.0+revision.a"),
+            ),
+            ("2147483648.0.0", RevisionPolicy::Reject, None),
+        ] {
+            assert_eq!(
+                convert(version, policy, None).as_deref(),
+                converted,
+                "{version}"
+            );
+        }
+        assert_eq!(
+            convert("255.255.65535", RevisionPolicy::Reject, Some(Profile::Msi)).as_deref(),
+            Some("255.255.65535.0")
+        );
+        assert_eq!(
+            convert("1.256.0", RevisionPolicy::Reject, Some(Profile::Msi)),
+            None
+        );
+        assert_eq!(
+            convert("1.0.0-rc", RevisionPolicy::Reject, Some(Profile::Msi)),
+            None
+        );
+
+        // A revision survives the round trip through build metadata.
+        let there = four_part_to_semver("1.2.3.4", RevisionPolicy::Build);
+        assert_eq!(
+            convert(&there.converted.unwrap(), RevisionPolicy::Build, None).as_deref(),
+            Some("1.2.3.4")
+        );
+    }
+}
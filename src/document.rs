@@ -0,0 +1,243 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Selecting versions out of JSON / YAML documents, with a (very) small
+//! subset of `jq` paths: `.releases[].version`.
+//!
+//! A path is object keys separated by `.`, with exactly one `[]`, marking
+//! the array whose elements are being sorted. Everything after `[]` selects
+//! the version within each element.
+use semver::Version;
+use serde_json::Value;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DocumentError {
+    /// The path wasn't understood.
+    #[error("invalid path {path:?}: {reason}")]
+    InvalidPath { path: String, reason: String },
+
+    /// The document wasn't JSON or YAML.
+    #[error("unable to parse the document as JSON ({json}) or YAML ({yaml})")]
+    Parse { json: String, yaml: String },
+
+    /// The path didn't lead to something we expected.
+    #[error("{path}: {reason}")]
+    Selection { path: String, reason: String },
+
+    /// A selected version wasn't valid.
+    #[error("{path}: {version:?}: {err}")]
+    InvalidVersion {
+        path: String,
+        version: String,
+        err: semver::Error,
+    },
+}
+
+/// A path to a version field, within the elements of an array.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionPath {
+    /// Keys leading to the array.
+    array: Vec<String>,
+    /// Keys leading from an element of the array, to its version.
+    field: Vec<String>,
+}
+
+impl FromStr for VersionPath {
+    type Err = DocumentError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| DocumentError::InvalidPath {
+            path: path.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let parts: Vec<&str> = path.split("[]").collect();
+        if parts.len() != 2 {
+            return Err(invalid("expected exactly one `[]`"));
+        }
+
+        let keys = |part: &str| -> Result<Vec<String>, DocumentError> {
+            if part.contains(['[', ']']) {
+                return Err(invalid("only `[]` is supported"));
+            }
+            match part.strip_prefix('.').unwrap_or(part) {
+                "" => Ok(Vec::new()),
+                part => part
+                    .split('.')
+                    .map(|key| match key {
+                        "" => Err(invalid("empty key")),
+                        key => Ok(key.to_string()),
+                    })
+                    .collect(),
+            }
+        };
+
+        let field = match parts[1] {
+            "" => Vec::new(),
+            part if part.starts_with('.') => keys(part)?,
+            _ => return Err(invalid("expected `.` after `[]`")),
+        };
+
+        Ok(Self {
+            array: keys(parts[0])?,
+            field,
+        })
+    }
+}
+
+impl VersionPath {
+    /// Select each element of the array, and its version.
+    pub(crate) fn select(&self, document: Value) -> Result<Vec<(Version, Value)>, DocumentError> {
+        let mut document = document;
+        let mut location = String::new();
+        for key in &self.array {
+            location = format!("{location}.{key}");
+            document = match document {
+                Value::Object(mut map) => map.remove(key),
+                _ => None,
+            }
+            .ok_or_else(|| DocumentError::Selection {
+                path: location.clone(),
+                reason: "not found".to_string(),
+            })?;
+        }
+
+        let elements = match document {
+            Value::Array(elements) => elements,
+            _ => {
+                return Err(DocumentError::Selection {
+                    path: format!("{location}[]"),
+                    reason: "not an array".to_string(),
+                })
+            }
+        };
+
+        elements
+            .into_iter()
+            .enumerate()
+            .map(|(index, element)| {
+                let mut location = format!("{location}[{index}]");
+                let mut field = &element;
+                for key in &self.field {
+                    location = format!("{location}.{key}");
+                    field = field.get(key).ok_or_else(|| DocumentError::Selection {
+                        path: location.clone(),
+                        reason: "not found".to_string(),
+                    })?;
+                }
+                let version = field.as_str().ok_or_else(|| DocumentError::Selection {
+                    path: location.clone(),
+                    reason: "not a string".to_string(),
+                })?;
+                let version =
+                    Version::parse(version).map_err(|err| DocumentError::InvalidVersion {
+                        path: location,
+                        version: version.to_string(),
+                        err,
+                    })?;
+                Ok((version, element))
+            })
+            .collect()
+    }
+}
+
+/// Parse a document as JSON, or failing that, YAML.
+pub(crate) fn parse(input: &str) -> Result<Value, DocumentError> {
+    serde_json::from_str(input).or_else(|json| {
+        serde_yaml::from_str(input).map_err(|yaml| DocumentError::Parse {
+            json: json.to_string(),
+            yaml: yaml.to_string(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_version_path() {
+        assert_eq!(
+            ".releases[].version".parse::<VersionPath>().unwrap(),
+            VersionPath {
+                array: vec!["releases".to_string()],
+                field: vec!["version".to_string()],
+            }
+        );
+        assert_eq!(
+            "[]".parse::<VersionPath>().unwrap(),
+            VersionPath {
+                array: vec![],
+                field: vec![],
+            }
+        );
+        assert_eq!(
+            ".[].meta.version".parse::<VersionPath>().unwrap(),
+            VersionPath {
+                array: vec![],
+                field: vec!["meta".to_string(), "version".to_string()],
+            }
+        );
+
+        assert!(".releases.version".parse::<VersionPath>().is_err());
+        assert!(".a[].b[].version".parse::<VersionPath>().is_err());
+        assert!(".a[0].version".parse::<VersionPath>().is_err());
+        assert!(".a..b[].version".parse::<VersionPath>().is_err());
+        assert!(".a[]version".parse::<VersionPath>().is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        let document = json!({
+            "releases": [
+                {"name": "b", "version": "1.10.0"},
+                {"name": "a", "version": "1.9.0"},
+            ]
+        });
+        let path: VersionPath = ".releases[].version".parse().unwrap();
+        let selected = path.select(document.clone()).unwrap();
+        assert_eq!(selected.len(), 2);
+        assert_eq!(selected[0].0, Version::parse("1.10.0").unwrap());
+        assert_eq!(selected[1].1, json!({"name": "a", "version": "1.9.0"}));
+
+        let path: VersionPath = ".releases[].name".parse().unwrap();
+        assert!(path.select(document.clone()).is_err());
+        let path: VersionPath = ".missing[].version".parse().unwrap();
+        assert!(path.select(document.clone()).is_err());
+        let path: VersionPath = ".releases[].missing".parse().unwrap();
+        assert!(path.select(document.clone()).is_err());
+        let path: VersionPath = "[].version".parse().unwrap();
+        assert!(path.select(document).is_err());
+
+        let path: VersionPath = "[]".parse().unwrap();
+        assert_eq!(path.select(json!(["1.0.0"])).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(r#"[{"version": "1.0.0"}]"#).unwrap(),
+            json!([{"version": "1.0.0"}])
+        );
+        assert_eq!(
+            parse("- version: 1.0.0\n").unwrap(),
+            json!([{"version": "1.0.0"}])
+        );
+        assert!(parse("- [").is_err());
+    }
+}
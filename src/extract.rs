@@ -0,0 +1,178 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Finding Semantic Versions in arbitrary text.
+//!
+//! The [regex] crate has no look-around, so word boundaries are checked by
+//! hand around each [SEMVER_REGEX] match. A match is only accepted when:
+//!
+//! - It isn't preceded by an alphanumeric character, or a `.`, so `a1.2.3`
+//!   and the tail of `1.1.2.3` are not versions. An optional `v` or `V`
+//!   prefix is allowed, under the same rule (`v1.2.3`, but not `dev1.2.3`).
+//!
+//! - It isn't followed by an alphanumeric character, or by one of `.`, `-`,
+//!   `+` followed by an alphanumeric character, so `1.2.3.4` is not a
+//!   version, but the end of the sentence `released 1.2.3.` is.
+use regex::Regex;
+
+use crate::regex::SEMVER_REGEX;
+
+/// A Semantic Version found in a line of text.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Found<'a> {
+    /// Byte offset of the match, including any `v` prefix.
+    pub(crate) start: usize,
+    /// Byte offset of the end of the match.
+    pub(crate) end: usize,
+    /// The version, without any `v` prefix.
+    pub(crate) version: &'a str,
+}
+
+pub(crate) struct Extractor {
+    re: Regex,
+}
+
+impl Extractor {
+    pub(crate) fn new() -> Self {
+        Self {
+            // Static string, always expected to pass being a valid regex.
+            re: Regex::new(SEMVER_REGEX).unwrap(),
+        }
+    }
+
+    /// Find all of the versions in a line of text.
+    pub(crate) fn find<'a>(&self, line: &'a str) -> Vec<Found<'a>> {
+        let bytes = line.as_bytes();
+        let mut found = Vec::new();
+        let mut position = 0;
+
+        while let Some(m) = self.re.find_at(line, position) {
+            let start = match m.start() {
+                s if s > 0 && matches!(bytes[s - 1], b'v' | b'V') => s - 1,
+                s => s,
+            };
+
+            if boundary_before(bytes, start) && boundary_after(bytes, m.end()) {
+                found.push(Found {
+                    start,
+                    end: m.end(),
+                    version: m.as_str(),
+                });
+                position = m.end();
+            } else {
+                position = m.start() + 1;
+            }
+        }
+        found
+    }
+}
+
+fn boundary_before(bytes: &[u8], start: usize) -> bool {
+    match start.checked_sub(1).map(|i| bytes[i]) {
+        None => true,
+        Some(b) => !(b.is_ascii_alphanumeric() || b == b'.'),
+    }
+}
+
+fn boundary_after(bytes: &[u8], end: usize) -> bool {
+    match bytes.get(end) {
+        None => true,
+        Some(b) if b.is_ascii_alphanumeric() => false,
+        Some(b'.' | b'-' | b'+') => !bytes
+            .get(end + 1)
+            .is_some_and(|b| b.is_ascii_alphanumeric()),
+        Some(_) => true,
+    }
+}
+
+/// Up to `width` bytes either side of a match, on character boundaries.
+pub(crate) fn context(line: &str, start: usize, end: usize, width: usize) -> &str {
+    let mut from = start.saturating_sub(width);
+    while !line.is_char_boundary(from) {
+        from -= 1;
+    }
+    let mut to = (end + width).min(line.len());
+    while !line.is_char_boundary(to) {
+        to += 1;
+    }
+    line[from..to].trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(line: &str) -> Vec<&str> {
+        Extractor::new()
+            .find(line)
+            .iter()
+            .map(|f| f.version)
+            .collect()
+    }
+
+    #[test]
+    fn test_extractor() {
+        assert_eq!(versions("1.2.3"), vec!["1.2.3"]);
+        assert_eq!(
+            versions("myapp-1.2.3-linux.tar.gz  sha256"),
+            vec!["1.2.3-linux.tar.gz"]
+        );
+        assert_eq!(versions("FROM rust:1.85.0-slim"), vec!["1.85.0-slim"]);
+        assert_eq!(
+            versions("<a href=\"/v2.0.0-rc.1\">v2.0.0-rc.1</a>"),
+            vec!["2.0.0-rc.1", "2.0.0-rc.1"]
+        );
+        assert_eq!(versions("released 1.2.3."), vec!["1.2.3"]);
+        assert_eq!(versions("1.2.3, 4.5.6+b1"), vec!["1.2.3", "4.5.6+b1"]);
+        assert_eq!(
+            versions("99999999999999999999.0.0"),
+            vec!["99999999999999999999.0.0"]
+        );
+
+        assert!(versions("1.2.3.4").is_empty());
+        assert!(versions("a1.2.3").is_empty());
+        assert!(versions("dev1.2.3").is_empty());
+        assert!(versions("1.2").is_empty());
+        assert!(versions("01.2.3").is_empty());
+    }
+
+    #[test]
+    fn test_extractor_start() {
+        let found = Extractor::new().find("tag v1.2.3 and 2.0.0");
+        assert_eq!(
+            found,
+            vec![
+                Found {
+                    start: 4,
+                    end: 10,
+                    version: "1.2.3"
+                },
+                Found {
+                    start: 15,
+                    end: 20,
+                    version: "2.0.0"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_context() {
+        let line = "  some text around 1.2.3 and more text  ";
+        assert_eq!(context(line, 19, 24, 5), "ound 1.2.3 and");
+        assert_eq!(context(line, 19, 24, 100), line.trim());
+        assert_eq!(context("é1.2.3é", 2, 7, 1), "é1.2.3é");
+    }
+}
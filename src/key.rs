@@ -0,0 +1,305 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! An ASCII encoding of a Semantic Version, whose byte-wise lexicographic
+//! order is the same as Semantic Versioning precedence.
+//!
+//! Layout (build metadata is never part of precedence, so it is dropped):
+//!
+//! ```text
+//! N(MAJOR) "." N(MINOR) "." N(PATCH) "~"                       (release)
+//! N(MAJOR) "." N(MINOR) "." N(PATCH) "-" IDENTIFIER* "!"       (pre-release)
+//!
+//! IDENTIFIER = "." N(numeric) | ":" alphanumeric "!"
+//! ```
+//!
+//! `N` is a self delimiting number, which sorts longer numbers after shorter
+//! ones: a letter giving the digit count of the length (`A` = 1, `B` = 2,
+//! ...), then the length, then the digits themselves. So `7` is `A17`, and
+//! `1234567890` is `B101234567890`. There is no bound on the length of the
+//! numbers, which matters since [crate::regex::SEMVER_REGEX] has none.
+//!
+//! The separators were picked for their ASCII order:
+//!
+//! - `!` < `-` < `~`, so the end of an identifier list sorts first, and a
+//!   release sorts after all of its pre-releases.
+//! - `.` < `:`, so numeric identifiers sort before alphanumeric ones.
+//! - `!` < any alphanumeric character, so `alpha` sorts before `alpha1`.
+use regex::Regex;
+use std::sync::LazyLock;
+use thiserror::Error;
+
+use crate::regex::SEMVER_REGEX;
+
+#[derive(Error, Debug, PartialEq)]
+pub(crate) enum KeyError {
+    /// The input was not a Semantic Version.
+    #[error("not a valid Semantic Version: {0:?}")]
+    InvalidVersion(String),
+
+    /// The input was not a key we produced.
+    #[error("not a valid key: {key:?}: {reason}")]
+    InvalidKey { key: String, reason: String },
+}
+
+static SEMVER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!("^{SEMVER_REGEX}$")).expect("static regex should compile")
+});
+
+const RELEASE: char = '~';
+const PRERELEASE: char = '-';
+const NUMERIC: char = '.';
+const ALPHANUMERIC: char = ':';
+const END: char = '!';
+
+/// Encode a Semantic Version (of any size) into a sortable key.
+pub(crate) fn encode(version: &str) -> Result<String, KeyError> {
+    let captures = SEMVER
+        .captures(version)
+        .ok_or_else(|| KeyError::InvalidVersion(version.to_string()))?;
+
+    let mut key = String::new();
+    encode_number(&mut key, &captures[1]);
+    key.push('.');
+    encode_number(&mut key, &captures[2]);
+    key.push('.');
+    encode_number(&mut key, &captures[3]);
+
+    match captures.get(4) {
+        None => key.push(RELEASE),
+        Some(pre) => {
+            key.push(PRERELEASE);
+            for identifier in pre.as_str().split('.') {
+                if identifier.bytes().all(|b| b.is_ascii_digit()) {
+                    key.push(NUMERIC);
+                    encode_number(&mut key, identifier);
+                } else {
+                    key.push(ALPHANUMERIC);
+                    key.push_str(identifier);
+                    key.push(END);
+                }
+            }
+            key.push(END);
+        }
+    }
+    Ok(key)
+}
+
+fn encode_number(key: &mut String, digits: &str) {
+    let length = digits.len().to_string();
+    key.push((b'A' + (length.len() - 1) as u8) as char);
+    key.push_str(&length);
+    key.push_str(digits);
+}
+
+/// Decode a key back into a Semantic Version (without build metadata).
+pub(crate) fn decode(key: &str) -> Result<String, KeyError> {
+    let mut decoder = Decoder { key, position: 0 };
+
+    let mut version = decoder.number()?;
+    decoder.expect('.')?;
+    version.push('.');
+    version.push_str(&decoder.number()?);
+    decoder.expect('.')?;
+    version.push('.');
+    version.push_str(&decoder.number()?);
+
+    match decoder.next()? {
+        RELEASE => {}
+        PRERELEASE => {
+            let mut identifiers = Vec::new();
+            loop {
+                match decoder.next()? {
+                    NUMERIC => identifiers.push(decoder.number()?),
+                    ALPHANUMERIC => identifiers.push(decoder.until(END)?),
+                    END => break,
+                    c => return Err(decoder.error(&format!("unexpected {c:?}"))),
+                }
+            }
+            if identifiers.is_empty() {
+                return Err(decoder.error("empty pre-release"));
+            }
+            version.push('-');
+            version.push_str(&identifiers.join("."));
+        }
+        c => return Err(decoder.error(&format!("unexpected {c:?}"))),
+    }
+
+    if decoder.position != key.len() {
+        return Err(decoder.error("trailing characters"));
+    }
+
+    // Round trip, to make sure nothing odd made it through.
+    match encode(&version) {
+        Ok(k) if k == key => Ok(version),
+        _ => Err(decoder.error("not in canonical form")),
+    }
+}
+
+struct Decoder<'a> {
+    key: &'a str,
+    position: usize,
+}
+
+impl Decoder<'_> {
+    fn error(&self, reason: &str) -> KeyError {
+        KeyError::InvalidKey {
+            key: self.key.to_string(),
+            reason: format!("{reason} at {}", self.position),
+        }
+    }
+
+    fn next(&mut self) -> Result<char, KeyError> {
+        let c = self.key[self.position..]
+            .chars()
+            .next()
+            .ok_or_else(|| self.error("unexpected end"))?;
+        self.position += c.len_utf8();
+        Ok(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), KeyError> {
+        match self.next()? {
+            c if c == expected => Ok(()),
+            c => Err(self.error(&format!("expected {expected:?}, found {c:?}"))),
+        }
+    }
+
+    fn take(&mut self, count: usize) -> Result<&str, KeyError> {
+        let end = self.position + count;
+        match self.key.get(self.position..end) {
+            Some(s) => {
+                self.position = end;
+                Ok(s)
+            }
+            None => Err(self.error("unexpected end")),
+        }
+    }
+
+    fn until(&mut self, terminator: char) -> Result<String, KeyError> {
+        match self.key[self.position..].find(terminator) {
+            Some(offset) => {
+                let s = self.take(offset)?.to_string();
+                self.position += terminator.len_utf8();
+                Ok(s)
+            }
+            None => Err(self.error(&format!("missing {terminator:?}"))),
+        }
+    }
+
+    fn number(&mut self) -> Result<String, KeyError> {
+        let width = match self.next()? {
+            c @ 'A'..='Z' => (c as u8 - b'A' + 1) as usize,
+            c => return Err(self.error(&format!("unexpected {c:?}"))),
+        };
+        let length = self.take(width)?;
+        let length = length
+            .parse::<usize>()
+            .map_err(|e| self.error(&e.to_string()))?;
+        let digits = self.take(length)?;
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(self.error("expected digits"));
+        }
+        Ok(digits.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use semver::Version;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode("1.2.3").unwrap(), "A11.A12.A13~");
+        assert_eq!(encode("10.0.0+build").unwrap(), "A210.A10.A10~");
+        assert_eq!(encode("1.0.0-alpha.1").unwrap(), "A11.A10.A10-:alpha!.A11!");
+        assert_eq!(encode("1234567890.0.0").unwrap(), "B101234567890.A10.A10~");
+        assert!(encode("1.2").is_err());
+        assert!(encode("01.2.3").is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        for v in [
+            "1.2.3",
+            "0.0.0-0",
+            "1.0.0-alpha.beta.1",
+            "1.0.0-x-y-z.--",
+            "18446744073709551616.0.0-00a.1",
+        ] {
+            assert_eq!(decode(&encode(v).unwrap()).unwrap(), v);
+        }
+        assert_eq!(decode(&encode("1.2.3+build").unwrap()).unwrap(), "1.2.3");
+
+        assert!(decode("").is_err());
+        assert!(decode("A11.A12.A13").is_err());
+        assert!(decode("A11.A12.A13~~").is_err());
+        assert!(decode("A11.A12.A13-!").is_err());
+        assert!(decode("A11.A12.A201~").is_err());
+        assert!(decode("A11.A12.A1x~").is_err());
+    }
+
+    // The spec's own example ordering, plus some numbers that need more
+    // width than others.
+    #[test]
+    fn test_key_ordering() {
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "2.0.0",
+            "2.1.0",
+            "2.1.1",
+            "9.0.0",
+            "10.0.0",
+            "1000000000.0.0",
+            "18446744073709551616.0.0",
+        ];
+
+        let keys: Vec<String> = ordered.iter().map(|v| encode(v).unwrap()).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_key_ordering_matches_semver() {
+        let mut versions: Vec<Version> = crate::regex::generate_u64_safe_semver(500)
+            .iter()
+            .map(|v| Version::parse(v).unwrap())
+            .collect();
+        versions.sort_by(|a, b| a.cmp_precedence(b));
+
+        let keys: Vec<String> = versions
+            .iter()
+            .map(|v| encode(&v.to_string()).unwrap())
+            .collect();
+        for (pair, versions) in keys.windows(2).zip(versions.windows(2)) {
+            assert_eq!(
+                pair[0].cmp(&pair[1]),
+                versions[0].cmp_precedence(&versions[1]),
+                "{} {}",
+                versions[0],
+                versions[1]
+            );
+        }
+    }
+}
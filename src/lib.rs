@@ -0,0 +1,87 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! `sem-tool`, as a library.
+//!
+//! `explain`, `compare`, `sort`, `filter-test` & `validate` work with any
+//! [VersionScheme], selected with `--scheme`, Semantic Versioning included.
+//! The other subcommands, and some options of `sort` (like `--tree`), only
+//! work with Semantic Versions. Third party versioning schemes can be
+//! implemented, added to a [SchemeRegistry], and run with those subcommands:
+//!
+//! ```no_run
+//! use sem_tool::{SchemeError, SchemeExplanation, SchemeRegistry, VersionScheme};
+//!
+//! /// Plain numbers.
+//! struct Serial;
+//!
+//! impl VersionScheme for Serial {
+//!     type Version = u64;
+//!     type Requirement = u64;
+//!
+//!     fn parse(input: &str) -> Result<u64, SchemeError> {
+//!         input.parse().map_err(|_| SchemeError::Malformed {
+//!             part: "version",
+//!             reason: format!("{input:?} is not a number"),
+//!         })
+//!     }
+//!
+//!     fn explain(version: &u64) -> SchemeExplanation {
+//!         SchemeExplanation::custom(version)
+//!     }
+//!
+//!     /// At least this number.
+//!     fn parse_requirement(input: &str) -> Result<u64, SchemeError> {
+//!         Self::parse(input)
+//!     }
+//!
+//!     fn matches(requirement: &u64, version: &u64) -> bool {
+//!         version >= requirement
+//!     }
+//! }
+//!
+//! fn main() -> Result<impl std::process::Termination, Box<dyn std::error::Error>> {
+//!     let mut registry = SchemeRegistry::default();
+//!     registry.register::<Serial>("serial", "Plain numbers.")?;
+//!     // `my-tool --scheme serial sort 10 9 100`
+//!     sem_tool::run(registry)
+//! }
+//! ```
+
+//! NOTE(canardleteer): We allow bare_urls, because CLI documentation is
+//!                     more important than rust-doc here.
+#![allow(rustdoc::bare_urls)]
+
+mod cli;
+mod constraint;
+mod convert;
+mod document;
+mod extract;
+mod key;
+mod merge;
+mod misc;
+mod parallel;
+mod record;
+mod regex;
+mod results;
+mod scheme;
+mod spill;
+mod stream;
+mod translate;
+
+pub use cli::run;
+pub use scheme::{
+    RegistryError, Scheme, SchemeError, SchemeExplanation, SchemeRegistry, VersionScheme,
+};
@@ -0,0 +1,276 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! A k-way merge of already sorted inputs, one version per line, holding
+//! only the next version of each input in memory.
+//!
+//! Inputs only need to be sorted by precedence. Versions of the same
+//! precedence (differing only in build metadata) may come in any order, and
+//! are ordered afterwards, by [crate::stream::write_grouped].
+use semver::Version;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+use thiserror::Error;
+
+use crate::results::{version_without_build_metadata, SortEntry};
+
+#[derive(Error, Debug)]
+pub enum MergeError {
+    /// An input couldn't be read.
+    #[error("{file}: unable to read: {err}")]
+    Io { file: String, err: io::Error },
+
+    /// An input contained something other than a version.
+    #[error("{file}: line {line}: {version}: {err}")]
+    Parse {
+        file: String,
+        line: usize,
+        version: String,
+        err: semver::Error,
+    },
+
+    /// An input wasn't sorted.
+    #[error("{file}: line {line}: {version} is out of order, following {previous}")]
+    Unsorted {
+        file: String,
+        line: usize,
+        version: Version,
+        previous: Version,
+    },
+
+    /// The output couldn't be written.
+    #[error("unable to write the output: {0}")]
+    Output(#[from] io::Error),
+}
+
+/// A named input, checked to be sorted as it is read.
+struct SortedInput {
+    name: String,
+    lines: io::Lines<Box<dyn BufRead>>,
+    line_no: usize,
+    previous: Option<Version>,
+    reverse: bool,
+}
+
+impl SortedInput {
+    /// The next version, and its precedence key. Blank lines are ignored.
+    fn next_entry(&mut self) -> Result<Option<(Version, Version)>, MergeError> {
+        for line in self.lines.by_ref() {
+            self.line_no += 1;
+            let line = line.map_err(|err| MergeError::Io {
+                file: self.name.clone(),
+                err,
+            })?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let version = Version::parse(line).map_err(|err| MergeError::Parse {
+                file: self.name.clone(),
+                line: self.line_no,
+                version: line.to_string(),
+                err,
+            })?;
+            let key = version_without_build_metadata(&version);
+
+            if let Some(previous) = &self.previous {
+                let expected = match self.reverse {
+                    false => Ordering::Greater,
+                    true => Ordering::Less,
+                };
+                let order = key.cmp(previous);
+                if order != Ordering::Equal && order != expected {
+                    return Err(MergeError::Unsorted {
+                        file: self.name.clone(),
+                        line: self.line_no,
+                        version,
+                        previous: previous.clone(),
+                    });
+                }
+            }
+            self.previous = Some(key.clone());
+            return Ok(Some((key, version)));
+        }
+        Ok(None)
+    }
+}
+
+/// The next version of an input, ordered for a [BinaryHeap] (a max-heap),
+/// so the next version to emit is at the top.
+#[derive(PartialEq, Eq)]
+struct Head {
+    key: Version,
+    version: Version,
+    input: usize,
+    reverse: bool,
+}
+
+impl Ord for Head {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let by_key = match self.reverse {
+            false => other.key.cmp(&self.key),
+            true => self.key.cmp(&other.key),
+        };
+        // Ties go to the earlier input, so the merge is stable.
+        by_key.then_with(|| other.input.cmp(&self.input))
+    }
+}
+
+impl PartialOrd for Head {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Merges sorted inputs, yielding versions in precedence order.
+pub(crate) struct Merger {
+    inputs: Vec<SortedInput>,
+    heap: BinaryHeap<Head>,
+    reverse: bool,
+}
+
+impl Merger {
+    /// Start merging named inputs, each sorted in ascending order (or
+    /// descending, if `reverse`).
+    pub(crate) fn new(
+        inputs: Vec<(String, Box<dyn BufRead>)>,
+        reverse: bool,
+    ) -> Result<Self, MergeError> {
+        let mut merger = Self {
+            inputs: inputs
+                .into_iter()
+                .map(|(name, reader)| SortedInput {
+                    name,
+                    lines: reader.lines(),
+                    line_no: 0,
+                    previous: None,
+                    reverse,
+                })
+                .collect(),
+            heap: BinaryHeap::new(),
+            reverse,
+        };
+        for input in 0..merger.inputs.len() {
+            merger.advance(input)?;
+        }
+        Ok(merger)
+    }
+
+    /// Read the next version of an input onto the heap.
+    fn advance(&mut self, input: usize) -> Result<(), MergeError> {
+        if let Some((key, version)) = self.inputs[input].next_entry()? {
+            self.heap.push(Head {
+                key,
+                version,
+                input,
+                reverse: self.reverse,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for Merger {
+    type Item = Result<SortEntry, MergeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.heap.pop()?;
+        Some(
+            self.advance(head.input)
+                .map(|_| SortEntry::from(head.version)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(name: &str, versions: &str) -> (String, Box<dyn BufRead>) {
+        (
+            name.to_string(),
+            Box::new(io::Cursor::new(versions.to_string())),
+        )
+    }
+
+    fn merge(inputs: Vec<(String, Box<dyn BufRead>)>, reverse: bool) -> Vec<String> {
+        Merger::new(inputs, reverse)
+            .unwrap()
+            .map(|entry| entry.unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_merger() {
+        assert_eq!(
+            merge(
+                vec![
+                    input("a", "0.1.0\n1.0.0+b\n2.0.0\n"),
+                    input("b", "\n1.0.0-rc.1\n1.0.0+a\n3.0.0"),
+                    input("c", ""),
+                ],
+                false
+            ),
+            vec![
+                "0.1.0",
+                "1.0.0-rc.1",
+                "1.0.0+b",
+                "1.0.0+a",
+                "2.0.0",
+                "3.0.0"
+            ]
+        );
+        assert_eq!(
+            merge(
+                vec![input("a", "2.0.0\n1.0.0\n"), input("b", "3.0.0\n1.0.0+a")],
+                true
+            ),
+            vec!["3.0.0", "2.0.0", "1.0.0", "1.0.0+a"]
+        );
+        // Same precedence, in any order, is still sorted.
+        assert_eq!(
+            merge(vec![input("a", "1.0.0+b\n1.0.0+a\n1.0.0")], false),
+            vec!["1.0.0+b", "1.0.0+a", "1.0.0"]
+        );
+    }
+
+    #[test]
+    fn test_merger_errors() {
+        let err = Merger::new(vec![input("a", "1.0.0\n2.0.0\n1.5.0\n")], false)
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "a: line 3: 1.5.0 is out of order, following 2.0.0"
+        );
+
+        let err = Merger::new(vec![input("a", "1.0.0\n2.0.0\n")], true)
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "a: line 2: 2.0.0 is out of order, following 1.0.0"
+        );
+
+        assert!(matches!(
+            Merger::new(vec![input("a", "1.0\n")], false),
+            Err(MergeError::Parse { line: 1, .. })
+        ));
+    }
+}
@@ -21,6 +21,7 @@ use std::process::{ExitCode, Termination};
 use thiserror::Error;
 
 use crate::results;
+use crate::scheme::{self, VersionScheme};
 
 #[derive(Error, Debug)]
 pub(crate) enum ApplicationError {
@@ -70,23 +71,71 @@ impl Termination for ApplicationTermination {
     }
 }
 
+/// A result generic over its [VersionScheme], rendered once it's known, so
+/// results of every scheme are output the same way.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub(crate) struct Rendered {
+    value: serde_json::Value,
+    #[serde(skip)]
+    text: String,
+}
+
+impl Rendered {
+    fn new<T: Serialize + fmt::Display>(result: &T) -> Self {
+        Rendered {
+            value: serde_json::to_value(result).expect("results should serialize"),
+            text: result.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Rendered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
 #[derive(Serialize)]
 #[serde(untagged)]
 pub(crate) enum ApplicationOutput {
     /// Asseration by this program
     ComparisonStatement(results::ComparisonStatement),
-    /// Ordered Map representation of versions
-    OrderedVersionMap(results::OrderedVersionMap),
-    /// Breakdown of version
-    VersionExplaination(results::VersionExplanation),
-    /// Flat list of versions
-    FlatVersionsList(results::FlatVersionsList),
+    /// Ordered Map representation of versions, in any scheme
+    OrderedVersionMap(Rendered),
+    /// Flat list of versions, in any scheme
+    FlatVersionsList(Rendered),
     /// Flat list of strings
     FlatStringList(results::FlatStringList),
     /// Results from a filter test
     FilterTestResult(results::FilterTestResult),
     /// Results from a test
     ValidateResult(results::ValidateResult),
+    /// Translation of a requirement between dialects
+    TranslationResult(results::TranslationResult),
+    /// Conversion of versions into another scheme
+    ConversionResult(results::ConversionResult),
+    /// A requirement inferred from a set of versions
+    InferredRequirement(results::InferredRequirement),
+    /// The impact of a release on dependents
+    ReleaseImpactReport(results::ReleaseImpactReport),
+    /// Versions, alongside their sortable keys
+    KeyedVersionsList(results::KeyedVersionsList),
+    /// Versions found in some text
+    ExtractResult(results::ExtractResult),
+    /// Results from checking a list is sorted
+    SortCheckResult(results::SortCheckResult),
+    /// Versions, without duplicates
+    UniqueVersionsList(results::UniqueVersionsList),
+    /// Versions nested by MAJOR, MINOR & PATCH
+    VersionTree(results::VersionTree),
+    /// Breakdown of a version, in any scheme
+    SchemeExplanation(scheme::SchemeExplanation),
+    /// The next version, in a CalVer format
+    CalverNext(scheme::calver::CalverNext),
+    /// Output that has already been written, as it was produced
+    #[serde(skip)]
+    Streamed,
 }
 
 impl From<results::ComparisonStatement> for ApplicationOutput {
@@ -95,20 +144,15 @@ impl From<results::ComparisonStatement> for ApplicationOutput {
     }
 }
 
-impl From<results::OrderedVersionMap> for ApplicationOutput {
-    fn from(value: results::OrderedVersionMap) -> Self {
-        ApplicationOutput::OrderedVersionMap(value)
-    }
-}
-impl From<results::VersionExplanation> for ApplicationOutput {
-    fn from(value: results::VersionExplanation) -> Self {
-        ApplicationOutput::VersionExplaination(value)
+impl<S: VersionScheme> From<results::OrderedVersionMap<S>> for ApplicationOutput {
+    fn from(value: results::OrderedVersionMap<S>) -> Self {
+        ApplicationOutput::OrderedVersionMap(Rendered::new(&value))
     }
 }
 
-impl From<results::FlatVersionsList> for ApplicationOutput {
-    fn from(value: results::FlatVersionsList) -> Self {
-        ApplicationOutput::FlatVersionsList(value)
+impl<S: VersionScheme> From<results::FlatVersionsList<S>> for ApplicationOutput {
+    fn from(value: results::FlatVersionsList<S>) -> Self {
+        ApplicationOutput::FlatVersionsList(Rendered::new(&value))
     }
 }
 
@@ -124,6 +168,78 @@ impl From<results::ValidateResult> for ApplicationOutput {
     }
 }
 
+impl From<results::TranslationResult> for ApplicationOutput {
+    fn from(value: results::TranslationResult) -> Self {
+        ApplicationOutput::TranslationResult(value)
+    }
+}
+
+impl From<results::ConversionResult> for ApplicationOutput {
+    fn from(value: results::ConversionResult) -> Self {
+        ApplicationOutput::ConversionResult(value)
+    }
+}
+
+impl From<results::InferredRequirement> for ApplicationOutput {
+    fn from(value: results::InferredRequirement) -> Self {
+        ApplicationOutput::InferredRequirement(value)
+    }
+}
+
+impl From<results::ReleaseImpactReport> for ApplicationOutput {
+    fn from(value: results::ReleaseImpactReport) -> Self {
+        ApplicationOutput::ReleaseImpactReport(value)
+    }
+}
+
+impl From<results::KeyedVersionsList> for ApplicationOutput {
+    fn from(value: results::KeyedVersionsList) -> Self {
+        ApplicationOutput::KeyedVersionsList(value)
+    }
+}
+
+impl From<results::ExtractResult> for ApplicationOutput {
+    fn from(value: results::ExtractResult) -> Self {
+        ApplicationOutput::ExtractResult(value)
+    }
+}
+
+impl From<results::SortCheckResult> for ApplicationOutput {
+    fn from(value: results::SortCheckResult) -> Self {
+        ApplicationOutput::SortCheckResult(value)
+    }
+}
+
+impl From<results::UniqueVersionsList> for ApplicationOutput {
+    fn from(value: results::UniqueVersionsList) -> Self {
+        ApplicationOutput::UniqueVersionsList(value)
+    }
+}
+
+impl From<results::VersionTree> for ApplicationOutput {
+    fn from(value: results::VersionTree) -> Self {
+        ApplicationOutput::VersionTree(value)
+    }
+}
+
+impl From<scheme::SchemeExplanation> for ApplicationOutput {
+    fn from(value: scheme::SchemeExplanation) -> Self {
+        ApplicationOutput::SchemeExplanation(value)
+    }
+}
+
+impl From<scheme::calver::CalverNext> for ApplicationOutput {
+    fn from(value: scheme::calver::CalverNext) -> Self {
+        ApplicationOutput::CalverNext(value)
+    }
+}
+
+impl From<results::FlatStringList> for ApplicationOutput {
+    fn from(value: results::FlatStringList) -> Self {
+        ApplicationOutput::FlatStringList(value)
+    }
+}
+
 impl From<results::GenerateResult> for ApplicationOutput {
     fn from(value: results::GenerateResult) -> Self {
         ApplicationOutput::FlatStringList(value.into())
@@ -140,6 +256,12 @@ impl Termination for ApplicationOutput {
             }
             ApplicationOutput::FilterTestResult(filter_test_result) => filter_test_result.report(),
             ApplicationOutput::ValidateResult(validate_result) => validate_result.report(),
+            ApplicationOutput::TranslationResult(translation_result) => translation_result.report(),
+            ApplicationOutput::ConversionResult(conversion_result) => conversion_result.report(),
+            ApplicationOutput::InferredRequirement(inferred_requirement) => {
+                inferred_requirement.report()
+            }
+            ApplicationOutput::SortCheckResult(sort_check_result) => sort_check_result.report(),
             _ => ExitCode::SUCCESS,
         }
     }
@@ -157,9 +279,6 @@ impl fmt::Display for ApplicationOutput {
             ApplicationOutput::OrderedVersionMap(v) => {
                 write!(f, "{}", v)
             }
-            ApplicationOutput::VersionExplaination(v) => {
-                write!(f, "{}", v)
-            }
             ApplicationOutput::FlatVersionsList(v) => {
                 write!(f, "{}", v)
             }
@@ -172,6 +291,40 @@ impl fmt::Display for ApplicationOutput {
             ApplicationOutput::ValidateResult(v) => {
                 write!(f, "{}", v)
             }
+            ApplicationOutput::TranslationResult(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::ConversionResult(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::InferredRequirement(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::ReleaseImpactReport(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::KeyedVersionsList(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::ExtractResult(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::SortCheckResult(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::UniqueVersionsList(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::VersionTree(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::SchemeExplanation(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::CalverNext(v) => {
+                write!(f, "{}", v)
+            }
+            ApplicationOutput::Streamed => Ok(()),
         }
     }
 }
@@ -0,0 +1,204 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Parsing and sorting on multiple threads, with [std::thread::scope] (to
+//! avoid another dependency).
+//!
+//! Results are always the same as doing the work on a single thread: chunks
+//! are put back together in their original order, and the sort is stable.
+use semver::Version;
+use std::thread;
+
+/// Split `items` into (up to) `threads` chunks of near equal size.
+fn chunks<T>(mut items: Vec<T>, threads: usize) -> Vec<Vec<T>> {
+    let size = items.len().div_ceil(threads.max(1)).max(1);
+    let mut chunks = Vec::new();
+    while items.len() > size {
+        let rest = items.split_off(size);
+        chunks.push(items);
+        items = rest;
+    }
+    chunks.push(items);
+    chunks
+}
+
+/// A failure to parse a line.
+#[derive(Debug)]
+pub(crate) struct ParseFailure<'a> {
+    /// 1-based line number.
+    pub(crate) line_no: usize,
+    pub(crate) line: &'a str,
+    pub(crate) err: semver::Error,
+}
+
+/// Parse one (trimmed) version per line. On failure, the first line that
+/// failed is reported.
+pub(crate) fn parse_lines(input: &str, threads: usize) -> Result<Vec<Version>, ParseFailure<'_>> {
+    let lines: Vec<(usize, &str)> = (1..).zip(input.lines().map(str::trim)).collect();
+
+    let parsed: Vec<Result<Vec<Version>, ParseFailure>> = thread::scope(|scope| {
+        let handles: Vec<_> = chunks(lines, threads)
+            .into_iter()
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .into_iter()
+                        .map(|(line_no, line)| {
+                            Version::parse(line).map_err(|err| ParseFailure { line_no, line, err })
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("a parsing thread panicked"))
+            .collect()
+    });
+
+    let mut versions = Vec::new();
+    for chunk in parsed {
+        versions.extend(chunk?);
+    }
+    Ok(versions)
+}
+
+/// Stable sort, with chunks sorted, then merged in pairs, on separate
+/// threads.
+pub(crate) fn sort<T: Ord + Send>(items: &mut Vec<T>, threads: usize) {
+    if threads <= 1 {
+        items.sort();
+        return;
+    }
+
+    let mut runs = thread::scope(|scope| {
+        let handles: Vec<_> = chunks(std::mem::take(items), threads)
+            .into_iter()
+            .map(|mut chunk| {
+                scope.spawn(move || {
+                    chunk.sort();
+                    chunk
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("a sorting thread panicked"))
+            .collect::<Vec<_>>()
+    });
+
+    while runs.len() > 1 {
+        runs = thread::scope(|scope| {
+            let mut handles = Vec::new();
+            let mut runs = runs.into_iter();
+            while let Some(a) = runs.next() {
+                match runs.next() {
+                    Some(b) => handles.push(scope.spawn(move || merge(a, b))),
+                    None => handles.push(scope.spawn(move || a)),
+                }
+            }
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("a merging thread panicked"))
+                .collect()
+        });
+    }
+    *items = runs.pop().unwrap_or_default();
+}
+
+/// Merge two sorted runs, preferring `a` on ties (keeping the sort stable).
+fn merge<T: Ord>(a: Vec<T>, b: Vec<T>) -> Vec<T> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+    loop {
+        let next = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if y < x => b.next(),
+            (Some(_), _) => a.next(),
+            (None, _) => b.next(),
+        };
+        match next {
+            Some(item) => merged.push(item),
+            None => return merged,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1.0.0\n 0.1.0 \n2.0.0-rc.1+build\n3.0.0\n";
+        for threads in 1..6 {
+            assert_eq!(
+                parse_lines(input, threads).unwrap(),
+                vec![
+                    Version::parse("1.0.0").unwrap(),
+                    Version::parse("0.1.0").unwrap(),
+                    Version::parse("2.0.0-rc.1+build").unwrap(),
+                    Version::parse("3.0.0").unwrap(),
+                ]
+            );
+
+            let failure = parse_lines("1.0.0\nx\n2.0.0\ny\n", threads).unwrap_err();
+            assert_eq!((failure.line_no, failure.line), (2, "x"));
+        }
+        assert!(parse_lines("", 4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sort() {
+        let input: Vec<(u8, usize)> = (0..1000).map(|i| ((i * 7919 % 13) as u8, i)).collect();
+        let mut expected = input.clone();
+        expected.sort();
+
+        for threads in [1, 2, 3, 8, 2000] {
+            let mut sorted = input.clone();
+            sort(&mut sorted, threads);
+            assert_eq!(sorted, expected);
+        }
+
+        let mut empty: Vec<u8> = Vec::new();
+        sort(&mut empty, 4);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_merge_is_stable() {
+        // Only the first element is compared.
+        #[derive(Debug, PartialEq, Eq)]
+        struct Item(u8, &'static str);
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        assert_eq!(
+            merge(
+                vec![Item(1, "a"), Item(2, "a")],
+                vec![Item(1, "b"), Item(3, "b")]
+            ),
+            vec![Item(1, "a"), Item(1, "b"), Item(2, "a"), Item(3, "b")]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Finding the version to sort a record (a line of text) by, similar to
+//! `sort -k`.
+use regex::bytes::{Regex, RegexBuilder};
+
+pub(crate) enum RecordKey {
+    /// A 1-based field. Without a delimiter, fields are separated by runs of
+    /// whitespace.
+    Field {
+        field: usize,
+        delimiter: Option<String>,
+    },
+    /// The first capture group of a regex, or the whole match if it has no
+    /// capture groups.
+    Regex(Regex),
+}
+
+impl RecordKey {
+    /// Find the version in a record, if present.
+    pub(crate) fn find<'a>(&self, record: &'a str) -> Option<&'a str> {
+        match self {
+            RecordKey::Field { field, delimiter } => {
+                let index = field.checked_sub(1)?;
+                match delimiter {
+                    None => record.split_whitespace().nth(index),
+                    Some(delimiter) => record.split(delimiter.as_str()).nth(index),
+                }
+                .map(str::trim)
+            }
+            RecordKey::Regex(re) => {
+                let captures = re.captures(record.as_bytes())?;
+                let m = captures.get(1).or_else(|| captures.get(0))?;
+                // Only a valid version is of any use, and those are ASCII.
+                std::str::from_utf8(m.as_bytes()).ok()
+            }
+        }
+    }
+}
+
+/// Parse a `--key-regex`, without Unicode support (which isn't compiled in).
+pub(crate) fn key_regex(input: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(input).unicode(false).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_key() {
+        let key = RecordKey::Field {
+            field: 2,
+            delimiter: None,
+        };
+        assert_eq!(key.find("myapp  1.2.3\tsha256"), Some("1.2.3"));
+        assert_eq!(key.find("myapp"), None);
+
+        let key = RecordKey::Field {
+            field: 3,
+            delimiter: Some(",".to_string()),
+        };
+        assert_eq!(key.find("myapp,linux, 1.2.3 ,sha256"), Some("1.2.3"));
+        assert_eq!(key.find("myapp,linux"), None);
+
+        let key = RecordKey::Field {
+            field: 0,
+            delimiter: None,
+        };
+        assert_eq!(key.find("1.2.3"), None);
+
+        let key = RecordKey::Regex(key_regex(r"myapp-(\d+\.\d+\.\S+?)-").unwrap());
+        assert_eq!(key.find("myapp-1.2.3-linux.tar.gz"), Some("1.2.3"));
+        assert_eq!(key.find("other-1.2.3-linux.tar.gz"), None);
+
+        let key = RecordKey::Regex(key_regex(r"\d+\.\d+\.\d+").unwrap());
+        assert_eq!(key.find("café 1.2.3"), Some("1.2.3"));
+        assert_eq!(key.find("myapp-1.2.3-linux.tar.gz"), Some("1.2.3"));
+    }
+}
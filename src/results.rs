@@ -14,19 +14,28 @@
 //! limitations under the License.
 //!
 //! These are generally the "Results" we're looking for, as types.
+use clap::ValueEnum;
 use std::{
     cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
     fmt,
     process::{ExitCode, Termination},
 };
 
 use indexmap::IndexMap;
 use rand::prelude::*;
-use regex::Regex;
 use semver::{BuildMetadata, Version, VersionReq};
-use serde::Serialize;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
 
+use super::constraint::{Dialect, Filter};
+use super::convert::{self, Conversion, ConvertTarget, RevisionPolicy};
+use super::extract::{self, Extractor};
+use super::key::{self, KeyError};
 use super::regex::{generate_any_valid_semver, generate_u64_safe_semver};
+use super::scheme::nuget::Profile;
+use super::scheme::semantic::{Precedence, Semver};
+use super::scheme::{Scheme, SchemeError, VersionScheme};
+use super::translate::{self, RangeSyntax, Translation};
 
 /// The result of a simple filter test.
 #[derive(Serialize, PartialEq)]
@@ -34,21 +43,6 @@ pub(crate) struct ValidateResult {
     valid: bool,
 }
 
-impl ValidateResult {
-    pub(crate) fn validate(semantic_version: String, small: bool) -> ValidateResult {
-        let pass = if small {
-            Version::parse(&semantic_version).is_ok()
-        } else {
-            // Static string, always expected to pass being a valid regex.
-            Regex::new(super::regex::SEMVER_REGEX)
-                .unwrap()
-                .is_match(&semantic_version)
-        };
-
-        ValidateResult { valid: pass }
-    }
-}
-
 impl fmt::Display for ValidateResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "valid: {}", self.valid)?;
@@ -56,6 +50,12 @@ impl fmt::Display for ValidateResult {
     }
 }
 
+impl From<bool> for ValidateResult {
+    fn from(valid: bool) -> Self {
+        ValidateResult { valid }
+    }
+}
+
 /// A equivalent of an ExitCode, for true/false.
 ///
 /// This is expected to remain stable.
@@ -76,8 +76,16 @@ pub(crate) struct FilterTestResult {
 }
 
 impl FilterTestResult {
-    pub(crate) fn filter_test(filter: &VersionReq, semantic_version: &Version) -> FilterTestResult {
-        filter.matches(semantic_version).into()
+    pub(crate) fn test<S: VersionScheme>(
+        requirement: &S::Requirement,
+        version: &S::Version,
+    ) -> Self {
+        S::matches(requirement, version).into()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn pass(&self) -> bool {
+        self.pass
     }
 }
 
@@ -195,184 +203,1422 @@ impl From<&Version> for VersionExplanation {
     }
 }
 
-impl fmt::Display for VersionExplanation {
+impl fmt::Display for VersionExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Major: {}", self.major)?;
+        writeln!(f, "Minor: {}", self.minor)?;
+        writeln!(f, "Patch: {}", self.patch)?;
+        writeln!(f, "PreRelease: {}", self.prerelease_string)?;
+        for i in self.prerelease.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        writeln!(f, "Build Metadata: {}", self.build_metadata_string)?;
+        for i in self.build_metadata.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A simple list of Versions.
+#[derive(Serialize)]
+#[serde(bound = "")]
+pub(crate) struct FlatVersionsList<S: VersionScheme = Semver> {
+    versions: Vec<SortEntry<S>>,
+    potentially_ambiguous: bool,
+}
+
+impl<S: VersionScheme> From<&mut OrderedVersionMap<S>> for FlatVersionsList<S> {
+    fn from(value: &mut OrderedVersionMap<S>) -> Self {
+        let mut flat: Vec<SortEntry<S>> = Vec::new();
+
+        value.inner.iter_mut().for_each(|vv| flat.append(vv.1));
+        Self {
+            versions: flat,
+            potentially_ambiguous: value.potentially_ambiguous,
+        }
+    }
+}
+
+impl<S: VersionScheme> fmt::Display for FlatVersionsList<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for v in self.versions.iter() {
+            writeln!(f, "{v}")?
+        }
+        Ok(())
+    }
+}
+
+/// A simple list of Strings.
+///
+/// NOTE(canardleteer): Probably could become any serializable type with Display.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct FlatStringList {
+    versions: Vec<String>,
+}
+
+impl From<GenerateResult> for FlatStringList {
+    fn from(value: GenerateResult) -> Self {
+        Self {
+            versions: value.into_inner(),
+        }
+    }
+}
+
+impl From<Vec<String>> for FlatStringList {
+    fn from(versions: Vec<String>) -> Self {
+        Self { versions }
+    }
+}
+
+impl From<ExtractResult> for FlatStringList {
+    fn from(value: ExtractResult) -> Self {
+        Self {
+            versions: value.matches.into_iter().map(|m| m.version).collect(),
+        }
+    }
+}
+
+impl fmt::Display for FlatStringList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for v in self.versions.iter() {
+            writeln!(f, "{v}")?
+        }
+        Ok(())
+    }
+}
+
+/// A version found in some text.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct ExtractedVersion {
+    file: String,
+    line: usize,
+    column: usize,
+    version: String,
+    context: String,
+}
+
+/// All of the versions found in some text.
+#[derive(Serialize, PartialEq, Default)]
+pub(crate) struct ExtractResult {
+    matches: Vec<ExtractedVersion>,
+}
+
+impl ExtractResult {
+    /// Add the versions found in a line of text. Lines and columns are
+    /// 1-based, with columns counted in bytes.
+    pub(crate) fn scan_line(
+        &mut self,
+        extractor: &Extractor,
+        file: &str,
+        line_no: usize,
+        line: &str,
+        small: bool,
+        context_width: usize,
+    ) {
+        for found in extractor.find(line) {
+            if small && Version::parse(found.version).is_err() {
+                continue;
+            }
+            self.matches.push(ExtractedVersion {
+                file: file.to_string(),
+                line: line_no,
+                column: found.start + 1,
+                version: found.version.to_string(),
+                context: extract::context(line, found.start, found.end, context_width).to_string(),
+            });
+        }
+    }
+}
+
+impl fmt::Display for ExtractResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for m in self.matches.iter() {
+            writeln!(f, "{}:{}:{}: {}", m.file, m.line, m.column, m.version)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which column(s) of a [KeyedVersionsList] are written as text.
+#[derive(PartialEq)]
+pub(crate) enum KeyedColumns {
+    Key,
+    Version,
+    Both,
+}
+
+/// A version, alongside its sortable key.
+#[derive(Serialize, PartialEq, Clone, Debug)]
+pub(crate) struct KeyedVersion {
+    version: String,
+    key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    record: Option<Record>,
+}
+
+impl KeyedVersion {
+    /// Encode a version into its key.
+    pub(crate) fn encode(version: &str) -> Result<Self, KeyError> {
+        Ok(Self {
+            key: key::encode(version)?,
+            version: version.to_string(),
+            record: None,
+        })
+    }
+
+    /// Decode a key into its version.
+    pub(crate) fn decode(key: &str) -> Result<Self, KeyError> {
+        Ok(Self {
+            version: key::decode(key)?,
+            key: key.to_string(),
+            record: None,
+        })
+    }
+}
+
+/// A list of versions, alongside their sortable keys.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct KeyedVersionsList {
+    versions: Vec<KeyedVersion>,
+    #[serde(skip)]
+    columns: KeyedColumns,
+}
+
+impl KeyedVersionsList {
+    /// Encoded versions, written as their keys.
+    pub(crate) fn encoded(versions: Vec<KeyedVersion>) -> Self {
+        Self {
+            versions,
+            columns: KeyedColumns::Key,
+        }
+    }
+
+    /// Decoded keys, written as their versions.
+    pub(crate) fn decoded(versions: Vec<KeyedVersion>) -> Self {
+        Self {
+            versions,
+            columns: KeyedColumns::Version,
+        }
+    }
+}
+
+impl From<&mut OrderedVersionMap> for KeyedVersionsList {
+    fn from(value: &mut OrderedVersionMap) -> Self {
+        let versions = value
+            .inner
+            .values()
+            .flatten()
+            .map(|e| KeyedVersion {
+                // Anything the semver crate parsed is a valid version.
+                record: e.record.clone(),
+                ..KeyedVersion::encode(&e.written).unwrap()
+            })
+            .collect();
+        Self {
+            versions,
+            columns: KeyedColumns::Both,
+        }
+    }
+}
+
+impl fmt::Display for KeyedVersionsList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for v in self.versions.iter() {
+            match self.columns {
+                KeyedColumns::Key => writeln!(f, "{}", v.key)?,
+                KeyedColumns::Version => writeln!(f, "{}", v.version)?,
+                KeyedColumns::Both => match &v.record {
+                    Some(record) => writeln!(f, "{}\t{record}", v.key)?,
+                    None => writeln!(f, "{}\t{}", v.key, v.version)?,
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Where a version being sorted was found.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub(crate) enum Record {
+    /// A line of text.
+    Line(String),
+    /// An element of a JSON / YAML document.
+    Document(serde_json::Value),
+}
+
+/// Records only need a stable order, for lexical sorting to be
+/// deterministic. Documents are compared as compact JSON.
+impl Ord for Record {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Record::Line(a), Record::Line(b)) => a.cmp(b),
+            (Record::Document(a), Record::Document(b)) => a.to_string().cmp(&b.to_string()),
+            (Record::Line(_), Record::Document(_)) => Ordering::Less,
+            (Record::Document(_), Record::Line(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Record::Line(line) => write!(f, "{line}"),
+            Record::Document(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// A version being sorted, as written, optionally carrying the record it
+/// was found in.
+///
+/// Without a record, this serializes as just the version. With a line of
+/// text, as the version and the record. With a document, as the document
+/// itself (which already contains the version).
+///
+/// Entries are ordered by precedence, then [VersionScheme::cmp_lexical],
+/// then by their record.
+pub(crate) struct SortEntry<S: VersionScheme = Semver> {
+    version: S::Version,
+    written: String,
+    record: Option<Record>,
+}
+
+impl<S: VersionScheme> SortEntry<S> {
+    pub(crate) fn parse(written: String) -> Result<Self, SchemeError> {
+        Ok(Self {
+            version: S::parse(&written)?,
+            written,
+            record: None,
+        })
+    }
+}
+
+impl SortEntry {
+    pub(crate) fn with_record(version: Version, record: Record) -> Self {
+        Self {
+            record: Some(record),
+            ..Self::from(version)
+        }
+    }
+
+    pub(crate) fn version(&self) -> &Version {
+        &self.version.0
+    }
+}
+
+impl From<Version> for SortEntry {
+    fn from(version: Version) -> Self {
+        Self {
+            written: version.to_string(),
+            version: Precedence(version),
+            record: None,
+        }
+    }
+}
+
+impl<S: VersionScheme> Clone for SortEntry<S>
+where
+    S::Version: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            version: self.version.clone(),
+            written: self.written.clone(),
+            record: self.record.clone(),
+        }
+    }
+}
+
+impl<S: VersionScheme> fmt::Debug for SortEntry<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortEntry")
+            .field("version", &self.written)
+            .field("record", &self.record)
+            .finish()
+    }
+}
+
+impl<S: VersionScheme> Ord for SortEntry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.version
+            .cmp(&other.version)
+            .then_with(|| {
+                S::cmp_lexical(
+                    &self.version,
+                    &other.version,
+                    (&self.written, &other.written),
+                )
+            })
+            .then_with(|| self.record.cmp(&other.record))
+    }
+}
+
+impl<S: VersionScheme> PartialOrd for SortEntry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: VersionScheme> PartialEq for SortEntry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: VersionScheme> Eq for SortEntry<S> {}
+
+impl PartialEq<Version> for SortEntry {
+    fn eq(&self, other: &Version) -> bool {
+        self.version.0 == *other
+    }
+}
+
+impl<S: VersionScheme> Serialize for SortEntry<S> {
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        match &self.record {
+            None => self.written.serialize(serializer),
+            Some(Record::Document(value)) => value.serialize(serializer),
+            Some(record) => {
+                let mut s = serializer.serialize_struct("SortEntry", 2)?;
+                s.serialize_field("version", &self.written)?;
+                s.serialize_field("record", record)?;
+                s.end()
+            }
+        }
+    }
+}
+
+impl<S: VersionScheme> fmt::Display for SortEntry<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.record {
+            None => write!(f, "{}", self.written),
+            Some(record) => write!(f, "{record}"),
+        }
+    }
+}
+
+/// A usefully ordered list of versions.
+///
+/// Versions of equal precedence are grouped, and keyed by
+/// [VersionScheme::precedence_key].
+#[derive(Serialize)]
+#[serde(bound = "")]
+pub(crate) struct OrderedVersionMap<S: VersionScheme = Semver> {
+    #[serde(rename(serialize = "versions"))]
+    inner: IndexMap<String, Vec<SortEntry<S>>>,
+    potentially_ambiguous: bool,
+}
+
+impl<S: VersionScheme> OrderedVersionMap<S> {
+    /// Sort entries, only keeping those matching `filter`.
+    ///
+    /// When lexically sorting, entries with identical versions are ordered by
+    /// their record.
+    pub(crate) fn from_entries(
+        mut entries: Vec<SortEntry<S>>,
+        filter: &Option<S::Requirement>,
+        lexical_sorting: bool,
+        reverse: bool,
+    ) -> Self {
+        if let Some(filter) = filter {
+            entries.retain(|e| S::matches(filter, &e.version));
+        }
+
+        // Generally sort the input, so each group is keyed by its least
+        // entry.
+        entries.sort();
+
+        let mut groups: Vec<(String, Vec<SortEntry<S>>)> = Vec::new();
+        for entry in entries {
+            match groups.last_mut() {
+                Some((_, group)) if group[0].version == entry.version => group.push(entry),
+                _ => groups.push((
+                    S::precedence_key(&entry.version, &entry.written),
+                    vec![entry],
+                )),
+            }
+        }
+
+        // Reverse the ordering, if appropriate.
+        if reverse {
+            groups.reverse();
+        }
+
+        // For each key, sort each list of versions in an appropriate order.
+        for (_, group) in groups.iter_mut() {
+            order_group(group, lexical_sorting, reverse);
+        }
+
+        let mut map = Self {
+            inner: IndexMap::from_iter(groups),
+            potentially_ambiguous: false,
+        };
+        map.update_potentially_ambiguous();
+        map
+    }
+
+    pub fn potentially_ambiguous(&self) -> bool {
+        self.potentially_ambiguous
+    }
+
+    fn update_potentially_ambiguous(&mut self) {
+        self.potentially_ambiguous = self.inner.values().any(|group| group.len() > 1);
+    }
+}
+
+/// The precedence of a group of Semantic Versions (without build metadata).
+fn group_precedence(group: &[SortEntry]) -> Version {
+    version_without_build_metadata(group[0].version())
+}
+
+impl OrderedVersionMap {
+    pub(crate) fn new(
+        versions: &[Version],
+        filter: &Option<Filter>,
+        lexical_sorting: bool,
+        reverse: bool,
+    ) -> Self {
+        let entries = versions.iter().cloned().map(SortEntry::from).collect();
+        Self::from_entries(entries, filter, lexical_sorting, reverse)
+    }
+
+    /// The precedence of each group (versions without build metadata), in
+    /// order.
+    pub(crate) fn precedence_keys(&self) -> impl DoubleEndedIterator<Item = Version> + '_ {
+        self.inner.values().map(|group| group_precedence(group))
+    }
+
+    /// Only keep versions without a pre-release.
+    pub(crate) fn retain_stable(&mut self) {
+        self.inner
+            .retain(|_, group| group[0].version().pre.is_empty());
+        self.update_potentially_ambiguous();
+    }
+
+    /// Only keep the latest precedence of each major (or minor) line.
+    pub(crate) fn retain_latest_per(&mut self, per: LatestPer) {
+        let line = |key: &Version| match per {
+            LatestPer::Major => (key.major, None),
+            LatestPer::Minor => (key.major, Some(key.minor)),
+        };
+        let mut latest: HashMap<(u64, Option<u64>), Version> = HashMap::new();
+        for key in self.precedence_keys() {
+            let current = latest.entry(line(&key)).or_insert(key.clone());
+            if key > *current {
+                *current = key;
+            }
+        }
+        let latest: HashSet<Version> = latest.into_values().collect();
+        self.inner
+            .retain(|_, group| latest.contains(&group_precedence(group)));
+        self.update_potentially_ambiguous();
+    }
+
+    /// Only keep the `limit` latest precedences.
+    pub(crate) fn retain_latest(&mut self, limit: usize) {
+        let mut keys: Vec<Version> = self.precedence_keys().collect();
+        keys.sort_by(|a, b| b.cmp(a));
+        keys.truncate(limit);
+        let latest: HashSet<Version> = keys.into_iter().collect();
+        self.inner
+            .retain(|_, group| latest.contains(&group_precedence(group)));
+        self.update_potentially_ambiguous();
+    }
+}
+
+/// Versions sharing a MAJOR.MINOR.PATCH, in a [VersionTree].
+#[derive(Serialize, Default)]
+pub(crate) struct PatchNode {
+    count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_stable: Option<Version>,
+    /// Pre-releases & build metadata variants.
+    variants: Vec<SortEntry>,
+}
+
+/// Versions sharing a MAJOR.MINOR, in a [VersionTree].
+#[derive(Serialize, Default)]
+pub(crate) struct MinorNode {
+    count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_stable: Option<Version>,
+    patches: IndexMap<u64, PatchNode>,
+}
+
+/// Versions sharing a MAJOR, in a [VersionTree].
+#[derive(Serialize, Default)]
+pub(crate) struct MajorNode {
+    count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_stable: Option<Version>,
+    minors: IndexMap<u64, MinorNode>,
+}
+
+/// Versions nested by MAJOR, MINOR & PATCH, with how many versions are in
+/// each, and the latest stable version (without a pre-release) of each.
+#[derive(Serialize, Default)]
+pub(crate) struct VersionTree {
+    count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_stable: Option<Version>,
+    majors: IndexMap<u64, MajorNode>,
+    potentially_ambiguous: bool,
+}
+
+/// Keep the greater of the latest stable version so far, and `key`.
+fn update_latest_stable(latest_stable: &mut Option<Version>, key: &Version) {
+    if key.pre.is_empty() && latest_stable.as_ref().is_none_or(|latest| key > latest) {
+        *latest_stable = Some(key.clone());
+    }
+}
+
+impl From<&mut OrderedVersionMap> for VersionTree {
+    fn from(value: &mut OrderedVersionMap) -> Self {
+        let mut tree = VersionTree {
+            potentially_ambiguous: value.potentially_ambiguous,
+            ..Default::default()
+        };
+
+        for group in value.inner.values_mut() {
+            let key = &group_precedence(group);
+            let count = group.len();
+            let major = tree.majors.entry(key.major).or_default();
+            let minor = major.minors.entry(key.minor).or_default();
+            let patch = minor.patches.entry(key.patch).or_default();
+            patch.variants.append(group);
+
+            for (node_count, latest_stable) in [
+                (&mut tree.count, &mut tree.latest_stable),
+                (&mut major.count, &mut major.latest_stable),
+                (&mut minor.count, &mut minor.latest_stable),
+                (&mut patch.count, &mut patch.latest_stable),
+            ] {
+                *node_count += count;
+                update_latest_stable(latest_stable, key);
+            }
+        }
+        tree
+    }
+}
+
+/// The count & latest stable version of a node, as text.
+fn tree_summary(count: usize, latest_stable: &Option<Version>) -> String {
+    let plural = if count == 1 { "" } else { "s" };
+    match latest_stable {
+        Some(latest) => format!("{count} version{plural}, latest stable {latest}"),
+        None => format!("{count} version{plural}"),
+    }
+}
+
+impl fmt::Display for VersionTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (major_no, major) in &self.majors {
+            writeln!(
+                f,
+                "{major_no} ({})",
+                tree_summary(major.count, &major.latest_stable)
+            )?;
+            for (minor_no, minor) in &major.minors {
+                writeln!(
+                    f,
+                    "  {major_no}.{minor_no} ({})",
+                    tree_summary(minor.count, &minor.latest_stable)
+                )?;
+                for (patch_no, patch) in &minor.patches {
+                    writeln!(
+                        f,
+                        "    {major_no}.{minor_no}.{patch_no} ({})",
+                        tree_summary(patch.count, &patch.latest_stable)
+                    )?;
+                    for variant in &patch.variants {
+                        let latest = self.latest_stable.as_ref().is_some_and(|latest| {
+                            version_without_build_metadata(variant.version()) == *latest
+                        });
+                        let marker = if latest { " (latest stable)" } else { "" };
+                        writeln!(f, "      - {variant}{marker}")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The release lines for `sort --latest-per`.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LatestPer {
+    /// Each MAJOR version.
+    Major,
+    /// Each MAJOR.MINOR version.
+    Minor,
+}
+
+/// Order the entries sharing a precedence key (differing only by build
+/// metadata, or record).
+pub(crate) fn order_group<T: Ord>(group: &mut [T], lexical_sorting: bool, reverse: bool) {
+    if lexical_sorting {
+        group.sort();
+        if reverse {
+            group.reverse();
+        }
+    } else {
+        group.shuffle(&mut rand::rng());
+        // reverse is silently ignored in this case.
+    }
+}
+
+impl<S: VersionScheme> fmt::Display for OrderedVersionMap<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // FIXME: need a better walk here
+        for key in self.inner.keys() {
+            writeln!(f, "{key}:")?;
+            if let Some(vals) = self.inner.get(key) {
+                for val in vals {
+                    writeln!(f, "\t- {val}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A version, and the line it was found on.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct CheckedVersion {
+    line: usize,
+    version: Version,
+}
+
+/// A pair of adjacent versions, that failed a check.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct CheckedPair {
+    previous: CheckedVersion,
+    next: CheckedVersion,
+    comparison: ComparisonStatement,
+}
+
+impl CheckedPair {
+    fn new(previous: &(usize, Version), next: &(usize, Version)) -> Self {
+        Self {
+            previous: CheckedVersion {
+                line: previous.0,
+                version: previous.1.clone(),
+            },
+            next: CheckedVersion {
+                line: next.0,
+                version: next.1.clone(),
+            },
+            comparison: ComparisonStatement::new(
+                &SortEntry::from(previous.1.clone()),
+                &SortEntry::from(next.1.clone()),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for CheckedPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {}, line {}: {} (Semantically: {:?}, Lexically: {:?})",
+            self.previous.line,
+            self.previous.version,
+            self.next.line,
+            self.next.version,
+            self.comparison.semantic_ordering,
+            self.comparison.lexical_ordering
+        )
+    }
+}
+
+/// The result of checking that a list of versions is already sorted.
+///
+/// There are 3 separate classes of failure, and the first pair of versions
+/// found for each is reported:
+///
+/// - `out_of_order`: the pair are not in Semantic order (or, when lexically
+///   sorting, in Lexical order, for the same precedence).
+/// - `duplicate`: the pair are identical.
+/// - `ambiguous`: the pair only differ by build metadata, so their order is
+///   potentially ambiguous.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct SortCheckResult {
+    sorted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    out_of_order: Option<CheckedPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duplicate: Option<CheckedPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ambiguous: Option<CheckedPair>,
+}
+
+impl SortCheckResult {
+    /// Check versions (and the lines they were found on), are in order.
+    pub(crate) fn check(
+        versions: &[(usize, Version)],
+        filter: &Option<Filter>,
+        lexical_sorting: bool,
+        reverse: bool,
+    ) -> Self {
+        let versions: Vec<&(usize, Version)> = versions
+            .iter()
+            .filter(|(_, v)| filter.as_ref().is_none_or(|filter| filter.matches(v)))
+            .collect();
+        let wrong_way = if reverse {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+
+        let mut out_of_order = None;
+        let mut duplicate = None;
+        let mut ambiguous = None;
+        for pair in versions.windows(2) {
+            let (previous, next) = (pair[0], pair[1]);
+            let semantic = version_without_build_metadata(&previous.1)
+                .cmp(&version_without_build_metadata(&next.1));
+
+            let failure = match semantic {
+                s if s == wrong_way => &mut out_of_order,
+                Ordering::Equal if previous.1 == next.1 => &mut duplicate,
+                Ordering::Equal if lexical_sorting && previous.1.cmp(&next.1) == wrong_way => {
+                    &mut out_of_order
+                }
+                Ordering::Equal => &mut ambiguous,
+                _ => continue,
+            };
+            if failure.is_none() {
+                *failure = Some(CheckedPair::new(previous, next));
+            }
+        }
+
+        Self {
+            sorted: out_of_order.is_none() && duplicate.is_none() && ambiguous.is_none(),
+            out_of_order,
+            duplicate,
+            ambiguous,
+        }
+    }
+}
+
+impl Termination for SortCheckResult {
+    fn report(self) -> ExitCode {
+        if self.sorted {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl fmt::Display for SortCheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "sorted: {}", self.sorted)?;
+        if let Some(pair) = &self.out_of_order {
+            writeln!(f, "out of order: {pair}")?;
+        }
+        if let Some(pair) = &self.duplicate {
+            writeln!(f, "duplicate: {pair}")?;
+        }
+        if let Some(pair) = &self.ambiguous {
+            writeln!(f, "ambiguous: {pair}")?;
+        }
+        Ok(())
+    }
+}
+
+/// What `sort --unique` considers a duplicate.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Unique {
+    /// Identical versions (including build metadata).
+    #[default]
+    Exact,
+    /// Versions of the same precedence (only differing by build metadata).
+    Precedence,
+}
+
+/// Which of a set of duplicates `sort --unique` keeps.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UniquePick {
+    /// The first seen, in the input.
+    #[default]
+    First,
+    /// The lexically greatest.
+    Max,
+    /// The lexically least.
+    Min,
+    /// Fail, if the duplicates are not all identical.
+    Fail,
+}
+
+/// Duplicates that were not identical, with `--pick fail`.
+#[derive(Debug)]
+pub(crate) struct UniqueConflict {
+    key: Version,
+    entries: Vec<SortEntry>,
+}
+
+impl fmt::Display for UniqueConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = self.entries.iter().map(|e| e.to_string()).collect();
+        write!(f, "{} is not unique: {}", self.key, entries.join(", "))
+    }
+}
+
+/// A version kept by `sort --unique`, and how many it stands for.
+#[derive(Serialize)]
+pub(crate) struct UniqueVersion {
+    version: Version,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    record: Option<Record>,
+    count: usize,
+}
+
+/// A sorted list of versions, without duplicates.
+#[derive(Serialize)]
+pub(crate) struct UniqueVersionsList {
+    versions: Vec<UniqueVersion>,
+    /// How many entries were dropped, as duplicates.
+    collapsed: usize,
+    potentially_ambiguous: bool,
+    /// Prefix each version with its count as text, like `uniq -c`.
+    #[serde(skip)]
+    counts: bool,
+}
+
+impl UniqueVersionsList {
+    /// Collapse duplicate entries (in input order), then sort what remains
+    /// like [OrderedVersionMap].
+    pub(crate) fn collapse(
+        entries: Vec<SortEntry>,
+        filter: &Option<Filter>,
+        unique: Unique,
+        pick: UniquePick,
+        lexical_sorting: bool,
+        reverse: bool,
+    ) -> Result<Self, UniqueConflict> {
+        let mut duplicates: IndexMap<Version, Vec<SortEntry>> = IndexMap::new();
+        for entry in entries {
+            if filter
+                .as_ref()
+                .is_some_and(|filter| !filter.matches(entry.version()))
+            {
+                continue;
+            }
+            let key = match unique {
+                Unique::Exact => entry.version().clone(),
+                Unique::Precedence => version_without_build_metadata(entry.version()),
+            };
+            duplicates.entry(key).or_default().push(entry);
+        }
+
+        let mut collapsed = 0;
+        let mut counts: BTreeMap<SortEntry, usize> = BTreeMap::new();
+        for (key, mut group) in duplicates {
+            collapsed += group.len() - 1;
+            let count = group.len();
+            let kept = match pick {
+                UniquePick::First => group.swap_remove(0),
+                UniquePick::Max => group.into_iter().max().expect("groups are never empty"),
+                UniquePick::Min => group.into_iter().min().expect("groups are never empty"),
+                UniquePick::Fail if group.iter().any(|e| *e != group[0]) => {
+                    return Err(UniqueConflict {
+                        key,
+                        entries: group,
+                    })
+                }
+                UniquePick::Fail => group.swap_remove(0),
+            };
+            counts.insert(kept, count);
+        }
+
+        let kept: Vec<SortEntry> = counts.keys().cloned().collect();
+        let mut map = OrderedVersionMap::from_entries(kept, &None, lexical_sorting, reverse);
+        let versions = FlatVersionsList::from(&mut map)
+            .versions
+            .into_iter()
+            .map(|entry| UniqueVersion {
+                count: counts[&entry],
+                version: entry.version.0,
+                record: entry.record,
+            })
+            .collect();
+
+        Ok(Self {
+            versions,
+            collapsed,
+            potentially_ambiguous: map.potentially_ambiguous(),
+            counts: false,
+        })
+    }
+
+    /// Prefix each version with its count as text.
+    pub(crate) fn with_counts(mut self, counts: bool) -> Self {
+        self.counts = counts;
+        self
+    }
+
+    pub(crate) fn potentially_ambiguous(&self) -> bool {
+        self.potentially_ambiguous
+    }
+}
+
+impl fmt::Display for UniqueVersionsList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for v in &self.versions {
+            if self.counts {
+                write!(f, "{:>7} ", v.count)?;
+            }
+            match &v.record {
+                None => writeln!(f, "{}", v.version)?,
+                Some(record) => writeln!(f, "{record}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A statement about the comparison about 2 versions
+#[derive(Serialize, PartialEq)]
+pub(crate) struct ComparisonStatement {
+    semantic_ordering: SerializableOrdering,
+    lexical_ordering: SerializableOrdering,
+}
+
+impl ComparisonStatement {
+    /// The semantic ordering is the scheme's precedence, and the lexical
+    /// ordering breaks ties with [VersionScheme::cmp_lexical].
+    pub(crate) fn new<S: VersionScheme>(a: &SortEntry<S>, b: &SortEntry<S>) -> Self {
+        Self {
+            semantic_ordering: a.version.cmp(&b.version).into(),
+            lexical_ordering: a.cmp(b).into(),
+        }
+    }
+
+    pub(crate) fn semantic_ordering(&self) -> &SerializableOrdering {
+        &self.semantic_ordering
+    }
+}
+
+/// The result of translating a requirement from one dialect to another.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct TranslationResult {
+    from: Dialect,
+    to: RangeSyntax,
+    requirement: String,
+    translation: Option<String>,
+    lossy: bool,
+    notes: Vec<String>,
+}
+
+impl TranslationResult {
+    pub(crate) fn new(
+        from: Dialect,
+        to: RangeSyntax,
+        requirement: String,
+        value: Translation,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            requirement,
+            translation: value.translation,
+            lossy: value.lossy,
+            notes: value.notes,
+        }
+    }
+}
+
+/// A equivalent of an ExitCode, for exact/lossy.
+impl Termination for TranslationResult {
+    fn report(self) -> ExitCode {
+        if self.lossy || self.translation.is_none() {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+impl fmt::Display for TranslationResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Major: {}", self.major)?;
-        writeln!(f, "Minor: {}", self.minor)?;
-        writeln!(f, "Patch: {}", self.patch)?;
-        writeln!(f, "PreRelease: {}", self.prerelease_string)?;
-        for i in self.prerelease.iter() {
-            writeln!(f, "- {i}")?;
+        match &self.translation {
+            Some(translation) => writeln!(f, "{translation}")?,
+            None => writeln!(f, "no translation from {} to {}", self.from, self.to)?,
         }
-        writeln!(f, "Build Metadata: {}", self.build_metadata_string)?;
-        for i in self.build_metadata.iter() {
-            writeln!(f, "- {i}")?;
+        for note in self.notes.iter() {
+            writeln!(f, "# {note}")?;
         }
         Ok(())
     }
 }
 
-/// A simple list of Versions.
+/// The result of converting versions from one scheme to another.
 #[derive(Serialize, PartialEq)]
-pub(crate) struct FlatVersionsList {
-    versions: Vec<Version>,
-    potentially_ambiguous: bool,
+pub(crate) struct ConversionResult {
+    from: Scheme,
+    to: Scheme,
+    conversions: Vec<Conversion>,
+    lossy: bool,
+    order_preserved: bool,
+    notes: Vec<String>,
 }
 
-impl From<&mut OrderedVersionMap> for FlatVersionsList {
-    fn from(value: &mut OrderedVersionMap) -> Self {
-        let mut flat: Vec<Version> = Vec::new();
+impl ConversionResult {
+    /// Convert every version, and check their order survived.
+    pub(crate) fn convert(versions: &[Version], to: ConvertTarget) -> Self {
+        Self::new(
+            Scheme::Semver,
+            to.into(),
+            versions
+                .iter()
+                .map(|version| convert::convert(version, to))
+                .collect(),
+        )
+    }
 
-        value.inner.iter_mut().for_each(|vv| flat.append(vv.1));
+    /// Convert four-part versions into Semantic Versions.
+    pub(crate) fn four_part_to_semver(versions: &[String], policy: RevisionPolicy) -> Self {
+        Self::new(
+            Scheme::Nuget,
+            Scheme::Semver,
+            versions
+                .iter()
+                .map(|version| convert::four_part_to_semver(version, policy))
+                .collect(),
+        )
+    }
+
+    /// Convert Semantic Versions into four-part versions.
+    pub(crate) fn semver_to_four_part(
+        versions: &[Version],
+        policy: RevisionPolicy,
+        profile: Option<Profile>,
+    ) -> Self {
+        Self::new(
+            Scheme::Semver,
+            Scheme::Nuget,
+            versions
+                .iter()
+                .map(|version| convert::semver_to_four_part(version, policy, profile))
+                .collect(),
+        )
+    }
+
+    fn new(from: Scheme, to: Scheme, conversions: Vec<Conversion>) -> Self {
+        let notes = convert::check_order(&conversions, from, to);
         Self {
-            versions: flat,
-            potentially_ambiguous: value.potentially_ambiguous,
+            from,
+            to,
+            lossy: conversions.iter().any(|c| c.lossy),
+            order_preserved: notes.is_empty(),
+            conversions,
+            notes,
         }
     }
 }
 
-impl fmt::Display for FlatVersionsList {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for v in self.versions.iter() {
-            writeln!(f, "{v}")?
+/// A equivalent of an ExitCode, for exact/lossy.
+impl Termination for ConversionResult {
+    fn report(self) -> ExitCode {
+        let converted = self.conversions.iter().all(|c| c.converted.is_some());
+        if self.lossy || !converted || !self.order_preserved {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
         }
-        Ok(())
     }
 }
 
-/// A simple list of Strings.
-///
-/// NOTE(canardleteer): Probably could become any serializable type with Display.
-#[derive(Serialize, PartialEq)]
-pub(crate) struct FlatStringList {
-    versions: Vec<String>,
-}
-
-impl From<GenerateResult> for FlatStringList {
-    fn from(value: GenerateResult) -> Self {
-        Self {
-            versions: value.into_inner(),
+impl fmt::Display for ConversionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for conversion in self.conversions.iter() {
+            match &conversion.converted {
+                Some(converted) => writeln!(f, "{converted}")?,
+                None => writeln!(
+                    f,
+                    "# no conversion of {} to {}",
+                    conversion.version, self.to
+                )?,
+            }
+            for note in conversion.notes.iter() {
+                writeln!(f, "# {}: {note}", conversion.version)?;
+            }
         }
+        for note in self.notes.iter() {
+            writeln!(f, "# order not preserved: {note}")?;
+        }
+        Ok(())
     }
 }
 
-impl fmt::Display for FlatStringList {
+/// How a release affects a single dependent.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ReleaseImpact {
+    /// Matched the latest, and will move to the candidate.
+    Upgrades,
+    /// Matched the latest, but can't have the candidate.
+    PinnedOut,
+    /// Didn't match the latest, but will pick up the candidate.
+    NewlyMatched,
+    /// Matches both, but the candidate isn't newer, so stays on the latest.
+    Retained,
+    /// Matches neither.
+    Unaffected,
+}
+
+impl fmt::Display for ReleaseImpact {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for v in self.versions.iter() {
-            writeln!(f, "{v}")?
+        match self {
+            ReleaseImpact::Upgrades => write!(f, "upgrades"),
+            ReleaseImpact::PinnedOut => write!(f, "pinned_out"),
+            ReleaseImpact::NewlyMatched => write!(f, "newly_matched"),
+            ReleaseImpact::Retained => write!(f, "retained"),
+            ReleaseImpact::Unaffected => write!(f, "unaffected"),
         }
-        Ok(())
     }
 }
 
-/// A usefully ordered list of versions.
-#[derive(Serialize)]
-pub(crate) struct OrderedVersionMap {
-    #[serde(rename(serialize = "versions"))]
-    inner: IndexMap<Version, Vec<Version>>,
-    potentially_ambiguous: bool,
+/// A single dependent's requirement, checked against a proposed release.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct DependentImpact {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    line: usize,
+    requirement: String,
+    matched_latest: bool,
+    matches_candidate: bool,
+    auto_selected: bool,
+    impact: ReleaseImpact,
 }
 
-impl OrderedVersionMap {
+impl DependentImpact {
     pub(crate) fn new(
-        versions: &mut Vec<Version>,
-        filter: &Option<VersionReq>,
-        lexical_sorting: bool,
-        reverse: bool,
+        name: Option<String>,
+        line: usize,
+        requirement: String,
+        filter: &Filter,
+        latest: &Version,
+        candidate: &Version,
     ) -> Self {
-        if let Some(filter) = filter {
-            versions.retain(|v| filter.matches(v));
+        let matched_latest = filter.matches(latest);
+        let matches_candidate = filter.matches(candidate);
+        let newer = candidate.cmp_precedence(latest) == Ordering::Greater;
+
+        let impact = match (matched_latest, matches_candidate) {
+            (true, true) if newer => ReleaseImpact::Upgrades,
+            (true, true) => ReleaseImpact::Retained,
+            (true, false) => ReleaseImpact::PinnedOut,
+            (false, true) => ReleaseImpact::NewlyMatched,
+            (false, false) => ReleaseImpact::Unaffected,
+        };
+
+        Self {
+            name,
+            line,
+            requirement,
+            matched_latest,
+            matches_candidate,
+            auto_selected: matches!(
+                impact,
+                ReleaseImpact::Upgrades | ReleaseImpact::NewlyMatched
+            ),
+            impact,
         }
+    }
+}
 
-        // Generally sort the input for keys into the IndexMap.
-        versions.sort();
+/// Counts of each [ReleaseImpact].
+#[derive(Serialize, PartialEq, Default)]
+pub(crate) struct ReleaseImpactSummary {
+    upgrades: usize,
+    pinned_out: usize,
+    newly_matched: usize,
+    retained: usize,
+    unaffected: usize,
+}
 
-        // Reverse the ordering, if appropriate.
-        if reverse {
-            versions.reverse()
-        }
-
-        // Create our return structure.
-        let mut ordered_version_map: IndexMap<Version, Vec<Version>> = IndexMap::new();
-
-        // Capture all keys and complete Versions.
-        for version in versions {
-            let key = version_without_build_metadata(version);
-            match ordered_version_map.get_mut(&key) {
-                Some(v) => v.push(version.clone()),
-                None => {
-                    let new_value = vec![version.clone()];
-                    let map_response = ordered_version_map.insert(key, new_value);
-                    if map_response.is_some() {
-                        panic!("should not have gotten a map response for an empty key")
-                    }
-                }
-            }
-        }
+/// A release "blast radius" report, for a candidate release, against the
+/// requirements of its dependents.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct ReleaseImpactReport {
+    latest: Version,
+    candidate: Version,
+    dependents: Vec<DependentImpact>,
+    summary: ReleaseImpactSummary,
+}
 
-        let mut potentially_ambiguous = false;
-        // For each key, sort each list of versions in an appropriate order.
-        for (_, v) in ordered_version_map.iter_mut() {
-            if lexical_sorting {
-                v.sort();
-                if reverse {
-                    v.reverse();
-                }
-            } else {
-                v.shuffle(&mut rand::rng());
-                // reverse is silently ignored in this case.
-            }
-            if v.len() > 1 {
-                potentially_ambiguous = true
+impl ReleaseImpactReport {
+    pub(crate) fn new(
+        latest: Version,
+        candidate: Version,
+        dependents: Vec<DependentImpact>,
+    ) -> Self {
+        let mut summary = ReleaseImpactSummary::default();
+        for d in dependents.iter() {
+            match d.impact {
+                ReleaseImpact::Upgrades => summary.upgrades += 1,
+                ReleaseImpact::PinnedOut => summary.pinned_out += 1,
+                ReleaseImpact::NewlyMatched => summary.newly_matched += 1,
+                ReleaseImpact::Retained => summary.retained += 1,
+                ReleaseImpact::Unaffected => summary.unaffected += 1,
             }
         }
-
         Self {
-            inner: ordered_version_map,
-            potentially_ambiguous,
+            latest,
+            candidate,
+            dependents,
+            summary,
         }
     }
 
-    pub fn potentially_ambiguous(&self) -> bool {
-        self.potentially_ambiguous
+    pub(crate) fn pinned_out(&self) -> usize {
+        self.summary.pinned_out
     }
 }
 
-impl fmt::Display for OrderedVersionMap {
+impl fmt::Display for ReleaseImpactReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // FIXME: need a better walk here
-        for key in self.inner.keys() {
-            writeln!(f, "{key}:")?;
-            if let Some(vals) = self.inner.get(key) {
-                for val in vals {
-                    writeln!(f, "\t- {val}")?;
-                }
+        writeln!(f, "{} -> {}", self.latest, self.candidate)?;
+        for d in self.dependents.iter() {
+            match &d.name {
+                Some(name) => writeln!(f, "{}\t{name}: {}", d.impact, d.requirement)?,
+                None => writeln!(f, "{}\t{}", d.impact, d.requirement)?,
             }
         }
         Ok(())
     }
 }
 
-/// A statement about the comparison about 2 versions
+/// The shape of requirement to infer.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequirementStyle {
+    /// The narrowest valid of `exact`, `tilde` or `caret`, then `range`.
+    #[default]
+    Auto,
+    /// `=MIN`
+    Exact,
+    /// `~MIN`
+    Tilde,
+    /// `^MIN`
+    Caret,
+    /// `>=MIN, <=MAX`
+    Range,
+}
+
+/// A requirement considered while inferring.
 #[derive(Serialize, PartialEq)]
-pub(crate) struct ComparisonStatement {
-    semantic_ordering: SerializableOrdering,
-    lexical_ordering: SerializableOrdering,
+pub(crate) struct RequirementCandidate {
+    style: RequirementStyle,
+    requirement: String,
+    accepts_all: bool,
+    rejects_excluded: bool,
 }
 
-impl ComparisonStatement {
-    pub(crate) fn new(a: &Version, b: &Version) -> Self {
-        let a_no_build = version_without_build_metadata(a);
-        let b_no_build = version_without_build_metadata(b);
+/// The narrowest requirement covering a set of versions.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct InferredRequirement {
+    requirement: Option<String>,
+    style: Option<RequirementStyle>,
+    candidates: Vec<RequirementCandidate>,
+}
+
+impl InferredRequirement {
+    /// Infer a requirement that accepts all of `include`, and none of
+    /// `exclude`.
+    ///
+    /// NOTE(canardleteer): `range` is always the narrowest (it can't be
+    ///                     narrower without `!=`), so `auto` only falls back
+    ///                     to it when none of the more idiomatic forms work.
+    pub(crate) fn infer(include: &[Version], exclude: &[Version], style: RequirementStyle) -> Self {
+        let ordered = OrderedVersionMap::new(include, &None, true, false);
+        let mut keys = ordered.precedence_keys();
+        let (min, max) = match (keys.next(), keys.next_back()) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min.clone(), min),
+            _ => {
+                return Self {
+                    requirement: None,
+                    style: None,
+                    candidates: Vec::new(),
+                }
+            }
+        };
+
+        let mut candidates = Vec::new();
+        if min == max {
+            candidates.push((RequirementStyle::Exact, format!("={min}")));
+        }
+        // Narrowest first, by upper bound (`^0.0.x` is narrower than `~0.0.x`).
+        let mut idiomatic = [
+            (RequirementStyle::Tilde, format!("~{min}")),
+            (RequirementStyle::Caret, format!("^{min}")),
+        ];
+        idiomatic.sort_by_key(|(_, req)| {
+            let req = VersionReq::parse(req).expect("built from a valid version");
+            translate::lower(&req.into())
+                .upper()
+                .map(|b| b.version.clone())
+        });
+        candidates.extend(idiomatic);
+        candidates.push((RequirementStyle::Range, format!(">={min}, <={max}")));
+
+        let candidates: Vec<RequirementCandidate> = candidates
+            .into_iter()
+            .map(|(style, requirement)| {
+                let req = VersionReq::parse(&requirement).expect("built from a valid version");
+                RequirementCandidate {
+                    style,
+                    accepts_all: include.iter().all(|v| req.matches(v)),
+                    rejects_excluded: !exclude.iter().any(|v| req.matches(v)),
+                    requirement,
+                }
+            })
+            .collect();
+
+        let chosen = candidates.iter().find(|c| {
+            c.accepts_all
+                && c.rejects_excluded
+                && (style == RequirementStyle::Auto || c.style == style)
+        });
 
         Self {
-            semantic_ordering: a_no_build.cmp(&b_no_build).into(),
-            lexical_ordering: a.cmp(b).into(),
+            requirement: chosen.map(|c| c.requirement.clone()),
+            style: chosen.map(|c| c.style),
+            candidates,
         }
     }
+}
 
-    pub(crate) fn semantic_ordering(&self) -> &SerializableOrdering {
-        &self.semantic_ordering
+/// A equivalent of an ExitCode, for found/not found.
+impl Termination for InferredRequirement {
+    fn report(self) -> ExitCode {
+        match self.requirement {
+            Some(_) => ExitCode::SUCCESS,
+            None => ExitCode::FAILURE,
+        }
+    }
+}
+
+impl fmt::Display for InferredRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.requirement {
+            Some(requirement) => writeln!(f, "{requirement}"),
+            None => writeln!(f, "no requirement found"),
+        }
     }
 }
 
@@ -494,6 +1740,7 @@ pub(crate) fn version_without_build_metadata(version: &Version) -> Version {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scheme::DynScheme;
 
     // NOTE(canardleteer): I normally prefer property testing for things like this,
     //                     and may add some eventually.
@@ -542,19 +1789,19 @@ mod tests {
     // Tests some simple static tests.
     #[test]
     fn test_ordered_version_map() {
-        let mut scaffold1 = ["99.0.0", "100.0.0", "0.0.1"]
+        let scaffold1: Vec<Version> = ["99.0.0", "100.0.0", "0.0.1"]
             .iter()
             .map(|v| Version::parse(v).unwrap())
             .collect();
 
-        let test = OrderedVersionMap::new(&mut scaffold1, &None, false, false);
+        let test = OrderedVersionMap::new(&scaffold1, &None, false, false);
         println!("{:?}", test.inner.keys());
-        assert!(test.inner.contains_key(&Version::parse("99.0.0").unwrap()));
-        assert!(test.inner.contains_key(&Version::parse("100.0.0").unwrap()));
-        assert!(test.inner.contains_key(&Version::parse("0.0.1").unwrap()));
+        assert!(test.inner.contains_key("99.0.0"));
+        assert!(test.inner.contains_key("100.0.0"));
+        assert!(test.inner.contains_key("0.0.1"));
         assert!(!test.potentially_ambiguous);
 
-        let mut scaffold2: Vec<Version> = vec![
+        let scaffold2: Vec<Version> = vec![
             "0.0.0-alpha.0+metadata",
             "0.0.0-alpha.0+other.metadata",
             "0.0.0-alpha.0+other.metadata.3",
@@ -581,8 +1828,8 @@ mod tests {
         .map(|v| Version::parse(v).unwrap())
         .collect();
 
-        let test = OrderedVersionMap::new(&mut scaffold2, &None, false, false);
-        let test_keys: Vec<Version> = test.inner.keys().cloned().collect();
+        let test = OrderedVersionMap::new(&scaffold2, &None, false, false);
+        let test_keys: Vec<Version> = test.precedence_keys().collect();
         assert!(test_keys.len() == 12);
         println!("{}", test_keys[0]);
         assert!(test_keys[0] == Version::parse("0.0.0-alpha.0").unwrap());
@@ -590,8 +1837,8 @@ mod tests {
         assert!(test.potentially_ambiguous);
 
         // Reverse of above test.
-        let test = OrderedVersionMap::new(&mut scaffold2, &None, false, true);
-        let test_keys: Vec<Version> = test.inner.keys().cloned().collect();
+        let test = OrderedVersionMap::new(&scaffold2, &None, false, true);
+        let test_keys: Vec<Version> = test.precedence_keys().collect();
         assert!(test_keys.len() == 12);
         println!("{}", test_keys[0]);
         assert!(test_keys[test_keys.len() - 1] == Version::parse("0.0.0-alpha.0").unwrap());
@@ -600,12 +1847,12 @@ mod tests {
 
         // Filter, this should exclude all versions with pre-releases
         let test = OrderedVersionMap::new(
-            &mut scaffold2,
-            &Some(VersionReq::parse("*").unwrap()),
+            &scaffold2,
+            &Some(VersionReq::parse("*").unwrap().into()),
             false,
             false,
         );
-        let test_keys: Vec<Version> = test.inner.keys().cloned().collect();
+        let test_keys: Vec<Version> = test.precedence_keys().collect();
         assert!(test_keys.len() == 5);
         println!("{}", test_keys[0]);
         assert!(test_keys[0] == Version::parse("0.0.1").unwrap());
@@ -615,11 +1862,305 @@ mod tests {
         let _ = format!("{}", test);
     }
 
+    #[test]
+    fn test_ordered_version_map_records() {
+        let scaffold: Vec<SortEntry> = [
+            ("1.10.0", "b,1.10.0"),
+            ("1.9.0+b", "c,1.9.0+b"),
+            ("1.9.0+a", "a,1.9.0+a"),
+        ]
+        .iter()
+        .map(|(v, r)| {
+            SortEntry::with_record(Version::parse(v).unwrap(), Record::Line(r.to_string()))
+        })
+        .collect();
+
+        let mut test = OrderedVersionMap::from_entries(scaffold, &None, true, false);
+        assert!(test.potentially_ambiguous);
+        let test = FlatVersionsList::from(&mut test);
+        let records: Vec<String> = test.versions.iter().map(|e| e.to_string()).collect();
+        assert_eq!(records, vec!["a,1.9.0+a", "c,1.9.0+b", "b,1.10.0"]);
+        assert!(test.versions[2] == Version::parse("1.10.0").unwrap());
+
+        assert_eq!(
+            serde_json::to_string(&test.versions[2]).unwrap(),
+            r#"{"version":"1.10.0","record":"b,1.10.0"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&SortEntry::from(Version::parse("1.10.0").unwrap())).unwrap(),
+            r#""1.10.0""#
+        );
+    }
+
+    #[test]
+    fn test_ordered_version_map_selection() {
+        let versions = |input: &[&str]| -> Vec<Version> {
+            input.iter().map(|v| Version::parse(v).unwrap()).collect()
+        };
+        let map = || {
+            OrderedVersionMap::new(
+                &versions(&[
+                    "1.0.0",
+                    "1.1.0",
+                    "1.1.1",
+                    "1.2.0-rc.1",
+                    "2.0.0",
+                    "2.0.1+a",
+                    "2.0.1+b",
+                    "2.1.0",
+                    "3.0.0-beta.1",
+                ]),
+                &None,
+                true,
+                false,
+            )
+        };
+        let keys = |map: &OrderedVersionMap| -> Vec<String> {
+            map.precedence_keys().map(|k| k.to_string()).collect()
+        };
+
+        let mut stable = map();
+        stable.retain_stable();
+        assert_eq!(
+            keys(&stable),
+            vec!["1.0.0", "1.1.0", "1.1.1", "2.0.0", "2.0.1", "2.1.0"]
+        );
+        assert!(stable.potentially_ambiguous());
+
+        let mut per_minor = map();
+        per_minor.retain_latest_per(LatestPer::Minor);
+        assert_eq!(
+            keys(&per_minor),
+            vec![
+                "1.0.0",
+                "1.1.1",
+                "1.2.0-rc.1",
+                "2.0.1",
+                "2.1.0",
+                "3.0.0-beta.1"
+            ]
+        );
+
+        let mut per_major = map();
+        per_major.retain_stable();
+        per_major.retain_latest_per(LatestPer::Major);
+        per_major.retain_latest(1);
+        assert_eq!(keys(&per_major), vec!["2.1.0"]);
+        assert!(!per_major.potentially_ambiguous());
+
+        let mut limited = map();
+        limited.retain_latest(3);
+        assert_eq!(keys(&limited), vec!["2.0.1", "2.1.0", "3.0.0-beta.1"]);
+        limited.retain_latest(0);
+        assert!(keys(&limited).is_empty());
+    }
+
+    #[test]
+    fn test_version_tree() {
+        let versions: Vec<Version> = [
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1+a",
+            "1.0.1+b",
+            "1.1.0",
+            "2.0.0-beta.1",
+        ]
+        .iter()
+        .map(|v| Version::parse(v).unwrap())
+        .collect();
+        let mut map = OrderedVersionMap::new(&versions, &None, true, false);
+        let tree = VersionTree::from(&mut map);
+
+        assert_eq!(tree.count, 6);
+        assert_eq!(tree.latest_stable, Some(Version::parse("1.1.0").unwrap()));
+        assert!(tree.potentially_ambiguous);
+
+        let one = &tree.majors[&1];
+        assert_eq!(one.count, 5);
+        assert_eq!(one.minors[&0].count, 4);
+        assert_eq!(
+            one.minors[&0].latest_stable,
+            Some(Version::parse("1.0.1").unwrap())
+        );
+        assert_eq!(one.minors[&0].patches[&0].variants.len(), 2);
+
+        let two = &tree.majors[&2];
+        assert_eq!(two.count, 1);
+        assert_eq!(two.latest_stable, None);
+
+        assert_eq!(
+            tree.to_string(),
+            "1 (5 versions, latest stable 1.1.0)
+  1.0 (4 versions, latest stable 1.0.1)
+    1.0.0 (2 versions, latest stable 1.0.0)
+      - 1.0.0-rc.1
+      - 1.0.0
+    1.0.1 (2 versions, latest stable 1.0.1)
+      - 1.0.1+a
+      - 1.0.1+b
+  1.1 (1 version, latest stable 1.1.0)
+    1.1.0 (1 version, latest stable 1.1.0)
+      - 1.1.0 (latest stable)
+2 (1 version)
+  2.0 (1 version)
+    2.0.0 (1 version)
+      - 2.0.0-beta.1
+"
+        );
+    }
+
+    #[test]
+    fn test_unique_versions_list() {
+        let entries = |versions: &[&str]| -> Vec<SortEntry> {
+            versions
+                .iter()
+                .map(|v| SortEntry::from(Version::parse(v).unwrap()))
+                .collect()
+        };
+        let input = entries(&["1.0.0+b", "0.1.0", "1.0.0+a", "0.1.0", "1.0.0+c", "2.0.0"]);
+        let summary = |unique: &UniqueVersionsList| -> Vec<(String, usize)> {
+            unique
+                .versions
+                .iter()
+                .map(|v| (v.version.to_string(), v.count))
+                .collect()
+        };
+
+        let exact = UniqueVersionsList::collapse(
+            input.clone(),
+            &None,
+            Unique::Exact,
+            UniquePick::First,
+            true,
+            false,
+        )
+        .unwrap();
+        assert_eq!(exact.collapsed, 1);
+        assert!(exact.potentially_ambiguous());
+        assert_eq!(
+            summary(&exact),
+            vec![
+                ("0.1.0".to_string(), 2),
+                ("1.0.0+a".to_string(), 1),
+                ("1.0.0+b".to_string(), 1),
+                ("1.0.0+c".to_string(), 1),
+                ("2.0.0".to_string(), 1),
+            ]
+        );
+
+        for (pick, kept) in [
+            (UniquePick::First, "1.0.0+b"),
+            (UniquePick::Max, "1.0.0+c"),
+            (UniquePick::Min, "1.0.0+a"),
+        ] {
+            let precedence = UniqueVersionsList::collapse(
+                input.clone(),
+                &None,
+                Unique::Precedence,
+                pick,
+                false,
+                true,
+            )
+            .unwrap();
+            assert_eq!(precedence.collapsed, 3);
+            assert!(!precedence.potentially_ambiguous());
+            assert_eq!(
+                summary(&precedence),
+                vec![
+                    ("2.0.0".to_string(), 1),
+                    (kept.to_string(), 3),
+                    ("0.1.0".to_string(), 2),
+                ]
+            );
+        }
+
+        let conflict = UniqueVersionsList::collapse(
+            input.clone(),
+            &None,
+            Unique::Precedence,
+            UniquePick::Fail,
+            false,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            conflict.to_string(),
+            "1.0.0 is not unique: 1.0.0+b, 1.0.0+a, 1.0.0+c"
+        );
+        assert!(UniqueVersionsList::collapse(
+            input,
+            &None,
+            Unique::Exact,
+            UniquePick::Fail,
+            false,
+            false
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_sort_check_result() {
+        let lines = |versions: &[&str]| -> Vec<(usize, Version)> {
+            (1..)
+                .zip(versions.iter().map(|v| Version::parse(v).unwrap()))
+                .collect()
+        };
+
+        let test =
+            SortCheckResult::check(&lines(&["1.0.0", "1.0.1", "2.0.0"]), &None, false, false);
+        assert!(test.sorted);
+
+        let test = SortCheckResult::check(&lines(&["2.0.0", "1.0.1", "1.0.0"]), &None, false, true);
+        assert!(test.sorted);
+
+        let test = SortCheckResult::check(
+            &lines(&["1.0.0", "1.2.0", "1.1.0", "1.0.0"]),
+            &None,
+            false,
+            false,
+        );
+        assert!(!test.sorted);
+        let pair = test.out_of_order.unwrap();
+        assert_eq!(pair.previous.line, 2);
+        assert_eq!(pair.next.line, 3);
+        assert_eq!(
+            pair.comparison.semantic_ordering,
+            SerializableOrdering::Greater
+        );
+        assert!(test.duplicate.is_none());
+        assert!(test.ambiguous.is_none());
+
+        let test = SortCheckResult::check(
+            &lines(&["1.0.0", "1.0.0", "1.0.1+b", "1.0.1+a"]),
+            &None,
+            false,
+            false,
+        );
+        assert!(!test.sorted);
+        assert!(test.out_of_order.is_none());
+        assert_eq!(test.duplicate.unwrap().next.line, 2);
+        assert_eq!(test.ambiguous.unwrap().next.line, 4);
+
+        // Lexically, `+b` before `+a` is also out of order.
+        let test = SortCheckResult::check(&lines(&["1.0.1+b", "1.0.1+a"]), &None, true, false);
+        assert!(test.out_of_order.is_some());
+
+        // Filtered versions are skipped.
+        let test = SortCheckResult::check(
+            &lines(&["1.0.0", "0.1.0-rc.1", "1.0.1"]),
+            &Some(VersionReq::parse("*").unwrap().into()),
+            false,
+            false,
+        );
+        assert!(test.sorted);
+    }
+
     // FlatVersionsList
     // Static test around the basic structure.
     #[test]
     fn flat_version_list() {
-        let mut scaffold: Vec<Version> = vec![
+        let scaffold: Vec<Version> = vec![
             "0.0.0-alpha.0+metadata",
             "0.0.0-alpha.0+other.metadata",
             "0.0.0-alpha.0+other.metadata.3",
@@ -647,14 +2188,14 @@ mod tests {
         .collect();
 
         // lexical sorting
-        let mut test = OrderedVersionMap::new(&mut scaffold, &None, true, false);
+        let mut test = OrderedVersionMap::new(&scaffold, &None, true, false);
         let test = FlatVersionsList::from(&mut test);
         assert!(test.versions.len() == 21);
         assert!(test.versions[0] == Version::parse("0.0.0-alpha.0+metadata").unwrap());
         assert!(test.versions[test.versions.len() - 1] == Version::parse("99.99.0-rc1.0").unwrap());
 
         // lexical sorting, reversed
-        let mut test = OrderedVersionMap::new(&mut scaffold, &None, true, true);
+        let mut test = OrderedVersionMap::new(&scaffold, &None, true, true);
         let test = FlatVersionsList::from(&mut test);
         assert!(test.versions.len() == 21);
         assert!(
@@ -698,49 +2239,50 @@ mod tests {
     // FilterTestResult
     #[test]
     fn test_filter_test_result() {
-        let test = FilterTestResult::filter_test(
-            &VersionReq::parse(">1").unwrap(),
-            &Version::parse("0.0.0").unwrap(),
+        let test = FilterTestResult::test::<Semver>(
+            &Semver::parse_requirement(">1").unwrap(),
+            &Semver::parse("0.0.0").unwrap(),
         );
         assert!(!test.pass);
         assert_eq!(test.report(), ExitCode::FAILURE);
 
-        let test = FilterTestResult::filter_test(
-            &VersionReq::parse(">1").unwrap(),
-            &Version::parse("2.0.0").unwrap(),
+        let test = FilterTestResult::test::<Semver>(
+            &Semver::parse_requirement(">1").unwrap(),
+            &Semver::parse("2.0.0").unwrap(),
         );
         assert!(test.pass);
         assert_eq!(test.report(), ExitCode::SUCCESS);
 
-        let test = FilterTestResult::filter_test(
-            &VersionReq::parse(">=1").unwrap(),
-            &Version::parse("1.0.0").unwrap(),
+        let test = FilterTestResult::test::<Semver>(
+            &Semver::parse_requirement(">=1").unwrap(),
+            &Semver::parse("1.0.0").unwrap(),
         );
         assert!(test.pass);
         assert_eq!(test.report(), ExitCode::SUCCESS);
 
         // Display Coverage
-        let test = FilterTestResult::filter_test(
-            &VersionReq::parse(">=1").unwrap(),
-            &Version::parse("1.0.0").unwrap(),
+        let test = FilterTestResult::test::<Semver>(
+            &Semver::parse_requirement(">=1").unwrap(),
+            &Semver::parse("1.0.0").unwrap(),
         );
         let _ = format!("{}", test);
     }
 
     #[test]
     fn test_validate() {
-        let test = ValidateResult::validate("0.0.0-x+b".to_string(), true);
+        let test = ValidateResult::from(Scheme::Semver.check("0.0.0-x+b").is_ok());
         assert!(test.valid);
 
-        let test = ValidateResult::validate("0.0.0-x+b".to_string(), false);
+        let test = Scheme::Semver.validate("0.0.0-x+b");
         assert!(test.valid);
 
         // This should fail.
-        let test = ValidateResult::validate("18446744073709551616.0.0-x+b".to_string(), true);
+        let test =
+            ValidateResult::from(Scheme::Semver.check("18446744073709551616.0.0-x+b").is_ok());
         assert!(!test.valid);
 
         // This should pass.
-        let test = ValidateResult::validate("18446744073709551616.0.0-x+b".to_string(), false);
+        let test = Scheme::Semver.validate("18446744073709551616.0.0-x+b");
         assert!(test.valid);
 
         // Display Coverage
@@ -762,44 +2304,144 @@ mod tests {
         let _ = format!("{}", test);
     }
 
+    #[test]
+    fn test_inferred_requirement() {
+        let versions = |list: &[&str]| -> Vec<Version> {
+            list.iter().map(|v| Version::parse(v).unwrap()).collect()
+        };
+
+        let test =
+            InferredRequirement::infer(&versions(&["1.2.3", "1.2.9"]), &[], RequirementStyle::Auto);
+        assert_eq!(test.requirement.as_deref(), Some("~1.2.3"));
+        assert_eq!(test.style, Some(RequirementStyle::Tilde));
+
+        let test = InferredRequirement::infer(
+            &versions(&["1.4.0", "1.2.3+build", "1.9.0"]),
+            &[],
+            RequirementStyle::Auto,
+        );
+        assert_eq!(test.requirement.as_deref(), Some("^1.2.3"));
+
+        // `^1.2.3` would accept an excluded version.
+        let test = InferredRequirement::infer(
+            &versions(&["1.4.0", "1.2.3", "1.9.0"]),
+            &versions(&["1.10.0"]),
+            RequirementStyle::Auto,
+        );
+        assert_eq!(test.requirement.as_deref(), Some(">=1.2.3, <=1.9.0"));
+
+        // `^0.0.3` is narrower than `~0.0.3`.
+        let test = InferredRequirement::infer(&versions(&["0.0.3"]), &[], RequirementStyle::Tilde);
+        assert_eq!(test.requirement.as_deref(), Some("~0.0.3"));
+        assert_eq!(test.candidates[1].requirement, "^0.0.3");
+
+        let test = InferredRequirement::infer(
+            &versions(&["1.2.3", "2.0.0"]),
+            &[],
+            RequirementStyle::Caret,
+        );
+        assert!(test.requirement.is_none());
+        assert_eq!(test.report(), ExitCode::FAILURE);
+
+        // An excluded version between the others, can't be inferred.
+        let test = InferredRequirement::infer(
+            &versions(&["1.2.3", "1.2.7"]),
+            &versions(&["1.2.5"]),
+            RequirementStyle::Auto,
+        );
+        assert!(test.requirement.is_none());
+
+        let test = InferredRequirement::infer(&Vec::new(), &[], RequirementStyle::Auto);
+        assert!(test.requirement.is_none());
+
+        // Display Coverage
+        let _ = format!("{}", test);
+    }
+
+    #[test]
+    fn test_release_impact_report() {
+        let latest = Version::parse("1.4.2").unwrap();
+        let candidate = Version::parse("2.0.0").unwrap();
+        let dependent = |req: &str| {
+            DependentImpact::new(
+                None,
+                0,
+                req.to_string(),
+                &VersionReq::parse(req).unwrap().into(),
+                &latest,
+                &candidate,
+            )
+        };
+
+        assert_eq!(dependent("^1.4").impact, ReleaseImpact::PinnedOut);
+        assert_eq!(dependent(">=1").impact, ReleaseImpact::Upgrades);
+        assert_eq!(dependent("^2").impact, ReleaseImpact::NewlyMatched);
+        assert_eq!(dependent("^0.1").impact, ReleaseImpact::Unaffected);
+        assert!(dependent(">=1").auto_selected);
+        assert!(!dependent("^1.4").auto_selected);
+
+        // A candidate older than the latest, is never an upgrade.
+        let test = DependentImpact::new(
+            None,
+            0,
+            ">=1".to_string(),
+            &VersionReq::parse(">=1").unwrap().into(),
+            &candidate,
+            &latest,
+        );
+        assert_eq!(test.impact, ReleaseImpact::Retained);
+        assert!(!test.auto_selected);
+
+        let test = ReleaseImpactReport::new(
+            latest.clone(),
+            candidate.clone(),
+            vec![dependent("^1.4"), dependent("^1.3"), dependent(">=1")],
+        );
+        assert_eq!(test.pinned_out(), 2);
+        assert_eq!(test.summary.upgrades, 1);
+
+        // Display Coverage
+        let _ = format!("{}", test);
+    }
+
     // ComparisonStatement
     #[test]
     fn test_comparison_statement() {
         let test = ComparisonStatement::new(
-            &Version::parse("0.0.0").unwrap(),
-            &Version::parse("2.0.0").unwrap(),
+            &SortEntry::from(Version::parse("0.0.0").unwrap()),
+            &SortEntry::from(Version::parse("2.0.0").unwrap()),
         );
         assert_eq!(test.semantic_ordering, SerializableOrdering::Less);
         assert_eq!(test.lexical_ordering, SerializableOrdering::Less);
         assert_eq!(test.report(), 100.into());
 
         let test = ComparisonStatement::new(
-            &Version::parse("2.0.0+100").unwrap(),
-            &Version::parse("2.0.0").unwrap(),
+            &SortEntry::from(Version::parse("2.0.0+100").unwrap()),
+            &SortEntry::from(Version::parse("2.0.0").unwrap()),
         );
         assert_eq!(test.semantic_ordering, SerializableOrdering::Equal);
         assert_eq!(test.lexical_ordering, SerializableOrdering::Greater);
         assert_eq!(test.report(), 112.into());
 
         let test = ComparisonStatement::new(
-            &Version::parse("2.0.0").unwrap(),
-            &Version::parse("2.0.0-rc1").unwrap(),
+            &SortEntry::from(Version::parse("2.0.0").unwrap()),
+            &SortEntry::from(Version::parse("2.0.0-rc1").unwrap()),
         );
         assert_eq!(test.semantic_ordering, SerializableOrdering::Greater);
         assert_eq!(test.lexical_ordering, SerializableOrdering::Greater);
         assert_eq!(test.report(), 122.into());
 
         let test = ComparisonStatement::new(
-            &Version::parse("2.4.2").unwrap(),
-            &Version::parse("2.4.2").unwrap(),
+            &SortEntry::from(Version::parse("2.4.2").unwrap()),
+            &SortEntry::from(Version::parse("2.4.2").unwrap()),
         );
         assert_eq!(test.semantic_ordering, SerializableOrdering::Equal);
         assert_eq!(test.lexical_ordering, SerializableOrdering::Equal);
         assert_eq!(test.report(), ExitCode::SUCCESS);
 
         let test = ComparisonStatement::new(
-            &Version::parse("2.4.2").unwrap(),
-            &Version::parse("2.4.2").unwrap(),
+            &SortEntry::from(Version::parse("2.4.2").unwrap()),
+            &SortEntry::from(Version::parse("2.4.2").unwrap()),
         );
 
         // Display Coverage
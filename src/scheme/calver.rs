@@ -0,0 +1,865 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Calendar Versioning (CalVer), in a given format.
+//!
+//! A format is tokens separated by `.`, `-` or `_`, like `YYYY.0M.0D`,
+//! `YY.0M.MICRO` or `YYYY.WW`:
+//!
+//! - `YYYY`: Full year, of at least 4 digits (2006, 2016, 2106).
+//! - `YY`: Short year, since 2000 (6, 16, 106).
+//! - `0Y`: Zero-padded year, since 2000 (06, 16, 106).
+//! - `MM`: Short month (1, 2 ... 11, 12).
+//! - `0M`: Zero-padded month (01, 02 ... 11, 12).
+//! - `WW`: Short week, since the start of the year (1, 2 ... 52, 53).
+//! - `0W`: Zero-padded week (01, 02 ... 52, 53).
+//! - `DD`: Short day (1, 2 ... 30, 31).
+//! - `0D`: Zero-padded day (01, 02 ... 30, 31).
+//! - `MAJOR`, `MINOR` & `MICRO`: Numbers, as in Semantic Versioning.
+//!
+//! Week 1 starts on January 1st, whatever the day of the week.
+//!
+//! Versions must be real dates, with exactly the padding the format asks
+//! for. They are ordered by their components, in the format's order.
+//!
+//! Without a format, a version is numbers separated by `.`, `-` or `_`,
+//! ordered number by number.
+//!
+//! Reference: https://calver.org/
+use serde::Serialize;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{
+    DynScheme, Explanation, Scheme, SchemeError, SchemeExplanation, Sorted, VersionScheme,
+};
+use crate::results::{ComparisonStatement, FilterTestResult, ValidateResult};
+
+/// A component of a CalVer format.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub(crate) enum CalverToken {
+    FullYear,
+    ShortYear,
+    ZeroPaddedYear,
+    ShortMonth,
+    ZeroPaddedMonth,
+    ShortWeek,
+    ZeroPaddedWeek,
+    ShortDay,
+    ZeroPaddedDay,
+    Major,
+    Minor,
+    Micro,
+}
+
+/// Longest first, so `YYYY` isn't read as `YY` twice.
+const TOKENS: [(&str, CalverToken); 12] = [
+    ("YYYY", CalverToken::FullYear),
+    ("MAJOR", CalverToken::Major),
+    ("MINOR", CalverToken::Minor),
+    ("MICRO", CalverToken::Micro),
+    ("YY", CalverToken::ShortYear),
+    ("0Y", CalverToken::ZeroPaddedYear),
+    ("MM", CalverToken::ShortMonth),
+    ("0M", CalverToken::ZeroPaddedMonth),
+    ("WW", CalverToken::ShortWeek),
+    ("0W", CalverToken::ZeroPaddedWeek),
+    ("DD", CalverToken::ShortDay),
+    ("0D", CalverToken::ZeroPaddedDay),
+];
+
+/// What a token means, regardless of how it's written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Year,
+    Month,
+    Week,
+    Day,
+    Major,
+    Minor,
+    Micro,
+}
+
+impl CalverToken {
+    fn name(&self) -> &'static str {
+        TOKENS
+            .iter()
+            .find(|(_, token)| token == self)
+            .map(|(name, _)| *name)
+            .expect("every token has a name")
+    }
+
+    fn field(&self) -> Field {
+        match self {
+            CalverToken::FullYear | CalverToken::ShortYear | CalverToken::ZeroPaddedYear => {
+                Field::Year
+            }
+            CalverToken::ShortMonth | CalverToken::ZeroPaddedMonth => Field::Month,
+            CalverToken::ShortWeek | CalverToken::ZeroPaddedWeek => Field::Week,
+            CalverToken::ShortDay | CalverToken::ZeroPaddedDay => Field::Day,
+            CalverToken::Major => Field::Major,
+            CalverToken::Minor => Field::Minor,
+            CalverToken::Micro => Field::Micro,
+        }
+    }
+
+    fn zero_padded(&self) -> bool {
+        matches!(
+            self,
+            CalverToken::ZeroPaddedYear
+                | CalverToken::ZeroPaddedMonth
+                | CalverToken::ZeroPaddedWeek
+                | CalverToken::ZeroPaddedDay
+        )
+    }
+
+    fn is_calendar(&self) -> bool {
+        !matches!(self.field(), Field::Major | Field::Minor | Field::Micro)
+    }
+
+    /// Read a token's digits, into the value of its field (years are
+    /// always full years).
+    fn read(&self, digits: &str) -> Result<u64, SchemeError> {
+        let part = self.name();
+        let malformed = |reason: &str| SchemeError::Malformed {
+            part,
+            reason: format!("{digits:?} {reason}"),
+        };
+        if digits.is_empty() {
+            return Err(SchemeError::Empty { part });
+        }
+        let value: u64 = digits.parse().map_err(|_| malformed("is too big"))?;
+        match self.zero_padded() {
+            true if digits.len() < 2 => return Err(malformed("is not zero-padded")),
+            true if digits.len() > 2 && digits.starts_with('0') => {
+                return Err(malformed("has a leading zero"))
+            }
+            false if digits.len() > 1 && digits.starts_with('0') => {
+                return Err(malformed("has a leading zero"))
+            }
+            _ => {}
+        }
+        if *self == CalverToken::FullYear && digits.len() < 4 {
+            return Err(malformed("is not a full year"));
+        }
+
+        let range = match self.field() {
+            Field::Month => 1..=12,
+            Field::Week => 1..=53,
+            Field::Day => 1..=31,
+            _ => 0..=u64::MAX,
+        };
+        if !range.contains(&value) {
+            return Err(malformed(&format!(
+                "is not in {}..={}",
+                range.start(),
+                range.end()
+            )));
+        }
+        match self {
+            CalverToken::ShortYear | CalverToken::ZeroPaddedYear => value
+                .checked_add(2000)
+                .ok_or_else(|| malformed("is too big")),
+            _ => Ok(value),
+        }
+    }
+
+    /// Write the value of a token's field.
+    fn write(&self, value: u64) -> Result<String, SchemeError> {
+        let value = match self {
+            CalverToken::ShortYear | CalverToken::ZeroPaddedYear => value
+                .checked_sub(2000)
+                .ok_or_else(|| SchemeError::Malformed {
+                    part: self.name(),
+                    reason: format!("can't represent {value}, before 2000"),
+                })?,
+            _ => value,
+        };
+        Ok(match self.zero_padded() {
+            true => format!("{value:02}"),
+            false => value.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for CalverToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A CalVer format, like `YYYY.0M.0D`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalverFormat {
+    format: String,
+    tokens: Vec<CalverToken>,
+    /// The separators following each token but the last.
+    separators: Vec<String>,
+}
+
+impl FromStr for CalverFormat {
+    type Err = SchemeError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        let malformed = |reason: String| SchemeError::Malformed {
+            part: "format",
+            reason,
+        };
+        let mut tokens = Vec::new();
+        let mut separators: Vec<String> = Vec::new();
+        let mut rest = format;
+        while !rest.is_empty() {
+            let Some((name, token)) = TOKENS.iter().find(|(name, _)| rest.starts_with(name)) else {
+                return Err(malformed(format!("has an unknown token at {rest:?}")));
+            };
+            tokens.push(*token);
+            rest = &rest[name.len()..];
+
+            let separator: String = rest.chars().take_while(|c| ".-_".contains(*c)).collect();
+            rest = &rest[separator.len()..];
+            match (separator.is_empty(), rest.is_empty()) {
+                (true, false) => {
+                    return Err(malformed(format!(
+                        "needs a separator (`.`, `-` or `_`) before {rest:?}"
+                    )))
+                }
+                (false, true) => return Err(malformed("ends with a separator".to_string())),
+                (false, false) => separators.push(separator),
+                (true, true) => {}
+            }
+        }
+
+        let fields: Vec<Field> = tokens.iter().map(CalverToken::field).collect();
+        for field in fields.iter() {
+            if fields.iter().filter(|f| *f == field).count() > 1 {
+                return Err(malformed(format!("has more than one {field:?}")));
+            }
+        }
+        if !fields.contains(&Field::Year) {
+            return Err(malformed("has no year".to_string()));
+        }
+        if fields.contains(&Field::Week)
+            && fields
+                .iter()
+                .any(|f| matches!(f, Field::Month | Field::Day))
+        {
+            return Err(malformed("has a week, and a month or day".to_string()));
+        }
+        if fields.contains(&Field::Day) && !fields.contains(&Field::Month) {
+            return Err(malformed("has a day, but no month".to_string()));
+        }
+
+        Ok(Self {
+            format: format.to_string(),
+            tokens,
+            separators,
+        })
+    }
+}
+
+impl fmt::Display for CalverFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format)
+    }
+}
+
+/// A version in a [CalverFormat], ordered by its components.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct CalverVersion {
+    /// The value of each token, in the format's order.
+    values: Vec<u64>,
+}
+
+/// A calendar date, in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Date {
+    year: u64,
+    month: u64,
+    day: u64,
+}
+
+fn is_leap_year(year: u64) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Date {
+    pub(crate) fn new(year: u64, month: u64, day: u64) -> Result<Self, SchemeError> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(SchemeError::Malformed {
+                part: "date",
+                reason: format!("{year:04}-{month:02}-{day:02} does not exist"),
+            });
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Today, in UTC.
+    pub(crate) fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() / 86_400)
+            .unwrap_or_default();
+        Self::from_unix_days(days)
+    }
+
+    /// Howard Hinnant's `civil_from_days`, for days since 1970-01-01.
+    fn from_unix_days(days: u64) -> Self {
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + u64::from(month <= 2);
+        Self { year, month, day }
+    }
+
+    /// The week of the year, where week 1 starts on January 1st.
+    fn week(&self) -> u64 {
+        let ordinal: u64 = (1..self.month)
+            .map(|m| days_in_month(self.year, m))
+            .sum::<u64>()
+            + self.day;
+        (ordinal - 1) / 7 + 1
+    }
+
+    fn field(&self, field: Field) -> Option<u64> {
+        match field {
+            Field::Year => Some(self.year),
+            Field::Month => Some(self.month),
+            Field::Week => Some(self.week()),
+            Field::Day => Some(self.day),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = SchemeError;
+
+    /// `YYYY-MM-DD`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let malformed = || SchemeError::Malformed {
+            part: "date",
+            reason: format!("{input:?} is not YYYY-MM-DD"),
+        };
+        let parts: Vec<&str> = input.split('-').collect();
+        let [year, month, day] = parts[..] else {
+            return Err(malformed());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(malformed());
+        }
+        let number = |s: &str| s.parse::<u64>().map_err(|_| malformed());
+        Date::new(number(year)?, number(month)?, number(day)?)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl CalverFormat {
+    fn field(&self, version: &CalverVersion, field: Field) -> Option<u64> {
+        self.tokens
+            .iter()
+            .position(|t| t.field() == field)
+            .map(|i| version.values[i])
+    }
+
+    pub(crate) fn parse(&self, input: &str) -> Result<CalverVersion, SchemeError> {
+        let mut values = Vec::with_capacity(self.tokens.len());
+        let mut rest = input;
+        for (i, token) in self.tokens.iter().enumerate() {
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+            values.push(token.read(&rest[..digits])?);
+            rest = &rest[digits..];
+
+            let separator = self
+                .separators
+                .get(i)
+                .map(String::as_str)
+                .unwrap_or_default();
+            rest = rest
+                .strip_prefix(separator)
+                .ok_or_else(|| SchemeError::Malformed {
+                    part: "version",
+                    reason: format!("{input:?} does not match {}", self.format),
+                })?;
+        }
+        if !rest.is_empty() {
+            return Err(SchemeError::Malformed {
+                part: "version",
+                reason: format!("{input:?} does not match {}", self.format),
+            });
+        }
+
+        let version = CalverVersion { values };
+        if let (Some(year), Some(month), Some(day)) = (
+            self.field(&version, Field::Year),
+            self.field(&version, Field::Month),
+            self.field(&version, Field::Day),
+        ) {
+            Date::new(year, month, day)?;
+        }
+        Ok(version)
+    }
+
+    pub(crate) fn explain(&self, input: &str) -> Result<CalverExplanation, SchemeError> {
+        let version = self.parse(input)?;
+        let mut digits = input;
+        let mut segments = Vec::new();
+        for (i, token) in self.tokens.iter().enumerate() {
+            let length = digits.bytes().take_while(u8::is_ascii_digit).count();
+            segments.push(CalverSegment {
+                kind: Some(*token),
+                value: digits[..length].to_string(),
+            });
+            let separator = self.separators.get(i).map(String::len).unwrap_or_default();
+            digits = &digits[length + separator..];
+        }
+        Ok(CalverExplanation {
+            format: Some(self.format.clone()),
+            year: self.field(&version, Field::Year),
+            month: self.field(&version, Field::Month),
+            week: self.field(&version, Field::Week),
+            day: self.field(&version, Field::Day),
+            major: self.field(&version, Field::Major),
+            minor: self.field(&version, Field::Minor),
+            micro: self.field(&version, Field::Micro),
+            segments,
+        })
+    }
+
+    /// The next version released on `date`.
+    ///
+    /// A new date resets `MAJOR`, `MINOR` & `MICRO` to 0. On the same date as
+    /// `current`, the last of them is incremented instead.
+    pub(crate) fn next(
+        &self,
+        date: Date,
+        current: Option<&str>,
+    ) -> Result<CalverNext, SchemeError> {
+        let calendar = |values: &[u64]| -> Vec<u64> {
+            self.tokens
+                .iter()
+                .zip(values)
+                .filter(|(t, _)| t.is_calendar())
+                .map(|(_, v)| *v)
+                .collect()
+        };
+        let mut values: Vec<u64> = self
+            .tokens
+            .iter()
+            .map(|t| date.field(t.field()).unwrap_or(0))
+            .collect();
+
+        if let Some(current) = current {
+            let current = self.parse(current)?;
+            match calendar(&current.values).cmp(&calendar(&values)) {
+                std::cmp::Ordering::Less => {}
+                std::cmp::Ordering::Greater => {
+                    return Err(SchemeError::Malformed {
+                        part: "version",
+                        reason: format!("is after {date}"),
+                    })
+                }
+                std::cmp::Ordering::Equal => {
+                    let last = self
+                        .tokens
+                        .iter()
+                        .rposition(|t| !t.is_calendar())
+                        .ok_or_else(|| SchemeError::Malformed {
+                            part: "format",
+                            reason: format!(
+                                "has no MAJOR, MINOR or MICRO, to release again on {date}"
+                            ),
+                        })?;
+                    values = current.values;
+                    values[last] =
+                        values[last]
+                            .checked_add(1)
+                            .ok_or_else(|| SchemeError::Malformed {
+                                part: self.tokens[last].name(),
+                                reason: "is too big to increment".to_string(),
+                            })?;
+                }
+            }
+        }
+
+        let mut version = String::new();
+        for (i, (token, value)) in self.tokens.iter().zip(values).enumerate() {
+            version.push_str(&token.write(value)?);
+            version.push_str(
+                self.separators
+                    .get(i)
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+            );
+        }
+        Ok(CalverNext {
+            version,
+            date: date.to_string(),
+        })
+    }
+}
+
+/// Versions in a [CalverFormat] must match it, and are then ordered as
+/// [Calver] orders them: the format's years since 2000 order as full years do.
+impl DynScheme for CalverFormat {
+    fn check(&self, input: &str) -> Result<String, SchemeError> {
+        self.parse(input).map(|_| input.to_string())
+    }
+
+    fn validate(&self, input: &str) -> ValidateResult {
+        self.parse(input).is_ok().into()
+    }
+
+    fn sort(
+        &self,
+        versions: Vec<String>,
+        lexical_sorting: bool,
+        reverse: bool,
+        flatten: bool,
+    ) -> Result<Sorted, SchemeError> {
+        for version in versions.iter() {
+            self.parse(version)?;
+        }
+        super::sort::<Calver>(versions, lexical_sorting, reverse, flatten)
+    }
+
+    fn compare(&self, a: &str, b: &str) -> Result<ComparisonStatement, SchemeError> {
+        self.parse(a)?;
+        self.parse(b)?;
+        super::compare::<Calver>(a, b)
+    }
+
+    fn explain(&self, input: &str) -> Result<SchemeExplanation, SchemeError> {
+        Ok(Explanation::Calver(CalverFormat::explain(self, input)?).into())
+    }
+
+    fn check_requirement(&self, input: &str) -> Result<String, SchemeError> {
+        super::check_requirement::<Calver>(input)
+    }
+
+    fn filter_test(&self, filter: &str, version: &str) -> Result<FilterTestResult, SchemeError> {
+        super::filter_test::<Calver>(filter, version)
+    }
+}
+
+/// Calendar versions, in any format.
+pub(crate) struct Calver;
+
+impl VersionScheme for Calver {
+    type Version = CalverVersion;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        if input.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        if let Some(character) = input
+            .chars()
+            .find(|c| !c.is_ascii_digit() && !".-_".contains(*c))
+        {
+            return Err(SchemeError::InvalidCharacter {
+                part: "version",
+                character,
+            });
+        }
+        let values = input
+            .split(['.', '-', '_'])
+            .map(|digits| match digits {
+                "" => Err(SchemeError::Empty { part: "segment" }),
+                digits => digits.parse().map_err(|_| SchemeError::Malformed {
+                    part: "segment",
+                    reason: format!("{digits:?} is too big"),
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(CalverVersion { values })
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Calver(CalverExplanation {
+            format: None,
+            year: None,
+            month: None,
+            week: None,
+            day: None,
+            major: None,
+            minor: None,
+            micro: None,
+            segments: version
+                .values
+                .iter()
+                .map(|value| CalverSegment {
+                    kind: None,
+                    value: value.to_string(),
+                })
+                .collect(),
+        })
+        .into()
+    }
+
+    type Requirement = Infallible;
+
+    fn parse_requirement(_: &str) -> Result<Self::Requirement, SchemeError> {
+        Err(SchemeError::Unsupported {
+            scheme: Scheme::Calver,
+            operation: "requirements",
+        })
+    }
+
+    fn matches(requirement: &Self::Requirement, _: &Self::Version) -> bool {
+        match *requirement {}
+    }
+}
+
+/// A token of a CalVer version, as written.
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct CalverSegment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<CalverToken>,
+    value: String,
+}
+
+impl fmt::Display for CalverSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            Some(kind) => write!(f, "{} ({kind:?})", self.value),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// Descriptive information about a CalVer version.
+///
+/// Years are always full years, and only the fields the format has are
+/// present. Without a format, there are only segments.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct CalverExplanation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    year: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    month: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    week: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    major: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minor: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    micro: Option<u64>,
+    segments: Vec<CalverSegment>,
+}
+
+impl fmt::Display for CalverExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(format) = &self.format {
+            writeln!(f, "Format: {format}")?;
+        }
+        for (name, value) in [
+            ("Year", self.year),
+            ("Month", self.month),
+            ("Week", self.week),
+            ("Day", self.day),
+            ("Major", self.major),
+            ("Minor", self.minor),
+            ("Micro", self.micro),
+        ] {
+            if let Some(value) = value {
+                writeln!(f, "{name}: {value}")?;
+            }
+        }
+        writeln!(f, "Segments:")?;
+        for i in self.segments.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The next CalVer version, for a date.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct CalverNext {
+    version: String,
+    date: String,
+}
+
+impl fmt::Display for CalverNext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(format: &str) -> CalverFormat {
+        format.parse().unwrap()
+    }
+
+    fn date(date: &str) -> Date {
+        date.parse().unwrap()
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format("YYYY.0M.0D").tokens,
+            [
+                CalverToken::FullYear,
+                CalverToken::ZeroPaddedMonth,
+                CalverToken::ZeroPaddedDay
+            ]
+        );
+        assert_eq!(format("YY.0M_MICRO").separators, [".", "_"]);
+
+        for input in [
+            "",
+            "YYYY.",
+            "YYYYMM",
+            "YYYY.XX",
+            "MM.DD",
+            "YYYY.YY",
+            "YYYY.WW.DD",
+            "YYYY.DD",
+            "YYYY.MICRO.MICRO",
+        ] {
+            assert!(input.parse::<CalverFormat>().is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        for (f, input, valid) in [
+            ("YYYY.MM.DD", "2024.2.29", true),
+            ("YYYY.MM.DD", "2023.2.29", false),
+            ("YYYY.MM.DD", "2024.02.29", false),
+            ("YYYY.0M.0D", "2024.02.09", true),
+            ("YYYY.0M.0D", "2024.2.9", false),
+            ("YYYY.0M.0D", "2024.13.01", false),
+            ("YY.0M.MICRO", "24.02.0", true),
+            ("YY.0M.MICRO", "24.02.00", false),
+            ("YY.0M.MICRO", "24.02", false),
+            ("0Y.0M", "06.11", true),
+            ("0Y.0M", "106.11", true),
+            ("0Y.0M", "006.11", false),
+            ("YYYY.WW", "2024.53", true),
+            ("YYYY.WW", "2024.54", false),
+            ("YYYY.WW", "2024.0", false),
+            ("YYYY.0W", "2024.05", true),
+            ("YYYY-0M-0D", "2024.01.01", false),
+            ("YYYY.MINOR", "2024.1.x", false),
+            ("YYYY.0M", "24.01", false),
+            ("YYYY.0M", "999.01", false),
+            ("YYYY.0M", "10000.01", true),
+        ] {
+            assert_eq!(format(f).parse(input).is_ok(), valid, "{f} {input}");
+        }
+        assert_eq!(format("YY.0M").parse("6.01").unwrap().values, [2006, 1]);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let f = format("YY.0M.MICRO");
+        assert!(f.parse("24.02.10").unwrap() > f.parse("24.02.9").unwrap());
+        assert!(f.parse("24.02.0").unwrap() < f.parse("24.10.0").unwrap());
+        assert!(f.parse("106.01.0").unwrap() > f.parse("24.12.0").unwrap());
+
+        assert!(Calver::parse("2024.02.10").unwrap() > Calver::parse("2024.2.9").unwrap());
+        assert_eq!(Calver::parse("24-02_1").unwrap().values, [24, 2, 1]);
+        for input in ["", "2024..1", "2024.1.", "v2024.1"] {
+            assert!(Calver::parse(input).is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(Date::from_unix_days(0), date("1970-01-01"));
+        assert_eq!(Date::from_unix_days(19_782), date("2024-02-29"));
+        assert_eq!(date("2024-01-07").week(), 1);
+        assert_eq!(date("2024-01-08").week(), 2);
+        assert_eq!(date("2024-12-31").week(), 53);
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2024-2-29".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let next = |f: &str, d: &str, current: Option<&str>| {
+            format(f).next(date(d), current).map(|n| n.version)
+        };
+        assert_eq!(
+            next("YYYY.0M.0D", "2024-02-09", None).unwrap(),
+            "2024.02.09"
+        );
+        assert_eq!(next("YY.MM.MICRO", "2024-02-09", None).unwrap(), "24.2.0");
+        assert_eq!(
+            next("YY.0M.MICRO", "2024-02-09", Some("24.02.3")).unwrap(),
+            "24.02.4"
+        );
+        assert_eq!(
+            next("YY.0M.MICRO", "2024-03-01", Some("24.02.3")).unwrap(),
+            "24.03.0"
+        );
+        assert_eq!(
+            next("YYYY.MAJOR.MINOR", "2024-03-01", Some("2024.1.5")).unwrap(),
+            "2024.1.6"
+        );
+        assert_eq!(next("YYYY.0W", "2024-01-08", None).unwrap(), "2024.02");
+        assert!(next("YY.0M.MICRO", "2024-01-09", Some("24.02.3")).is_err());
+        assert!(next("YYYY.0M.0D", "2024-02-09", Some("2024.02.09")).is_err());
+        assert!(next("YY.0M", "1999-02-09", None).is_err());
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            format("YY.0M.MICRO")
+                .explain("24.02.3")
+                .unwrap()
+                .to_string(),
+            "Format: YY.0M.MICRO\n\
+             Year: 2024\n\
+             Month: 2\n\
+             Micro: 3\n\
+             Segments:\n\
+             - 24 (ShortYear)\n\
+             - 02 (ZeroPaddedMonth)\n\
+             - 3 (Micro)\n"
+        );
+    }
+}
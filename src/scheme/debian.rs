@@ -0,0 +1,371 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Debian package versions, parsed and compared the way dpkg does.
+//!
+//! `[epoch:]upstream_version[-debian_revision]`
+//!
+//! - The epoch is a number (0 if absent), and is compared first.
+//! - The upstream version runs up to the last hyphen, and the revision comes
+//!   after it. A version without a revision (a "native" package) compares
+//!   the same as one with a revision of `0`.
+//!
+//! Upstream versions and revisions are compared as alternating runs of
+//! non-digits and digits. Runs of digits compare numerically. Runs of
+//! non-digits compare character by character, where `~` sorts before
+//! anything (even the end of the run), then letters, then everything else.
+//! So `1.0~rc1` < `1.0` < `1.0a` < `1.0+b1`.
+//!
+//! References:
+//! - https://www.debian.org/doc/debian-policy/ch-controlfields.html#version
+//! - https://manpages.debian.org/deb-version
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+
+use super::{check_characters, Explanation, Scheme, SchemeError, SchemeExplanation, VersionScheme};
+use crate::results::PreMetaSegment;
+
+pub(crate) struct Debian;
+
+/// dpkg limits epochs to a (signed) C `int`.
+const MAX_EPOCH: u64 = i32::MAX as u64;
+
+#[derive(Debug, Clone)]
+pub(crate) struct DebianVersion {
+    epoch: u64,
+    upstream_version: String,
+    revision: Option<String>,
+}
+
+impl PartialEq for DebianVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DebianVersion {}
+
+impl PartialOrd for DebianVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DebianVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| verrevcmp(&self.upstream_version, &other.upstream_version))
+            .then_with(|| {
+                verrevcmp(
+                    self.revision.as_deref().unwrap_or_default(),
+                    other.revision.as_deref().unwrap_or_default(),
+                )
+            })
+    }
+}
+
+/// The order of a character in a run of non-digits, with `None` for the end
+/// of the run.
+fn order(c: Option<&u8>) -> i32 {
+    match c {
+        None => 0,
+        Some(b'~') => -1,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => i32::from(*c),
+        Some(c) => i32::from(*c) + 256,
+    }
+}
+
+/// dpkg's `verrevcmp`, for upstream versions & revisions.
+fn verrevcmp(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let is_digit = |c: Option<&u8>| c.is_some_and(u8::is_ascii_digit);
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        while a.get(i).is_some_and(|c| !c.is_ascii_digit())
+            || b.get(j).is_some_and(|c| !c.is_ascii_digit())
+        {
+            let (ac, bc) = (order(a.get(i)), order(b.get(j)));
+            if ac != bc {
+                return ac.cmp(&bc);
+            }
+            i += 1;
+            j += 1;
+        }
+
+        while a.get(i) == Some(&b'0') {
+            i += 1;
+        }
+        while b.get(j) == Some(&b'0') {
+            j += 1;
+        }
+        // Without leading zeros, the longer number is greater, and otherwise
+        // the first differing digit decides.
+        let mut first_diff = Ordering::Equal;
+        while is_digit(a.get(i)) && is_digit(b.get(j)) {
+            first_diff = first_diff.then(a[i].cmp(&b[j]));
+            i += 1;
+            j += 1;
+        }
+        if is_digit(a.get(i)) {
+            return Ordering::Greater;
+        }
+        if is_digit(b.get(j)) {
+            return Ordering::Less;
+        }
+        if first_diff != Ordering::Equal {
+            return first_diff;
+        }
+    }
+    Ordering::Equal
+}
+
+impl VersionScheme for Debian {
+    type Version = DebianVersion;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        if input.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        check_characters("version", input, ".+~-:")?;
+
+        let (epoch, rest) = match input.split_once(':') {
+            Some(("", _)) => return Err(SchemeError::Empty { part: "epoch" }),
+            Some((epoch, rest)) => {
+                let epoch = match epoch.parse::<u64>() {
+                    Ok(epoch) if epoch <= MAX_EPOCH => epoch,
+                    Ok(_) => {
+                        return Err(SchemeError::Malformed {
+                            part: "epoch",
+                            reason: "is too big".to_string(),
+                        })
+                    }
+                    Err(_) => {
+                        return Err(SchemeError::Malformed {
+                            part: "epoch",
+                            reason: "is not a number".to_string(),
+                        })
+                    }
+                };
+                (epoch, rest)
+            }
+            None => (0, input),
+        };
+
+        let (upstream_version, revision) = match rest.rsplit_once('-') {
+            Some((_, "")) => return Err(SchemeError::Empty { part: "revision" }),
+            Some((upstream_version, revision)) => (upstream_version, Some(revision)),
+            None => (rest, None),
+        };
+        if upstream_version.is_empty() {
+            return Err(SchemeError::Empty {
+                part: "upstream version",
+            });
+        }
+        if !upstream_version.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(SchemeError::Malformed {
+                part: "upstream version",
+                reason: "does not start with a digit".to_string(),
+            });
+        }
+        if let Some(revision) = revision {
+            check_characters("revision", revision, ".+~")?;
+        }
+
+        Ok(DebianVersion {
+            epoch,
+            upstream_version: upstream_version.to_string(),
+            revision: revision.map(str::to_string),
+        })
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Debian(DebianExplanation::from(version)).into()
+    }
+
+    type Requirement = Infallible;
+
+    fn parse_requirement(_: &str) -> Result<Self::Requirement, SchemeError> {
+        Err(SchemeError::Unsupported {
+            scheme: Scheme::Debian,
+            operation: "requirements",
+        })
+    }
+
+    fn matches(requirement: &Self::Requirement, _: &Self::Version) -> bool {
+        match *requirement {}
+    }
+}
+
+/// Split into the alternating runs of non-digits and digits, that are
+/// compared in turn.
+fn runs(value: &str) -> Vec<PreMetaSegment> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for (i, c) in value.char_indices().skip(1) {
+        let previous = value.as_bytes()[i - 1];
+        if previous.is_ascii_digit() != c.is_ascii_digit() {
+            runs.push(PreMetaSegment::from(&value[start..i]));
+            start = i;
+        }
+    }
+    if start < value.len() {
+        runs.push(PreMetaSegment::from(&value[start..]));
+    }
+    runs
+}
+
+/// Descriptive information about a Debian version.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct DebianExplanation {
+    epoch: u64,
+    upstream_version_string: String,
+    #[serde(rename(serialize = "upstream-version"))]
+    upstream_version: Vec<PreMetaSegment>,
+    revision_string: String,
+    revision: Vec<PreMetaSegment>,
+    /// Without a revision, the package is "native" to Debian.
+    native: bool,
+}
+
+impl From<&DebianVersion> for DebianExplanation {
+    fn from(value: &DebianVersion) -> Self {
+        let revision = value.revision.clone().unwrap_or_default();
+        Self {
+            epoch: value.epoch,
+            upstream_version: runs(&value.upstream_version),
+            upstream_version_string: value.upstream_version.clone(),
+            revision: runs(&revision),
+            revision_string: revision,
+            native: value.revision.is_none(),
+        }
+    }
+}
+
+impl fmt::Display for DebianExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Epoch: {}", self.epoch)?;
+        writeln!(f, "Upstream Version: {}", self.upstream_version_string)?;
+        for i in self.upstream_version.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        writeln!(f, "Revision: {}", self.revision_string)?;
+        for i in self.revision.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        writeln!(f, "Native: {}", self.native)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> DebianVersion {
+        Debian::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_verrevcmp() {
+        use Ordering::*;
+
+        for (a, b, expected) in [
+            ("1.0", "1.0", Equal),
+            ("1.0", "1.00", Equal),
+            ("1.0", "0:1.0", Equal),
+            ("1.0", "1.0-0", Equal),
+            ("1.0", "1.0-1", Less),
+            ("1.2.3", "1.10", Less),
+            ("1.0~rc1", "1.0", Less),
+            ("1.0~~", "1.0~~a", Less),
+            ("1.0~~a", "1.0~", Less),
+            ("1.0~", "1.0", Less),
+            ("1.0", "1.0a", Less),
+            ("1.0a", "1.0+", Less),
+            ("1.0", "1.0+b1", Less),
+            ("1.0+b1", "1.0.1", Less),
+            ("1:0.9", "2.0", Greater),
+            ("2.30-1ubuntu1", "2.30-1ubuntu2", Less),
+            ("2.30-1ubuntu1", "2.30-1", Greater),
+            ("1.0-1~bpo1", "1.0-1", Less),
+            ("007", "7", Equal),
+            ("1.18.0", "1.18", Greater),
+        ] {
+            assert_eq!(parse(a).cmp(&parse(b)), expected, "{a} vs {b}");
+            assert_eq!(parse(b).cmp(&parse(a)), expected.reverse(), "{b} vs {a}");
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let version = parse("1:2.30-1ubuntu1");
+        assert_eq!(version.epoch, 1);
+        assert_eq!(version.upstream_version, "2.30");
+        assert_eq!(version.revision.as_deref(), Some("1ubuntu1"));
+
+        // Hyphens & colons may appear in the upstream version.
+        let version = parse("2:1.0-beta:2-3");
+        assert_eq!(version.upstream_version, "1.0-beta:2");
+        assert_eq!(version.revision.as_deref(), Some("3"));
+
+        for (input, error) in [
+            ("", "version is empty"),
+            (":1.0", "epoch is empty"),
+            ("a:1.0", "epoch is not a number"),
+            ("2147483648:1.0", "epoch is too big"),
+            ("1:", "upstream version is empty"),
+            ("-1", "upstream version is empty"),
+            ("1.0-", "revision is empty"),
+            ("a1.0", "upstream version does not start with a digit"),
+            ("1.0_1", "invalid character '_' in version"),
+            ("1 0", "invalid character ' ' in version"),
+            ("1:1.0-1:2", "invalid character ':' in revision"),
+        ] {
+            assert_eq!(
+                Debian::parse(input).unwrap_err().to_string(),
+                error,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            DebianExplanation::from(&parse("1:2.30~rc1-1ubuntu1")).to_string(),
+            "Epoch: 1\n\
+             Upstream Version: 2.30~rc1\n\
+             - 2 (Numeric)\n\
+             - . (Ascii)\n\
+             - 30 (Numeric)\n\
+             - ~rc (Ascii)\n\
+             - 1 (Numeric)\n\
+             Revision: 1ubuntu1\n\
+             - 1 (Numeric)\n\
+             - ubuntu (Ascii)\n\
+             - 1 (Numeric)\n\
+             Native: false\n"
+        );
+        assert_eq!(
+            DebianExplanation::from(&parse("1.0")).to_string(),
+            "Epoch: 0\nUpstream Version: 1.0\n- 1 (Numeric)\n- . (Ascii)\n- 0 (Numeric)\nRevision: \nNative: true\n"
+        );
+    }
+}
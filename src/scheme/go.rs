@@ -0,0 +1,490 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Go module versions, the way `golang.org/x/mod` reads them.
+//!
+//! Versions are Semantic Versions with a `v` prefix (`v1.2.3`), where `v1`
+//! and `v1.2` are shorthand for `v1.0.0` and `v1.2.0`. Build metadata is
+//! ignored when ordering, and only `+incompatible` is allowed: it marks a
+//! v2+ version of a module without a major version suffix in its path.
+//!
+//! Pseudo-versions name untagged commits, as a pre-release of the version
+//! after their base (the most recent tag):
+//!
+//! - `vX.0.0-yyyymmddhhmmss-abcdef123456`, with no base.
+//! - `vX.Y.Z-pre.0.yyyymmddhhmmss-abcdef123456`, based on `vX.Y.Z-pre`.
+//! - `vX.Y.(Z+1)-0.yyyymmddhhmmss-abcdef123456`, based on `vX.Y.Z`.
+//!
+//! References:
+//! - https://go.dev/ref/mod#versions
+//! - https://go.dev/ref/mod#pseudo-versions
+//! - https://go.dev/ref/mod#major-version-suffixes
+use regex::Regex;
+use semver::Version;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::sync::LazyLock;
+
+use super::{Explanation, Scheme, SchemeError, SchemeExplanation, VersionScheme};
+use crate::results::VersionExplanation;
+
+pub(crate) struct Go;
+
+/// `golang.org/x/mod/module`'s `pseudoVersionRE`.
+static PSEUDO_VERSION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^v[0-9]+\.(0\.0-|[0-9]+\.[0-9]+-([^+]*\.)?0\.)[0-9]{14}-[A-Za-z0-9]+(\+[0-9A-Za-z-]+(\.[0-9A-Za-z-]+)*)?$",
+    )
+    .expect("static regex should compile")
+});
+
+const INCOMPATIBLE: &str = "incompatible";
+
+/// The parts of a pseudo-version.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct PseudoVersion {
+    /// The tag the commit follows, if any.
+    base_version: Option<String>,
+    /// The commit time (UTC), as RFC 3339.
+    timestamp: String,
+    /// A prefix of the commit hash.
+    revision: String,
+}
+
+impl PseudoVersion {
+    /// `module.PseudoVersionBase`, and friends.
+    fn parse(version: &Version) -> Result<Self, SchemeError> {
+        let malformed = |reason: &str| SchemeError::Malformed {
+            part: "pseudo-version",
+            reason: reason.to_string(),
+        };
+        let pre = version.pre.as_str();
+        let (rest, revision) = pre.rsplit_once('-').expect("matched by the pattern");
+        let (prefix, timestamp) = match rest.rsplit_once('.') {
+            Some((prefix, timestamp)) => (Some(prefix), timestamp),
+            None => (None, rest),
+        };
+
+        let base_version = match prefix {
+            // vX.0.0-yyyymmddhhmmss-abcdef123456
+            None if !version.build.is_empty() => {
+                return Err(malformed("lacks a base version, but has build metadata"))
+            }
+            None => None,
+            // vX.Y.(Z+1)-0.yyyymmddhhmmss-abcdef123456
+            Some("0") => {
+                let patch = version
+                    .patch
+                    .checked_sub(1)
+                    .ok_or_else(|| malformed("has a patch of 0, after a release"))?;
+                Some(Version::new(version.major, version.minor, patch))
+            }
+            // vX.Y.Z-pre.0.yyyymmddhhmmss-abcdef123456
+            Some(prefix) => {
+                let pre = prefix.strip_suffix(".0").expect("matched by the pattern");
+                let mut base = Version::new(version.major, version.minor, version.patch);
+                base.pre = semver::Prerelease::new(pre)?;
+                Some(base)
+            }
+        };
+        let base_version = base_version.map(|mut base| {
+            base.build = version.build.clone();
+            format!("v{base}")
+        });
+
+        let t = |range: std::ops::Range<usize>| &timestamp[range];
+        Ok(Self {
+            base_version,
+            timestamp: format!(
+                "{}-{}-{}T{}:{}:{}Z",
+                t(0..4),
+                t(4..6),
+                t(6..8),
+                t(8..10),
+                t(10..12),
+                t(12..14)
+            ),
+            revision: revision.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GoVersion {
+    /// The canonical version, without the `v` prefix.
+    version: Version,
+    pseudo_version: Option<PseudoVersion>,
+}
+
+impl GoVersion {
+    fn incompatible(&self) -> bool {
+        self.version.build.as_str() == INCOMPATIBLE
+    }
+
+    /// The major version suffix a module path needs for this version.
+    fn path_major(&self) -> String {
+        match self.version.major {
+            0 | 1 => String::new(),
+            _ if self.incompatible() => String::new(),
+            major => format!("/v{major}"),
+        }
+    }
+}
+
+impl PartialEq for GoVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for GoVersion {}
+
+impl PartialOrd for GoVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// `semver.Compare`, which ignores build metadata.
+impl Ord for GoVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.version.cmp_precedence(&other.version)
+    }
+}
+
+impl fmt::Display for GoVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}", self.version)
+    }
+}
+
+impl VersionScheme for Go {
+    type Version = GoVersion;
+    type Requirement = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        let Some(rest) = input.strip_prefix('v') else {
+            return Err(SchemeError::Malformed {
+                part: "version",
+                reason: "does not start with v".to_string(),
+            });
+        };
+        // `v1` & `v1.2` are shorthand, without a pre-release or build.
+        let canonical = match rest.matches('.').count() {
+            0 if rest.bytes().all(|c| c.is_ascii_digit()) => format!("{rest}.0.0"),
+            1 if rest.bytes().all(|c| c.is_ascii_digit() || c == b'.') => format!("{rest}.0"),
+            _ => rest.to_string(),
+        };
+        let version = Version::parse(&canonical)?;
+
+        match version.build.as_str() {
+            "" => {}
+            INCOMPATIBLE if version.major < 2 => {
+                return Err(SchemeError::Malformed {
+                    part: "version",
+                    reason: format!("v{} is not incompatible", version.major),
+                })
+            }
+            INCOMPATIBLE => {}
+            _ => {
+                return Err(SchemeError::Malformed {
+                    part: "build metadata",
+                    reason: "is not +incompatible".to_string(),
+                })
+            }
+        }
+
+        let pseudo_version = match PSEUDO_VERSION.is_match(input) {
+            true => Some(PseudoVersion::parse(&version)?),
+            false => None,
+        };
+        Ok(GoVersion {
+            version,
+            pseudo_version,
+        })
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Go(GoExplanation::from(version)).into()
+    }
+
+    fn parse_requirement(_: &str) -> Result<Self::Requirement, SchemeError> {
+        Err(SchemeError::Unsupported {
+            scheme: Scheme::Go,
+            operation: "requirements",
+        })
+    }
+
+    fn matches(requirement: &Self::Requirement, _: &Self::Version) -> bool {
+        match *requirement {}
+    }
+}
+
+/// `module.SplitPathVersion`: split a module path into its prefix, and major
+/// version suffix (`/v2`, or `.v2` for `gopkg.in`), which may be empty.
+fn split_path_major(path: &str) -> Result<&str, SchemeError> {
+    let invalid = || SchemeError::Malformed {
+        part: "module path",
+        reason: format!("{path:?} has an invalid major version suffix"),
+    };
+    if let Some(rest) = path.strip_prefix("gopkg.in/") {
+        let rest = rest.strip_suffix("-unstable").unwrap_or(rest);
+        let digits = rest.bytes().rev().take_while(u8::is_ascii_digit).count();
+        let major = rest[..rest.len() - digits]
+            .strip_suffix(".v")
+            .map(|_| &rest[rest.len() - digits - 2..]);
+        return match major {
+            Some(".v0") => Ok(".v0"),
+            Some(major) if major.len() > 2 && !major[2..].starts_with('0') => Ok(major),
+            // All gopkg.in paths must end in .vN.
+            _ => Err(invalid()),
+        };
+    }
+
+    let tail = path
+        .bytes()
+        .rev()
+        .take_while(|c| c.is_ascii_digit() || *c == b'.')
+        .count();
+    let (prefix, digits) = path.split_at(path.len() - tail);
+    let Some(prefix) = prefix.strip_suffix("/v").filter(|_| tail > 0) else {
+        return Ok("");
+    };
+    let major = &path[prefix.len()..];
+    if digits.contains('.') || digits.starts_with('0') || digits == "1" {
+        return Err(invalid());
+    }
+    Ok(major)
+}
+
+/// `module.CheckPathMajor`: check a version is allowed for a module path, by
+/// its major version suffix.
+pub(crate) fn check_path_major(path: &str, version: &str) -> Result<(), SchemeError> {
+    let version = Go::parse(version)?;
+    let path_major = split_path_major(path)?;
+    let path_major = path_major.strip_suffix("-unstable").unwrap_or(path_major);
+    let major = format!("v{}", version.version.major);
+    let expected = match path_major {
+        // An old bug generated v0.0.0- pseudo-versions for gopkg.in/*.v1.
+        ".v1" if version.pseudo_version.is_some() && version.to_string().starts_with("v0.0.0-") => {
+            return Ok(())
+        }
+        "" if version.path_major().is_empty() => return Ok(()),
+        "" => "v0 or v1",
+        path_major if path_major[1..] == major => {
+            return match version.incompatible() {
+                true => Err(SchemeError::Malformed {
+                    part: "version",
+                    reason: format!("is +incompatible, but {path:?} has a major version suffix"),
+                }),
+                false => Ok(()),
+            };
+        }
+        path_major => &path_major[1..],
+    };
+    Err(SchemeError::Malformed {
+        part: "version",
+        reason: format!("should be {expected}, not {major}, for {path:?}"),
+    })
+}
+
+/// Descriptive information about a Go module version.
+#[derive(Serialize, PartialEq)]
+pub(crate) struct GoExplanation {
+    canonical: String,
+    #[serde(flatten)]
+    version: VersionExplanation,
+    incompatible: bool,
+    /// The suffix a module path needs, to use this version.
+    major_version_suffix: String,
+    pseudo_version: Option<PseudoVersion>,
+}
+
+impl From<&GoVersion> for GoExplanation {
+    fn from(value: &GoVersion) -> Self {
+        Self {
+            canonical: value.to_string(),
+            version: VersionExplanation::from(&value.version),
+            incompatible: value.incompatible(),
+            major_version_suffix: value.path_major(),
+            pseudo_version: value.pseudo_version.clone(),
+        }
+    }
+}
+
+impl fmt::Display for GoExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Canonical: {}", self.canonical)?;
+        write!(f, "{}", self.version)?;
+        writeln!(f, "Incompatible: {}", self.incompatible)?;
+        writeln!(f, "Major Version Suffix: {}", self.major_version_suffix)?;
+        match &self.pseudo_version {
+            Some(pseudo) => {
+                writeln!(f, "Pseudo-Version: true")?;
+                writeln!(
+                    f,
+                    "Base Version: {}",
+                    pseudo.base_version.as_deref().unwrap_or_default()
+                )?;
+                writeln!(f, "Timestamp: {}", pseudo.timestamp)?;
+                writeln!(f, "Revision: {}", pseudo.revision)?;
+            }
+            None => writeln!(f, "Pseudo-Version: false")?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> GoVersion {
+        Go::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("v1").to_string(), "v1.0.0");
+        assert_eq!(parse("v1.2").to_string(), "v1.2.0");
+        assert_eq!(
+            parse("v2.0.0+incompatible").to_string(),
+            "v2.0.0+incompatible"
+        );
+
+        for input in [
+            "1.2.3",
+            "v1.2-rc1",
+            "v1+incompatible",
+            "v1.0.0+incompatible",
+            "v2.0.0+meta",
+            "v01.0.0",
+            "v1.2.3.4",
+            "v0.0.0-20191109021931-daa7c04131f5+incompatible",
+            "v1.2.0-0.20191109021931-daa7c04131f5",
+        ] {
+            assert!(Go::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_pseudo_version() {
+        for (input, base) in [
+            ("v0.0.0-20191109021931-daa7c04131f5", None),
+            ("v1.2.4-0.20191109021931-daa7c04131f5", Some("v1.2.3")),
+            (
+                "v1.2.3-pre.0.20191109021931-daa7c04131f5",
+                Some("v1.2.3-pre"),
+            ),
+            (
+                "v2.0.1-0.20191109021931-daa7c04131f5+incompatible",
+                Some("v2.0.0+incompatible"),
+            ),
+        ] {
+            let pseudo = parse(input).pseudo_version.unwrap();
+            assert_eq!(pseudo.base_version.as_deref(), base, "{input}");
+            assert_eq!(pseudo.timestamp, "2019-11-09T02:19:31Z");
+            assert_eq!(pseudo.revision, "daa7c04131f5");
+        }
+
+        assert!(parse("v1.2.3-pre").pseudo_version.is_none());
+        assert!(parse("v1.2.3-0.2019110902193-daa7c04131f5")
+            .pseudo_version
+            .is_none());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let ordered = [
+            "v0.0.0-20191109021931-daa7c04131f5",
+            "v0.1.0",
+            "v1.2.3-pre",
+            "v1.2.3-pre.0.20191109021931-daa7c04131f5",
+            "v1.2.3",
+            "v1.2.4-0.20191109021931-daa7c04131f5",
+            "v1.2.4-0.20201109021931-0123456789ab",
+            "v1.2.4",
+            "v2.0.0+incompatible",
+            "v10",
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(
+                parse(pair[0]).cmp(&parse(pair[1])),
+                Ordering::Less,
+                "{pair:?}"
+            );
+        }
+        assert_eq!(
+            parse("v2.0.0+incompatible").cmp(&parse("v2")),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_check_path_major() {
+        for (path, version) in [
+            ("example.com/mod", "v0.1.0"),
+            ("example.com/mod", "v1.2.3"),
+            ("example.com/mod", "v3.0.0+incompatible"),
+            ("example.com/mod/v2", "v2.0.0"),
+            ("example.com/mod/v2", "v2.0.1-0.20191109021931-daa7c04131f5"),
+            ("example.com/v2mod", "v1.0.0"),
+            ("gopkg.in/yaml.v2", "v2.4.0"),
+            ("gopkg.in/yaml.v1", "v0.0.0-20191109021931-daa7c04131f5"),
+            ("gopkg.in/check.v1-unstable", "v1.0.0"),
+            ("gopkg.in/yaml.v0", "v0.1.0"),
+        ] {
+            assert!(check_path_major(path, version).is_ok(), "{path} {version}");
+        }
+
+        for (path, version) in [
+            ("example.com/mod", "v2.0.0"),
+            ("example.com/mod/v2", "v1.0.0"),
+            ("example.com/mod/v2", "v3.0.0"),
+            ("example.com/mod/v2", "v2.0.0+incompatible"),
+            ("example.com/mod/v1", "v1.0.0"),
+            ("example.com/mod/v02", "v2.0.0"),
+            ("example.com/mod/v2.1", "v2.0.0"),
+            ("gopkg.in/yaml", "v1.0.0"),
+            ("gopkg.in/yaml.v2", "v3.0.0"),
+        ] {
+            assert!(check_path_major(path, version).is_err(), "{path} {version}");
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            GoExplanation::from(&parse("v1.2.4-0.20191109021931-daa7c04131f5")).to_string(),
+            "Canonical: v1.2.4-0.20191109021931-daa7c04131f5\n\
+             Major: 1\n\
+             Minor: 2\n\
+             Patch: 4\n\
+             PreRelease: 0.20191109021931-daa7c04131f5\n\
+             - 0 (Numeric)\n\
+             - 20191109021931-daa7c04131f5 (Ascii)\n\
+             Build Metadata: \n\
+             -  (Numeric)\n\
+             Incompatible: false\n\
+             Major Version Suffix: \n\
+             Pseudo-Version: true\n\
+             Base Version: v1.2.3\n\
+             Timestamp: 2019-11-09T02:19:31Z\n\
+             Revision: daa7c04131f5\n"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Kubernetes API versions (`v1`, `v2beta1`, `v1alpha3`), ordered by the
+//! priority Kubernetes gives them when choosing a preferred version.
+//!
+//! `v<major>[(alpha|beta)<minor>]`
+//!
+//! GA versions are greater than betas, which are greater than alphas. Then
+//! the greater major version, and then the greater minor version wins.
+//! Anything else is less than every Kubernetes version, and among
+//! themselves, lexically earlier strings are greater. So in Kubernetes'
+//! (descending) priority order, which `sort --reverse` gives, they come last,
+//! sorted lexically:
+//!
+//! `v10, v2, v1, v11beta2, v10beta3, v3beta1, v12alpha1, v11alpha2, foo1, foo10`
+//!
+//! The default ascending `sort` is the exact reverse, so there they come
+//! first, in reverse lexical order.
+//!
+//! Reference: https://kubernetes.io/docs/tasks/extend-kubernetes/custom-resources/custom-resource-definition-versioning/#version-priority
+use regex::Regex;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::sync::LazyLock;
+
+use super::{Explanation, Scheme, SchemeError, SchemeExplanation, VersionScheme};
+
+pub(crate) struct K8sApi;
+
+static KUBE_VERSION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^v([0-9]+)(?:(alpha|beta)([0-9]+))?$").expect("static regex should compile")
+});
+
+/// The stability of a Kubernetes API version, in increasing priority.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Stability {
+    Alpha,
+    Beta,
+    #[serde(rename = "GA")]
+    GenerallyAvailable,
+}
+
+impl fmt::Display for Stability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stability::Alpha => write!(f, "Alpha"),
+            Stability::Beta => write!(f, "Beta"),
+            Stability::GenerallyAvailable => write!(f, "GA"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum K8sApiVersion {
+    Kube {
+        stability: Stability,
+        major: u64,
+        /// The alpha or beta level, 0 for GA versions.
+        minor: u64,
+    },
+    /// Anything not matching `v<major>[(alpha|beta)<minor>]`.
+    Other(String),
+}
+
+impl PartialOrd for K8sApiVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// `CompareKubeAwareVersionStrings`, from `k8s.io/apimachinery`.
+impl Ord for K8sApiVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (
+                K8sApiVersion::Kube {
+                    stability,
+                    major,
+                    minor,
+                },
+                K8sApiVersion::Kube {
+                    stability: other_stability,
+                    major: other_major,
+                    minor: other_minor,
+                },
+            ) => (stability, major, minor).cmp(&(other_stability, other_major, other_minor)),
+            (K8sApiVersion::Kube { .. }, K8sApiVersion::Other(_)) => Ordering::Greater,
+            (K8sApiVersion::Other(_), K8sApiVersion::Kube { .. }) => Ordering::Less,
+            (K8sApiVersion::Other(a), K8sApiVersion::Other(b)) => b.cmp(a),
+        }
+    }
+}
+
+impl VersionScheme for K8sApi {
+    type Version = K8sApiVersion;
+    type Requirement = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        if input.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        let kube = KUBE_VERSION.captures(input).and_then(|captures| {
+            // Numbers too big to compare aren't Kubernetes versions either.
+            let major = captures[1].parse().ok()?;
+            let (stability, minor) = match captures.get(2).map(|m| m.as_str()) {
+                Some("alpha") => (Stability::Alpha, captures[3].parse().ok()?),
+                Some(_) => (Stability::Beta, captures[3].parse().ok()?),
+                None => (Stability::GenerallyAvailable, 0),
+            };
+            Some(K8sApiVersion::Kube {
+                stability,
+                major,
+                minor,
+            })
+        });
+        Ok(kube.unwrap_or_else(|| K8sApiVersion::Other(input.to_string())))
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::K8sApi(K8sApiExplanation::from(version)).into()
+    }
+
+    fn parse_requirement(_: &str) -> Result<Self::Requirement, SchemeError> {
+        Err(SchemeError::Unsupported {
+            scheme: Scheme::K8sApi,
+            operation: "requirements",
+        })
+    }
+
+    fn matches(requirement: &Self::Requirement, _: &Self::Version) -> bool {
+        match *requirement {}
+    }
+}
+
+/// Descriptive information about a Kubernetes API version.
+///
+/// Everything is unset for strings that aren't Kubernetes versions.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct K8sApiExplanation {
+    stability: Option<Stability>,
+    major: Option<u64>,
+    minor: Option<u64>,
+}
+
+impl From<&K8sApiVersion> for K8sApiExplanation {
+    fn from(value: &K8sApiVersion) -> Self {
+        match value {
+            K8sApiVersion::Kube {
+                stability: Stability::GenerallyAvailable,
+                major,
+                ..
+            } => Self {
+                stability: Some(Stability::GenerallyAvailable),
+                major: Some(*major),
+                minor: None,
+            },
+            K8sApiVersion::Kube {
+                stability,
+                major,
+                minor,
+            } => Self {
+                stability: Some(*stability),
+                major: Some(*major),
+                minor: Some(*minor),
+            },
+            K8sApiVersion::Other(_) => Self {
+                stability: None,
+                major: None,
+                minor: None,
+            },
+        }
+    }
+}
+
+impl fmt::Display for K8sApiExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let optional = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_default();
+        match self.stability {
+            Some(stability) => writeln!(f, "Stability: {stability}")?,
+            None => writeln!(f, "Stability: ")?,
+        }
+        writeln!(f, "Major: {}", optional(self.major))?;
+        writeln!(f, "Minor: {}", optional(self.minor))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> K8sApiVersion {
+        K8sApi::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_ordering() {
+        // From the Kubernetes documentation, in descending priority.
+        let ordered = [
+            "v10",
+            "v2",
+            "v1",
+            "v11beta2",
+            "v10beta3",
+            "v3beta1",
+            "v12alpha1",
+            "v11alpha2",
+            "foo1",
+            "foo10",
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(
+                parse(pair[0]).cmp(&parse(pair[1])),
+                Ordering::Greater,
+                "{pair:?}"
+            );
+        }
+        assert_eq!(parse("v01").cmp(&parse("v1")), Ordering::Equal);
+        assert_eq!(parse("v1gamma1").cmp(&parse("v1alpha1")), Ordering::Less);
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            K8sApiExplanation::from(&parse("v2beta3")).to_string(),
+            "Stability: Beta\nMajor: 2\nMinor: 3\n"
+        );
+        assert_eq!(
+            K8sApiExplanation::from(&parse("v2")).to_string(),
+            "Stability: GA\nMajor: 2\nMinor: \n"
+        );
+        assert_eq!(
+            K8sApiExplanation::from(&parse("v2beta")).to_string(),
+            "Stability: \nMajor: \nMinor: \n"
+        );
+        assert!(K8sApi::parse("").is_err());
+    }
+}
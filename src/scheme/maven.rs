@@ -0,0 +1,665 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Maven artifact versions & version ranges, compared the way
+//! `org.apache.maven.artifact.versioning.ComparableVersion` (Maven 3.9) does.
+//!
+//! Any string is a version. It's split into numbers and qualifiers at `.`,
+//! `-`, and transitions between digits & letters. A `-` (or a transition)
+//! starts a nested list, so `1-1` is `[1, [1]]`. Trailing "null" items
+//! (`0`, `ga`, `final`, `release`) are dropped, so `1.0.0 == 1-ga == 1`.
+//!
+//! Items are compared pairwise, padding the shorter list with nulls:
+//!
+//! - Numbers compare numerically, and are greater than qualifiers & lists.
+//! - Lists are greater than qualifiers.
+//! - Known qualifiers are ordered
+//!   `alpha < beta < milestone < rc < snapshot < "" < sp`, with `a`, `b` &
+//!   `m` (when directly followed by a number) and `cr` as aliases. Unknown
+//!   qualifiers sort after them, lexically.
+//!
+//! References:
+//! - https://maven.apache.org/ref/3.9.9/maven-artifact/apidocs/org/apache/maven/artifact/versioning/ComparableVersion.html
+//! - https://maven.apache.org/enforcer/enforcer-rules/versionRanges.html
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::{Explanation, SchemeError, SchemeExplanation, VersionScheme};
+
+pub(crate) struct Maven;
+
+/// Known qualifiers, in order. The empty qualifier is a release.
+const QUALIFIERS: [&str; 7] = ["alpha", "beta", "milestone", "rc", "snapshot", "", "sp"];
+const RELEASE_INDEX: usize = 5;
+
+/// `ComparableVersion`'s ordering key for a qualifier.
+fn comparable_qualifier(value: &str) -> String {
+    match QUALIFIERS.iter().position(|q| *q == value) {
+        Some(i) => i.to_string(),
+        None => format!("{}-{value}", QUALIFIERS.len()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    /// Digits, without leading zeros (so length orders them first).
+    Number(String),
+    Qualifier(String),
+    List(Vec<Item>),
+}
+
+impl Item {
+    fn number(digits: &str) -> Self {
+        let digits = digits.trim_start_matches('0');
+        Item::Number(if digits.is_empty() { "0" } else { digits }.to_string())
+    }
+
+    fn qualifier(value: &str, followed_by_digit: bool) -> Self {
+        let value = match (value, followed_by_digit) {
+            ("a", true) => "alpha",
+            ("b", true) => "beta",
+            ("m", true) => "milestone",
+            ("ga" | "final" | "release", _) => "",
+            ("cr", _) => "rc",
+            (value, _) => value,
+        };
+        Item::Qualifier(value.to_string())
+    }
+
+    fn is_null(&self) -> bool {
+        match self {
+            Item::Number(n) => n == "0",
+            Item::Qualifier(q) => comparable_qualifier(q) == RELEASE_INDEX.to_string(),
+            Item::List(items) => items.is_empty(),
+        }
+    }
+
+    /// Compare against a missing item, as when padding the shorter list.
+    fn cmp_null(&self) -> Ordering {
+        match self {
+            Item::Number(_) if self.is_null() => Ordering::Equal,
+            Item::Number(_) => Ordering::Greater,
+            Item::Qualifier(q) => comparable_qualifier(q).cmp(&RELEASE_INDEX.to_string()),
+            Item::List(items) => items
+                .iter()
+                .map(Item::cmp_null)
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Item::Number(a), Item::Number(b)) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+            (Item::Number(_), _) => Ordering::Greater,
+            (_, Item::Number(_)) => Ordering::Less,
+            (Item::Qualifier(a), Item::Qualifier(b)) => {
+                comparable_qualifier(a).cmp(&comparable_qualifier(b))
+            }
+            (Item::Qualifier(_), Item::List(_)) => Ordering::Less,
+            (Item::List(_), Item::Qualifier(_)) => Ordering::Greater,
+            (Item::List(a), Item::List(b)) => {
+                for i in 0..a.len().max(b.len()) {
+                    let ordering = match (a.get(i), b.get(i)) {
+                        (Some(a), Some(b)) => a.cmp(b),
+                        (Some(a), None) => a.cmp_null(),
+                        (None, Some(b)) => b.cmp_null().reverse(),
+                        (None, None) => unreachable!("within the longest list"),
+                    };
+                    if ordering.is_ne() {
+                        return ordering;
+                    }
+                }
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+/// `ComparableVersion`'s canonical form.
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Item::Number(n) => write!(f, "{n}"),
+            Item::Qualifier(q) => write!(f, "{q}"),
+            Item::List(items) => {
+                let mut canonical = String::new();
+                for item in items {
+                    if !canonical.is_empty() {
+                        canonical.push(if matches!(item, Item::List(_)) {
+                            '-'
+                        } else {
+                            '.'
+                        });
+                    }
+                    canonical.push_str(&item.to_string());
+                }
+                write!(f, "{canonical}")
+            }
+        }
+    }
+}
+
+/// Drop trailing null items, but not past a nested list.
+fn normalize(items: &mut Vec<Item>) {
+    for i in (0..items.len()).rev() {
+        if items[i].is_null() {
+            items.remove(i);
+        } else if !matches!(items[i], Item::List(_)) {
+            break;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MavenVersion {
+    items: Item,
+}
+
+impl PartialEq for MavenVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MavenVersion {}
+
+impl PartialOrd for MavenVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MavenVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.items.cmp(&other.items)
+    }
+}
+
+/// `ComparableVersion.parseVersion`.
+///
+/// Items are only ever added to the innermost list, so the lists are kept
+/// as a stack, and nested once parsing is done.
+fn parse_items(version: &str) -> Item {
+    let version = version.to_ascii_lowercase();
+    let mut lists: Vec<Vec<Item>> = vec![Vec::new()];
+    let mut is_digit = false;
+    let mut start = 0;
+    for (i, c) in version.char_indices() {
+        match c {
+            '.' => {
+                let item = match i == start {
+                    true => Item::number("0"),
+                    false => parse_item(is_digit, &version[start..i]),
+                };
+                current(&mut lists).push(item);
+                start = i + 1;
+            }
+            '-' => {
+                let item = match i == start {
+                    true => Item::number("0"),
+                    false => parse_item(is_digit, &version[start..i]),
+                };
+                current(&mut lists).push(item);
+                start = i + 1;
+                lists.push(Vec::new());
+            }
+            c if c.is_ascii_digit() => {
+                if !is_digit && i > start {
+                    // `1.0.0.x1` is `1.0.0-x1`.
+                    if !current(&mut lists).is_empty() {
+                        lists.push(Vec::new());
+                    }
+                    current(&mut lists).push(Item::qualifier(&version[start..i], true));
+                    start = i;
+                    lists.push(Vec::new());
+                }
+                is_digit = true;
+            }
+            _ => {
+                if is_digit && i > start {
+                    current(&mut lists).push(parse_item(true, &version[start..i]));
+                    start = i;
+                    lists.push(Vec::new());
+                }
+                is_digit = false;
+            }
+        }
+    }
+    if version.len() > start {
+        // `1.0.0.x` is `1.0.0-x`.
+        if !is_digit && !current(&mut lists).is_empty() {
+            lists.push(Vec::new());
+        }
+        current(&mut lists).push(parse_item(is_digit, &version[start..]));
+    }
+
+    let mut items = lists.pop().expect("there is always a list");
+    normalize(&mut items);
+    while let Some(mut parent) = lists.pop() {
+        parent.push(Item::List(items));
+        normalize(&mut parent);
+        items = parent;
+    }
+    Item::List(items)
+}
+
+/// The innermost list.
+fn current(lists: &mut [Vec<Item>]) -> &mut Vec<Item> {
+    lists.last_mut().expect("there is always a list")
+}
+
+fn parse_item(is_digit: bool, value: &str) -> Item {
+    match is_digit {
+        true => Item::number(value),
+        false => Item::qualifier(value, false),
+    }
+}
+
+impl VersionScheme for Maven {
+    type Version = MavenVersion;
+    type Requirement = VersionRange;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        if input.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        // Maven accepts anything, but whitespace & range syntax are almost
+        // certainly mistakes.
+        if let Some(character) = input
+            .chars()
+            .find(|c| !c.is_ascii_graphic() || "[](),".contains(*c))
+        {
+            return Err(SchemeError::InvalidCharacter {
+                part: "version",
+                character,
+            });
+        }
+        Ok(MavenVersion {
+            items: parse_items(input),
+        })
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Maven(MavenExplanation::from(version)).into()
+    }
+
+    fn parse_requirement(input: &str) -> Result<Self::Requirement, SchemeError> {
+        VersionRange::parse(input)
+    }
+
+    fn matches(requirement: &Self::Requirement, version: &Self::Version) -> bool {
+        requirement.contains(version)
+    }
+}
+
+/// A single interval of a [VersionRange], like `[1.0,2.0)`.
+#[derive(Debug)]
+struct Restriction {
+    lower: Option<(MavenVersion, bool)>,
+    upper: Option<(MavenVersion, bool)>,
+}
+
+impl Restriction {
+    fn parse(input: &str) -> Result<Self, SchemeError> {
+        let lower_inclusive = input.starts_with('[');
+        let upper_inclusive = input.ends_with(']');
+        let bound = |input: &str| match input.trim() {
+            "" => Ok(None),
+            input => Maven::parse(input).map(Some),
+        };
+
+        let inner = &input[1..input.len() - 1];
+        match inner.split_once(',') {
+            None => {
+                if !lower_inclusive || !upper_inclusive {
+                    return Err(malformed(input, "is a single version not surrounded by []"));
+                }
+                let version = Maven::parse(inner.trim())?;
+                Ok(Self {
+                    lower: Some((version.clone(), true)),
+                    upper: Some((version, true)),
+                })
+            }
+            Some((_, upper)) if upper.contains(',') => {
+                Err(malformed(input, "has more than 2 bounds"))
+            }
+            Some((lower, upper)) => {
+                let lower = bound(lower)?;
+                let upper = bound(upper)?;
+                if let (Some(lower), Some(upper)) = (&lower, &upper) {
+                    match upper.cmp(lower) {
+                        Ordering::Less => {
+                            return Err(malformed(
+                                input,
+                                "has an upper bound below its lower bound",
+                            ))
+                        }
+                        Ordering::Equal if !lower_inclusive || !upper_inclusive => {
+                            return Err(malformed(input, "is empty"))
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(Self {
+                    lower: lower.map(|v| (v, lower_inclusive)),
+                    upper: upper.map(|v| (v, upper_inclusive)),
+                })
+            }
+        }
+    }
+
+    fn contains(&self, version: &MavenVersion) -> bool {
+        let above = match &self.lower {
+            Some((lower, inclusive)) => match lower.cmp(version) {
+                Ordering::Less => true,
+                Ordering::Equal => *inclusive,
+                Ordering::Greater => false,
+            },
+            None => true,
+        };
+        let below = match &self.upper {
+            Some((upper, inclusive)) => match upper.cmp(version) {
+                Ordering::Greater => true,
+                Ordering::Equal => *inclusive,
+                Ordering::Less => false,
+            },
+            None => true,
+        };
+        above && below
+    }
+}
+
+fn malformed(input: &str, reason: &str) -> SchemeError {
+    SchemeError::Malformed {
+        part: "range",
+        reason: format!("{input:?} {reason}"),
+    }
+}
+
+/// A Maven version range, the way `VersionRange.createFromVersionSpec`
+/// reads it.
+///
+/// Comma separated intervals (`[1.0,2.0),[3.0,)`) match if ANY of them
+/// match. A bare version (`1.0`) is only a "soft" requirement to Maven,
+/// and matches every version.
+#[derive(Debug)]
+pub(crate) struct VersionRange {
+    restrictions: Vec<Restriction>,
+}
+
+impl VersionRange {
+    fn parse(input: &str) -> Result<Self, SchemeError> {
+        let mut rest = input.trim();
+        if rest.is_empty() {
+            return Err(SchemeError::Empty { part: "range" });
+        }
+
+        let mut restrictions: Vec<Restriction> = Vec::new();
+        while rest.starts_with(['[', '(']) {
+            let end = rest
+                .find([')', ']'])
+                .ok_or_else(|| malformed(input, "has an unbounded range"))?;
+            let restriction = Restriction::parse(&rest[..=end])?;
+            if let Some(Restriction {
+                upper: Some((upper, _)),
+                ..
+            }) = restrictions.last()
+            {
+                match &restriction.lower {
+                    Some((lower, _)) if lower >= upper => {}
+                    _ => return Err(malformed(input, "has overlapping ranges")),
+                }
+            }
+            restrictions.push(restriction);
+
+            rest = rest[end + 1..].trim_start();
+            if let Some(next) = rest.strip_prefix(',') {
+                rest = next.trim_start();
+            }
+        }
+
+        if !rest.is_empty() {
+            if !restrictions.is_empty() {
+                return Err(malformed(input, "mixes a version with ranges"));
+            }
+            Maven::parse(rest)?;
+            restrictions.push(Restriction {
+                lower: None,
+                upper: None,
+            });
+        }
+        Ok(Self { restrictions })
+    }
+
+    fn contains(&self, version: &MavenVersion) -> bool {
+        self.restrictions.iter().any(|r| r.contains(version))
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) enum MavenItemType {
+    Number,
+    Alpha,
+    Beta,
+    Milestone,
+    ReleaseCandidate,
+    Snapshot,
+    Release,
+    ServicePack,
+    /// Any other qualifier, which sorts after all of the known ones.
+    Qualifier,
+}
+
+impl fmt::Display for MavenItemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A number or qualifier of a Maven version, after aliases are applied.
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct MavenItem {
+    kind: MavenItemType,
+    value: String,
+    /// How many lists deep the item is, incremented by each `-` (or
+    /// transition between digits & letters).
+    depth: usize,
+}
+
+impl fmt::Display for MavenItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.value, self.kind)
+    }
+}
+
+/// Descriptive information about a Maven version.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct MavenExplanation {
+    canonical: String,
+    items: Vec<MavenItem>,
+}
+
+fn flatten(item: &Item, depth: usize, out: &mut Vec<MavenItem>) {
+    let (kind, value) = match item {
+        Item::Number(n) => (MavenItemType::Number, n),
+        Item::Qualifier(q) => {
+            let kind = match q.as_str() {
+                "alpha" => MavenItemType::Alpha,
+                "beta" => MavenItemType::Beta,
+                "milestone" => MavenItemType::Milestone,
+                "rc" => MavenItemType::ReleaseCandidate,
+                "snapshot" => MavenItemType::Snapshot,
+                "" => MavenItemType::Release,
+                "sp" => MavenItemType::ServicePack,
+                _ => MavenItemType::Qualifier,
+            };
+            (kind, q)
+        }
+        Item::List(items) => {
+            for i in items {
+                flatten(i, depth + 1, out);
+            }
+            return;
+        }
+    };
+    out.push(MavenItem {
+        kind,
+        value: value.clone(),
+        depth,
+    });
+}
+
+impl From<&MavenVersion> for MavenExplanation {
+    fn from(value: &MavenVersion) -> Self {
+        let mut items = Vec::new();
+        if let Item::List(top) = &value.items {
+            for i in top {
+                flatten(i, 0, &mut items);
+            }
+        }
+        Self {
+            canonical: value.items.to_string(),
+            items,
+        }
+    }
+}
+
+impl fmt::Display for MavenExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Canonical: {}", self.canonical)?;
+        writeln!(f, "Items:")?;
+        for i in self.items.iter() {
+            writeln!(f, "{}- {i}", "  ".repeat(i.depth))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> MavenVersion {
+        Maven::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_comparable_version_cases() {
+        let cases = include_str!("../../example-data/maven-comparableversion-cases.txt");
+        let mut count = 0;
+        for line in cases.lines() {
+            if line.starts_with('#') || line.is_empty() {
+                continue;
+            }
+            let case: Vec<&str> = line.split_whitespace().collect();
+            let [a, b, expected] = case[..] else {
+                panic!("malformed case: {line}");
+            };
+            let expected = match expected {
+                "-1" => Ordering::Less,
+                "0" => Ordering::Equal,
+                "1" => Ordering::Greater,
+                _ => panic!("malformed case: {line}"),
+            };
+            assert_eq!(parse(a).cmp(&parse(b)), expected, "{line}");
+            count += 1;
+        }
+        assert!(count > 100);
+    }
+
+    #[test]
+    fn test_canonical() {
+        for (input, canonical) in [
+            ("1.0.0", "1"),
+            ("1a1", "1-alpha-1"),
+            ("1.0-M3", "1-milestone-3"),
+            ("1.0.0.x1", "1-x-1"),
+            ("2.0.1-SNAPSHOT", "2.0.1-snapshot"),
+            ("1.0-GA", "1"),
+            ("1.0cr1", "1-rc-1"),
+            ("1.0-sp-0", "1-sp"),
+        ] {
+            assert_eq!(parse(input).items.to_string(), canonical, "{input}");
+        }
+        assert!(Maven::parse("").is_err());
+        assert!(Maven::parse("1.0 ").is_err());
+        assert!(Maven::parse("[1.0]").is_err());
+    }
+
+    #[test]
+    fn test_ranges() {
+        for (range, version, expected) in [
+            ("[1.0,2.0)", "1.0", true),
+            ("[1.0,2.0)", "1.5-SNAPSHOT", true),
+            ("[1.0,2.0)", "2.0", false),
+            ("[1.0,2.0)", "2.0-rc1", true),
+            ("(1.0,2.0]", "1.0", false),
+            ("(1.0,2.0]", "2.0.0", true),
+            ("[1.5]", "1.5.0", true),
+            ("[1.5]", "1.5.1", false),
+            ("(,1.0]", "0.1", true),
+            ("[1.0,)", "99", true),
+            ("(,1.0],[1.2,)", "1.1", false),
+            ("(,1.0], [1.2,)", "1.2", true),
+            // A soft requirement.
+            ("1.0", "3.0", true),
+        ] {
+            let range = VersionRange::parse(range).unwrap();
+            assert_eq!(
+                range.contains(&parse(version)),
+                expected,
+                "{range:?} {version}"
+            );
+        }
+
+        for range in [
+            "",
+            "[1.0",
+            "(1.0)",
+            "[1.0,2.0,3.0]",
+            "[2.0,1.0]",
+            "(1.0,1.0]",
+            "[1.0,2.0),1.5",
+            "[1.0,3.0),[2.0,4.0)",
+            "[1.0,2.0) x",
+        ] {
+            assert!(VersionRange::parse(range).is_err(), "{range:?}");
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            MavenExplanation::from(&parse("1.2-beta-3.RC")).to_string(),
+            "Canonical: 1.2-beta-3-rc\n\
+             Items:\n\
+             - 1 (Number)\n\
+             - 2 (Number)\n\
+             \x20 - beta (Beta)\n\
+             \x20   - 3 (Number)\n\
+             \x20     - rc (ReleaseCandidate)\n"
+        );
+    }
+}
@@ -0,0 +1,665 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Versioning schemes, Semantic Versioning included.
+//!
+//! Each scheme parses versions into a type ordered by the scheme's
+//! precedence. Results are generic over the scheme, with versions kept as
+//! they were written.
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use thiserror::Error;
+
+use crate::misc::ApplicationOutput;
+use crate::results::{
+    ComparisonStatement, FilterTestResult, FlatVersionsList, OrderedVersionMap, SortEntry,
+    ValidateResult,
+};
+
+pub(crate) mod calver;
+pub(crate) mod debian;
+pub(crate) mod go;
+pub(crate) mod k8s;
+pub(crate) mod maven;
+pub(crate) mod nuget;
+pub(crate) mod pep440;
+pub(crate) mod rpm;
+pub(crate) mod semantic;
+
+/// A versioning scheme, deciding how versions are parsed and ordered.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Scheme {
+    /// Semantic Versioning 2.0.0.
+    #[default]
+    Semver,
+    /// Debian package versions (`[epoch:]upstream_version[-debian_revision]`),
+    /// ordered as `dpkg --compare-versions` does.
+    Debian,
+    /// RPM package versions (`[epoch:]version[-release]`), ordered as
+    /// `rpmvercmp` does.
+    Rpm,
+    /// Python package versions (`[N!]N(.N)*[{a|b|rc}N][.postN][.devN][+local]`),
+    /// ordered and matched as PEP 440 describes.
+    Pep440,
+    /// Maven artifact versions (`1.0-SNAPSHOT`, `1.0.0.RC1`), ordered as
+    /// `ComparableVersion` does, and matched against version ranges.
+    Maven,
+    /// Go module versions (`v1.2.3`, `v2.0.0+incompatible`), including
+    /// pseudo-versions, ordered as the Go toolchain does.
+    Go,
+    /// Kubernetes API versions (`v1`, `v2beta1`, `v1alpha3`), ordered by
+    /// Kubernetes' version priority. Other strings are less than any of them,
+    /// so `sort --reverse` gives priority order, with them lexically last.
+    K8sApi,
+    /// NuGet package versions (`1.2.3`, `1.2.3.4-beta.1`), including the
+    /// four-part versions of .NET assemblies, ordered as NuGet does.
+    Nuget,
+    /// Calendar versions (`2024.02.29`, `24.2.1`), numbers separated by `.`,
+    /// `-` or `_`, ordered number by number. With `--format`, versions must
+    /// match the format, like `YYYY.0M.0D`.
+    Calver,
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_possible_value() {
+            Some(value) => write!(f, "{}", value.get_name()),
+            None => write!(f, "{self:?}"),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum SchemeError {
+    /// The input was not a Semantic Version.
+    #[error(transparent)]
+    Semver(#[from] semver::Error),
+
+    /// A required part of the version was empty.
+    #[error("{part} is empty")]
+    Empty { part: &'static str },
+
+    /// The scheme doesn't allow a character.
+    #[error("invalid character {character:?} in {part}")]
+    InvalidCharacter { part: &'static str, character: char },
+
+    /// A part of the version was otherwise malformed.
+    #[error("{part} {reason}")]
+    Malformed { part: &'static str, reason: String },
+
+    /// The scheme doesn't support an operation.
+    #[error("{scheme:?} versions have no {operation}")]
+    Unsupported {
+        scheme: Scheme,
+        operation: &'static str,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    /// Another scheme already has the name.
+    #[error("a scheme named {name:?} is already registered")]
+    AlreadyRegistered { name: &'static str },
+}
+
+/// How a versioning scheme parses, orders and explains versions, and
+/// matches them against requirements.
+///
+/// Versions compare by the [Ord] of the parsed [VersionScheme::Version].
+/// Implement this for a scheme of your own, and add it to a
+/// [SchemeRegistry] to use it with `--scheme`.
+pub trait VersionScheme {
+    /// A parsed version, ordered by the scheme's precedence.
+    type Version: Ord;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError>;
+
+    /// Accept a valid version. By default, any version that parses.
+    fn is_valid(input: &str) -> bool {
+        Self::parse(input).is_ok()
+    }
+
+    /// Order two versions of equal precedence, written as `written`, when
+    /// sorting lexically (and for `compare`'s lexical ordering). By default,
+    /// by how they were written.
+    fn cmp_lexical(_a: &Self::Version, _b: &Self::Version, written: (&str, &str)) -> Ordering {
+        written.0.cmp(written.1)
+    }
+
+    /// Name a group of versions of equal precedence, by the (lexically)
+    /// least of them, when sorting. By default, as it was written.
+    fn precedence_key(_version: &Self::Version, written: &str) -> String {
+        written.to_string()
+    }
+
+    /// Break a version apart, into its individual components.
+    fn explain(version: &Self::Version) -> SchemeExplanation;
+
+    /// A requirement versions can be tested against, like `>=1.0, <2.0`.
+    type Requirement;
+
+    fn parse_requirement(input: &str) -> Result<Self::Requirement, SchemeError>;
+
+    fn matches(requirement: &Self::Requirement, version: &Self::Version) -> bool;
+}
+
+/// Descriptive information about a version, in any scheme.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct SchemeExplanation(Explanation);
+
+impl SchemeExplanation {
+    /// Explain a version of a scheme outside of this crate, with anything
+    /// that serializes (for YAML & JSON output), and displays (for text
+    /// output).
+    pub fn custom<T: Serialize + fmt::Display>(explanation: &T) -> Self {
+        let text = explanation.to_string();
+        let value = serde_json::to_value(explanation)
+            .unwrap_or_else(|_| serde_json::Value::String(text.clone()));
+        SchemeExplanation(Explanation::Custom(CustomExplanation { value, text }))
+    }
+}
+
+impl From<Explanation> for SchemeExplanation {
+    fn from(value: Explanation) -> Self {
+        SchemeExplanation(value)
+    }
+}
+
+impl fmt::Display for SchemeExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The explanation of each scheme.
+#[derive(Serialize)]
+#[serde(untagged)]
+pub(crate) enum Explanation {
+    Semver(crate::results::VersionExplanation),
+    Debian(debian::DebianExplanation),
+    Rpm(rpm::RpmExplanation),
+    Pep440(pep440::Pep440Explanation),
+    Maven(maven::MavenExplanation),
+    Go(go::GoExplanation),
+    K8sApi(k8s::K8sApiExplanation),
+    Nuget(nuget::NugetExplanation),
+    Calver(calver::CalverExplanation),
+    Custom(CustomExplanation),
+}
+
+/// An explanation from a scheme outside of this crate.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub(crate) struct CustomExplanation {
+    value: serde_json::Value,
+    #[serde(skip)]
+    text: String,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Explanation::Semver(v) => write!(f, "{v}"),
+            Explanation::Debian(v) => write!(f, "{v}"),
+            Explanation::Rpm(v) => write!(f, "{v}"),
+            Explanation::Pep440(v) => write!(f, "{v}"),
+            Explanation::Maven(v) => write!(f, "{v}"),
+            Explanation::Go(v) => write!(f, "{v}"),
+            Explanation::K8sApi(v) => write!(f, "{v}"),
+            Explanation::Nuget(v) => write!(f, "{v}"),
+            Explanation::Calver(v) => write!(f, "{v}"),
+            Explanation::Custom(v) => writeln!(f, "{}", v.text),
+        }
+    }
+}
+
+/// Call a function generic over [VersionScheme], for a [Scheme].
+macro_rules! with_scheme {
+    ($scheme:expr, $function:ident($($arg:expr),*)) => {
+        match *$scheme {
+            Scheme::Semver => $function::<semantic::Semver>($($arg),*),
+            Scheme::Debian => $function::<debian::Debian>($($arg),*),
+            Scheme::Rpm => $function::<rpm::Rpm>($($arg),*),
+            Scheme::Pep440 => $function::<pep440::Pep440>($($arg),*),
+            Scheme::Maven => $function::<maven::Maven>($($arg),*),
+            Scheme::Go => $function::<go::Go>($($arg),*),
+            Scheme::K8sApi => $function::<k8s::K8sApi>($($arg),*),
+            Scheme::Nuget => $function::<nuget::Nuget>($($arg),*),
+            Scheme::Calver => $function::<calver::Calver>($($arg),*),
+        }
+    };
+}
+
+/// Versions sorted by a [DynScheme], for output.
+pub(crate) struct Sorted {
+    pub(crate) output: ApplicationOutput,
+    pub(crate) potentially_ambiguous: bool,
+}
+
+/// A versioning scheme as a trait object, working on versions as written.
+///
+/// Every [VersionScheme] is one, once registered, and so is every [Scheme].
+pub(crate) trait DynScheme {
+    /// Accept a valid version of this scheme.
+    fn check(&self, input: &str) -> Result<String, SchemeError>;
+
+    fn validate(&self, input: &str) -> ValidateResult;
+
+    /// Group versions of equal precedence, and order the groups (or flatten
+    /// them, into a list).
+    fn sort(
+        &self,
+        versions: Vec<String>,
+        lexical_sorting: bool,
+        reverse: bool,
+        flatten: bool,
+    ) -> Result<Sorted, SchemeError>;
+
+    fn compare(&self, a: &str, b: &str) -> Result<ComparisonStatement, SchemeError>;
+
+    fn explain(&self, input: &str) -> Result<SchemeExplanation, SchemeError>;
+
+    /// Accept a valid requirement of this scheme.
+    fn check_requirement(&self, input: &str) -> Result<String, SchemeError>;
+
+    fn filter_test(&self, filter: &str, version: &str) -> Result<FilterTestResult, SchemeError>;
+}
+
+impl DynScheme for Scheme {
+    fn check(&self, input: &str) -> Result<String, SchemeError> {
+        with_scheme!(self, check(input))
+    }
+
+    fn validate(&self, input: &str) -> ValidateResult {
+        with_scheme!(self, validate(input))
+    }
+
+    fn sort(
+        &self,
+        versions: Vec<String>,
+        lexical_sorting: bool,
+        reverse: bool,
+        flatten: bool,
+    ) -> Result<Sorted, SchemeError> {
+        with_scheme!(self, sort(versions, lexical_sorting, reverse, flatten))
+    }
+
+    fn compare(&self, a: &str, b: &str) -> Result<ComparisonStatement, SchemeError> {
+        with_scheme!(self, compare(a, b))
+    }
+
+    fn explain(&self, input: &str) -> Result<SchemeExplanation, SchemeError> {
+        with_scheme!(self, explain(input))
+    }
+
+    fn check_requirement(&self, input: &str) -> Result<String, SchemeError> {
+        with_scheme!(self, check_requirement(input))
+    }
+
+    fn filter_test(&self, filter: &str, version: &str) -> Result<FilterTestResult, SchemeError> {
+        with_scheme!(self, filter_test(filter, version))
+    }
+}
+
+impl Scheme {
+    /// Order two versions of this scheme, by precedence.
+    pub(crate) fn cmp(self, a: &str, b: &str) -> Result<Ordering, SchemeError> {
+        with_scheme!(&self, precedence(a, b))
+    }
+}
+
+/// A [VersionScheme] from outside of this crate.
+struct Registered<S>(PhantomData<S>);
+
+impl<S: VersionScheme> DynScheme for Registered<S> {
+    fn check(&self, input: &str) -> Result<String, SchemeError> {
+        check::<S>(input)
+    }
+
+    fn validate(&self, input: &str) -> ValidateResult {
+        validate::<S>(input)
+    }
+
+    fn sort(
+        &self,
+        versions: Vec<String>,
+        lexical_sorting: bool,
+        reverse: bool,
+        flatten: bool,
+    ) -> Result<Sorted, SchemeError> {
+        sort::<S>(versions, lexical_sorting, reverse, flatten)
+    }
+
+    fn compare(&self, a: &str, b: &str) -> Result<ComparisonStatement, SchemeError> {
+        compare::<S>(a, b)
+    }
+
+    fn explain(&self, input: &str) -> Result<SchemeExplanation, SchemeError> {
+        explain::<S>(input)
+    }
+
+    fn check_requirement(&self, input: &str) -> Result<String, SchemeError> {
+        check_requirement::<S>(input)
+    }
+
+    fn filter_test(&self, filter: &str, version: &str) -> Result<FilterTestResult, SchemeError> {
+        filter_test::<S>(filter, version)
+    }
+}
+
+/// A versioning scheme `--scheme` can select.
+pub(crate) struct RegisteredScheme {
+    value: PossibleValue,
+    /// Which of the built in schemes this is, if any.
+    pub(crate) builtin: Option<Scheme>,
+    pub(crate) scheme: Box<dyn DynScheme>,
+}
+
+/// The versioning schemes `--scheme` can select, by name.
+///
+/// The default registry has every built in [Scheme], under the same names
+/// `sem-tool` uses.
+pub struct SchemeRegistry {
+    schemes: Vec<RegisteredScheme>,
+}
+
+impl Default for SchemeRegistry {
+    fn default() -> Self {
+        let schemes = Scheme::value_variants()
+            .iter()
+            .filter_map(|scheme| {
+                Some(RegisteredScheme {
+                    value: scheme.to_possible_value()?,
+                    builtin: Some(*scheme),
+                    scheme: Box::new(*scheme),
+                })
+            })
+            .collect();
+        Self { schemes }
+    }
+}
+
+impl SchemeRegistry {
+    /// Register a versioning scheme under `name`. `help` is shown by
+    /// `--help`.
+    ///
+    /// Fails if a scheme is already registered with that name, including
+    /// the built in schemes (which `sem-tool` relies on).
+    pub fn register<S: VersionScheme + 'static>(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+    ) -> Result<&mut Self, RegistryError> {
+        if self.get(name).is_some() {
+            return Err(RegistryError::AlreadyRegistered { name });
+        }
+        self.schemes.push(RegisteredScheme {
+            value: PossibleValue::new(name).help(help),
+            builtin: None,
+            scheme: Box::new(Registered::<S>(PhantomData)),
+        });
+        Ok(self)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&RegisteredScheme> {
+        self.schemes
+            .iter()
+            .find(|registered| registered.value.get_name() == name)
+    }
+
+    /// Every registered name, for `--scheme`.
+    pub(crate) fn possible_values(&self) -> Vec<PossibleValue> {
+        self.schemes
+            .iter()
+            .map(|registered| registered.value.clone())
+            .collect()
+    }
+}
+
+/// Reject the first character of `part` that isn't alphanumeric, or in
+/// `allowed`.
+fn check_characters(part: &'static str, value: &str, allowed: &str) -> Result<(), SchemeError> {
+    match value
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !allowed.contains(*c))
+    {
+        Some(character) => Err(SchemeError::InvalidCharacter { part, character }),
+        None => Ok(()),
+    }
+}
+
+fn check<S: VersionScheme>(input: &str) -> Result<String, SchemeError> {
+    S::parse(input).map(|_| input.to_string())
+}
+
+fn validate<S: VersionScheme>(input: &str) -> ValidateResult {
+    S::is_valid(input).into()
+}
+
+fn check_requirement<S: VersionScheme>(input: &str) -> Result<String, SchemeError> {
+    S::parse_requirement(input).map(|_| input.to_string())
+}
+
+fn filter_test<S: VersionScheme>(
+    filter: &str,
+    version: &str,
+) -> Result<FilterTestResult, SchemeError> {
+    Ok(FilterTestResult::test::<S>(
+        &S::parse_requirement(filter)?,
+        &S::parse(version)?,
+    ))
+}
+
+/// Group versions of equal precedence, and order the groups.
+pub(crate) fn sort<S: VersionScheme>(
+    versions: Vec<String>,
+    lexical_sorting: bool,
+    reverse: bool,
+    flatten: bool,
+) -> Result<Sorted, SchemeError> {
+    let entries = versions
+        .into_iter()
+        .map(SortEntry::parse)
+        .collect::<Result<_, _>>()?;
+    let mut map = OrderedVersionMap::<S>::from_entries(entries, &None, lexical_sorting, reverse);
+    Ok(Sorted {
+        potentially_ambiguous: map.potentially_ambiguous(),
+        output: match flatten {
+            true => FlatVersionsList::from(&mut map).into(),
+            false => map.into(),
+        },
+    })
+}
+
+fn precedence<S: VersionScheme>(a: &str, b: &str) -> Result<Ordering, SchemeError> {
+    Ok(S::parse(a)?.cmp(&S::parse(b)?))
+}
+
+fn compare<S: VersionScheme>(a: &str, b: &str) -> Result<ComparisonStatement, SchemeError> {
+    Ok(ComparisonStatement::new::<S>(
+        &SortEntry::parse(a.to_string())?,
+        &SortEntry::parse(b.to_string())?,
+    ))
+}
+
+fn explain<S: VersionScheme>(input: &str) -> Result<SchemeExplanation, SchemeError> {
+    S::parse(input).map(|version| S::explain(&version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(input: &[&str]) -> Vec<String> {
+        input.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_sort() {
+        let versions = strings(&["1.00", "2.0", "0:1.0", "1.0~rc1", "1.0"]);
+
+        let map = Scheme::Debian
+            .sort(versions.clone(), true, false, false)
+            .unwrap()
+            .output;
+        assert_eq!(
+            serde_json::to_string(&map).unwrap(),
+            r#"{"versions":{"1.0~rc1":["1.0~rc1"],"0:1.0":["0:1.0","1.0","1.00"],"2.0":["2.0"]},"potentially_ambiguous":true}"#
+        );
+
+        let map = Scheme::Debian
+            .sort(versions, true, true, false)
+            .unwrap()
+            .output;
+        assert_eq!(
+            serde_json::to_string(&map).unwrap(),
+            r#"{"versions":{"2.0":["2.0"],"0:1.0":["1.00","1.0","0:1.0"],"1.0~rc1":["1.0~rc1"]},"potentially_ambiguous":true}"#
+        );
+
+        // Semantic Versions are grouped under their precedence, as usual.
+        let map = Scheme::Semver
+            .sort(
+                strings(&["1.0.0+b", "0.1.0", "1.0.0+a"]),
+                true,
+                false,
+                false,
+            )
+            .unwrap()
+            .output;
+        assert_eq!(
+            map.to_string(),
+            "0.1.0:\n\t- 0.1.0\n1.0.0:\n\t- 1.0.0+a\n\t- 1.0.0+b\n"
+        );
+
+        assert!(Scheme::Debian
+            .sort(strings(&["1.0", ""]), true, false, false)
+            .is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let statement = Scheme::Debian.compare("1.0", "1.00").unwrap();
+        assert_eq!(
+            serde_json::to_string(&statement).unwrap(),
+            r#"{"semantic_ordering":"Equal","lexical_ordering":"Less"}"#
+        );
+        let statement = Scheme::Debian.compare("1:0.1", "2.0").unwrap();
+        assert_eq!(
+            serde_json::to_string(&statement).unwrap(),
+            r#"{"semantic_ordering":"Greater","lexical_ordering":"Greater"}"#
+        );
+        assert!(Scheme::Debian.compare("1.0", "a").is_err());
+    }
+
+    /// Plain numbers, as a third party scheme.
+    struct Serial;
+
+    impl VersionScheme for Serial {
+        type Version = u64;
+        type Requirement = u64;
+
+        fn parse(input: &str) -> Result<u64, SchemeError> {
+            input.parse().map_err(|_| SchemeError::Malformed {
+                part: "version",
+                reason: "is not a number".to_string(),
+            })
+        }
+
+        fn explain(version: &u64) -> SchemeExplanation {
+            SchemeExplanation::custom(version)
+        }
+
+        fn parse_requirement(input: &str) -> Result<u64, SchemeError> {
+            Self::parse(input)
+        }
+
+        fn matches(requirement: &u64, version: &u64) -> bool {
+            version >= requirement
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = SchemeRegistry::default();
+        assert_eq!(
+            registry.get("k8s-api").and_then(|r| r.builtin),
+            Some(Scheme::K8sApi)
+        );
+        assert!(registry.get("serial").is_none());
+
+        registry
+            .register::<Serial>("serial", "Plain numbers.")
+            .unwrap();
+        let serial = registry.get("serial").unwrap();
+        assert_eq!(serial.builtin, None);
+        assert_eq!(
+            registry.possible_values().len(),
+            Scheme::value_variants().len() + 1
+        );
+
+        let map = serial
+            .scheme
+            .sort(strings(&["10", "9", "100"]), false, false, false)
+            .unwrap()
+            .output;
+        assert_eq!(map.to_string(), "9:\n\t- 9\n10:\n\t- 10\n100:\n\t- 100\n");
+        assert!(serial.scheme.filter_test("10", "100").unwrap().pass());
+        assert!(serial.scheme.check("1.0").is_err());
+
+        let explanation = serial.scheme.explain("42").unwrap();
+        assert_eq!(explanation.to_string(), "42\n");
+        assert_eq!(serde_json::to_string(&explanation).unwrap(), "42");
+
+        // Names can't be registered again, including those of the built in
+        // schemes.
+        for name in ["serial", "debian", "semver"] {
+            assert!(matches!(
+                registry.register::<Serial>(name, "Again."),
+                Err(RegistryError::AlreadyRegistered { .. })
+            ));
+        }
+        assert_eq!(
+            registry.get("debian").and_then(|r| r.builtin),
+            Some(Scheme::Debian)
+        );
+        assert_eq!(
+            registry.possible_values().len(),
+            Scheme::value_variants().len() + 1
+        );
+    }
+
+    #[test]
+    fn test_filter_test() {
+        assert!(Scheme::Pep440
+            .filter_test("~=1.4.5", "1.4.9")
+            .unwrap()
+            .pass());
+        assert!(!Scheme::Pep440
+            .filter_test(">=1.0", "2.0rc1")
+            .unwrap()
+            .pass());
+        assert!(Scheme::Semver.filter_test(">=1.0", "1.2.3").unwrap().pass());
+        assert!(matches!(
+            Scheme::Debian.filter_test(">= 1.0", "1.0"),
+            Err(SchemeError::Unsupported { .. })
+        ));
+    }
+}
@@ -0,0 +1,467 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! NuGet package versions, parsed, normalized and compared the way NuGet
+//! does, including the four-part versions of .NET assemblies & Windows
+//! installers.
+//!
+//! `major[.minor[.patch[.revision]]][-prerelease][+metadata]`
+//!
+//! - Missing parts are 0, and leading zeros are ignored, so `1.01`,
+//!   `1.1.0` and `1.1.0.0` are all the same version.
+//! - Pre-releases are dot separated labels, like Semantic Versioning's, but
+//!   compared ignoring case. Numeric labels can't have leading zeros.
+//! - Build metadata is ignored when comparing.
+//!
+//! The normalized form (used by nuget.org) always has three parts, has a
+//! revision only when it isn't 0, keeps the pre-release as written, and
+//! drops build metadata. So `1.01.0.0-RC.1+abc` is normalized to
+//! `1.1.0-RC.1`.
+//!
+//! References:
+//! - https://learn.microsoft.com/en-us/nuget/concepts/package-versioning
+//! - https://learn.microsoft.com/en-us/windows/win32/msi/productversion
+use clap::ValueEnum;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+
+use super::{check_characters, Explanation, Scheme, SchemeError, SchemeExplanation, VersionScheme};
+use crate::results::PreMetaSegment;
+
+pub(crate) struct Nuget;
+
+/// NuGet stores each part as a (signed) 32 bit integer.
+const PART_MAX: u64 = i32::MAX as u64;
+
+const PARTS: [&str; 4] = ["major", "minor", "patch", "revision"];
+
+#[derive(Debug, Clone)]
+pub(crate) struct NugetVersion {
+    pub(crate) major: u64,
+    pub(crate) minor: u64,
+    pub(crate) patch: u64,
+    pub(crate) revision: u64,
+    pub(crate) release: Vec<String>,
+    pub(crate) metadata: Option<String>,
+}
+
+impl NugetVersion {
+    /// The version as nuget.org writes it.
+    pub(crate) fn normalized(&self) -> String {
+        let mut normalized = format!("{}.{}.{}", self.major, self.minor, self.patch);
+        if self.revision != 0 {
+            normalized.push_str(&format!(".{}", self.revision));
+        }
+        if !self.release.is_empty() {
+            normalized.push('-');
+            normalized.push_str(&self.release.join("."));
+        }
+        normalized
+    }
+}
+
+impl PartialEq for NugetVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NugetVersion {}
+
+impl PartialOrd for NugetVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NugetVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch, self.revision)
+            .cmp(&(other.major, other.minor, other.patch, other.revision))
+            .then_with(
+                || match (self.release.is_empty(), other.release.is_empty()) {
+                    // A release is greater than any of its pre-releases.
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => compare_release(&self.release, &other.release),
+                },
+            )
+    }
+}
+
+fn is_numeric(label: &str) -> bool {
+    label.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Compare pre-releases label by label. Numeric labels compare numerically,
+/// and are less than other labels, which compare ignoring case. If every
+/// label is equal, the pre-release with more labels is greater.
+fn compare_release(a: &[String], b: &[String]) -> Ordering {
+    for (a, b) in a.iter().zip(b) {
+        let order = match (is_numeric(a), is_numeric(b)) {
+            // Without leading zeros, the longer number is greater.
+            (true, true) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => a
+                .bytes()
+                .map(|b| b.to_ascii_lowercase())
+                .cmp(b.bytes().map(|b| b.to_ascii_lowercase())),
+        };
+        if order != Ordering::Equal {
+            return order;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+/// Split dot separated labels, rejecting empty ones.
+fn labels(part: &'static str, value: &str) -> Result<Vec<String>, SchemeError> {
+    value
+        .split('.')
+        .map(|label| {
+            if label.is_empty() {
+                return Err(SchemeError::Empty { part });
+            }
+            check_characters(part, label, "-")?;
+            Ok(label.to_string())
+        })
+        .collect()
+}
+
+impl VersionScheme for Nuget {
+    type Version = NugetVersion;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        if input.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        let (rest, metadata) = match input.split_once('+') {
+            Some((rest, metadata)) => (rest, Some(metadata)),
+            None => (input, None),
+        };
+        let (numbers, release) = match rest.split_once('-') {
+            Some((numbers, release)) => (numbers, Some(release)),
+            None => (rest, None),
+        };
+
+        let mut parts = [0; 4];
+        for (i, number) in numbers.split('.').enumerate() {
+            let part = *PARTS.get(i).ok_or_else(|| SchemeError::Malformed {
+                part: "version",
+                reason: "has more than four numeric parts".to_string(),
+            })?;
+            if number.is_empty() {
+                return Err(SchemeError::Empty { part });
+            }
+            if let Some(character) = number.chars().find(|c| !c.is_ascii_digit()) {
+                return Err(SchemeError::InvalidCharacter { part, character });
+            }
+            parts[i] = match number.parse::<u64>() {
+                Ok(value) if value <= PART_MAX => value,
+                _ => {
+                    return Err(SchemeError::Malformed {
+                        part,
+                        reason: "is too big".to_string(),
+                    })
+                }
+            };
+        }
+
+        let release = match release {
+            Some(release) => labels("pre-release", release)?,
+            None => Vec::new(),
+        };
+        if let Some(label) = release
+            .iter()
+            .find(|label| label.len() > 1 && label.starts_with('0') && is_numeric(label))
+        {
+            return Err(SchemeError::Malformed {
+                part: "pre-release",
+                reason: format!("has a leading zero in `{label}`"),
+            });
+        }
+        if let Some(metadata) = metadata {
+            labels("build metadata", metadata)?;
+        }
+
+        let [major, minor, patch, revision] = parts;
+        Ok(NugetVersion {
+            major,
+            minor,
+            patch,
+            revision,
+            release,
+            metadata: metadata.map(str::to_string),
+        })
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Nuget(NugetExplanation::from(version)).into()
+    }
+
+    type Requirement = Infallible;
+
+    fn parse_requirement(_: &str) -> Result<Self::Requirement, SchemeError> {
+        Err(SchemeError::Unsupported {
+            scheme: Scheme::Nuget,
+            operation: "requirements",
+        })
+    }
+
+    fn matches(requirement: &Self::Requirement, _: &Self::Version) -> bool {
+        match *requirement {}
+    }
+}
+
+/// Limits on four-part versions, beyond NuGet's own.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Profile {
+    /// Windows Installer (MSI) product versions, at most `255.255.65535`,
+    /// without a pre-release or build metadata. Windows Installer ignores
+    /// the revision.
+    Msi,
+}
+
+impl Profile {
+    /// Check a version is within the limits of the profile.
+    pub(crate) fn check(self, version: &NugetVersion) -> Result<(), SchemeError> {
+        let limits = match self {
+            Profile::Msi => [255, 255, 65535],
+        };
+        let values = [version.major, version.minor, version.patch];
+        for ((part, value), limit) in PARTS.into_iter().zip(values).zip(limits) {
+            if value > limit {
+                return Err(SchemeError::Malformed {
+                    part,
+                    reason: format!("is greater than {limit}, the most {self} allows"),
+                });
+            }
+        }
+        if !version.release.is_empty() {
+            return Err(SchemeError::Malformed {
+                part: "pre-release",
+                reason: format!("isn't allowed by {self}"),
+            });
+        }
+        if version.metadata.is_some() {
+            return Err(SchemeError::Malformed {
+                part: "build metadata",
+                reason: format!("isn't allowed by {self}"),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Profile::Msi => write!(f, "Windows Installer"),
+        }
+    }
+}
+
+/// Descriptive information about a NuGet version.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct NugetExplanation {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    revision: u64,
+    prerelease_string: String,
+    #[serde(rename(serialize = "prerelease"))]
+    prerelease: Vec<PreMetaSegment>,
+    build_metadata_string: String,
+    #[serde(rename(serialize = "build-metadata"))]
+    build_metadata: Vec<PreMetaSegment>,
+    normalized: String,
+}
+
+impl From<&NugetVersion> for NugetExplanation {
+    fn from(value: &NugetVersion) -> Self {
+        let metadata = value.metadata.clone().unwrap_or_default();
+        Self {
+            major: value.major,
+            minor: value.minor,
+            patch: value.patch,
+            revision: value.revision,
+            prerelease: value
+                .release
+                .iter()
+                .map(|label| PreMetaSegment::from(label.as_str()))
+                .collect(),
+            prerelease_string: value.release.join("."),
+            build_metadata: metadata
+                .split('.')
+                .filter(|label| !label.is_empty())
+                .map(PreMetaSegment::from)
+                .collect(),
+            build_metadata_string: metadata,
+            normalized: value.normalized(),
+        }
+    }
+}
+
+impl fmt::Display for NugetExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Major: {}", self.major)?;
+        writeln!(f, "Minor: {}", self.minor)?;
+        writeln!(f, "Patch: {}", self.patch)?;
+        writeln!(f, "Revision: {}", self.revision)?;
+        writeln!(f, "PreRelease: {}", self.prerelease_string)?;
+        for i in self.prerelease.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        writeln!(f, "Build Metadata: {}", self.build_metadata_string)?;
+        for i in self.build_metadata.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        writeln!(f, "Normalized: {}", self.normalized)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> NugetVersion {
+        Nuget::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let version = parse("1.2.3.4-beta.2+abc.5");
+        assert_eq!(
+            (
+                version.major,
+                version.minor,
+                version.patch,
+                version.revision
+            ),
+            (1, 2, 3, 4)
+        );
+        assert_eq!(version.release, ["beta", "2"]);
+        assert_eq!(version.metadata.as_deref(), Some("abc.5"));
+        assert_eq!(parse("1").normalized(), "1.0.0");
+
+        for (input, error) in [
+            ("", "version is empty"),
+            ("1.2.3.4.5", "version has more than four numeric parts"),
+            ("1..2", "minor is empty"),
+            ("v1.0", "invalid character 'v' in major"),
+            ("1.0.0.2147483648", "revision is too big"),
+            ("1.0-", "pre-release is empty"),
+            ("1.0-rc..1", "pre-release is empty"),
+            ("1.0-rc_1", "invalid character '_' in pre-release"),
+            ("1.0-rc.01", "pre-release has a leading zero in `01`"),
+            ("1.0+", "build metadata is empty"),
+        ] {
+            assert_eq!(
+                Nuget::parse(input).unwrap_err().to_string(),
+                error,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalized() {
+        for (input, normalized) in [
+            ("1.0", "1.0.0"),
+            ("1.01.0.0", "1.1.0"),
+            ("1.0.0.4", "1.0.0.4"),
+            ("1.0.0.0-RC.1+abc", "1.0.0-RC.1"),
+        ] {
+            assert_eq!(parse(input).normalized(), normalized, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        let ordered = [
+            "1.0.0-1",
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-Beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.10",
+            "1.0.0",
+            "1.0.0.1",
+            "1.0.1-rc",
+            "1.0.1",
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(
+                parse(pair[0]).cmp(&parse(pair[1])),
+                Ordering::Less,
+                "{pair:?}"
+            );
+        }
+        assert_eq!(parse("1.0.0-RC").cmp(&parse("1.0.0-rc")), Ordering::Equal);
+        assert_eq!(parse("1.0+a").cmp(&parse("1.0.0.0+b")), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_profile() {
+        assert!(Profile::Msi.check(&parse("255.255.65535.99")).is_ok());
+        for (input, error) in [
+            (
+                "256.0.0",
+                "major is greater than 255, the most Windows Installer allows",
+            ),
+            (
+                "1.256.0",
+                "minor is greater than 255, the most Windows Installer allows",
+            ),
+            (
+                "1.0.65536",
+                "patch is greater than 65535, the most Windows Installer allows",
+            ),
+            ("1.0.0-rc", "pre-release isn't allowed by Windows Installer"),
+            (
+                "1.0.0+abc",
+                "build metadata isn't allowed by Windows Installer",
+            ),
+        ] {
+            assert_eq!(
+                Profile::Msi.check(&parse(input)).unwrap_err().to_string(),
+                error,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            NugetExplanation::from(&parse("1.02.3.0-rc.1+abc")).to_string(),
+            "Major: 1\n\
+             Minor: 2\n\
+             Patch: 3\n\
+             Revision: 0\n\
+             PreRelease: rc.1\n\
+             - rc (Ascii)\n\
+             - 1 (Numeric)\n\
+             Build Metadata: abc\n\
+             - abc (Ascii)\n\
+             Normalized: 1.2.3-rc.1\n"
+        );
+    }
+}
@@ -0,0 +1,769 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Python package versions & specifiers, as described by PEP 440, and
+//! interpreted the way `packaging` (and so pip) does.
+//!
+//! `[N!]N(.N)*[{a|b|rc}N][.postN][.devN][+local]`
+//!
+//! Alternate spellings (`1.0-alpha.1`, `1.0-1`, `v1.0`, `1.0.DEV`, ...) are
+//! accepted, and explained in their normalized form.
+//!
+//! Versions are ordered by epoch, then release (with trailing zeros ignored,
+//! so `1.0 == 1.0.0`), then: dev releases of the release, pre-releases (`a`
+//! < `b` < `rc`), the release itself, then post releases. Dev releases of a
+//! pre or post release sort just before it. A local label sorts after the
+//! same version without one.
+//!
+//! References:
+//! - https://peps.python.org/pep-0440/
+//! - https://packaging.python.org/en/latest/specifications/version-specifiers/
+use regex::Regex;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::LazyLock;
+
+use super::{Explanation, SchemeError, SchemeExplanation, VersionScheme};
+use crate::results::PreMetaSegment;
+
+pub(crate) struct Pep440;
+
+/// `packaging`'s `VERSION_PATTERN`, for lowercase input.
+static VERSION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?x)
+        ^v?
+        (?:(?P<epoch>[0-9]+)!)?
+        (?P<release>[0-9]+(?:\.[0-9]+)*)
+        (?P<pre>
+            [-_.]?
+            (?P<pre_l>alpha|a|beta|b|preview|pre|c|rc)
+            [-_.]?
+            (?P<pre_n>[0-9]+)?
+        )?
+        (?P<post>
+            (?:-(?P<post_n1>[0-9]+))
+            |
+            (?:
+                [-_.]?
+                (?P<post_l>post|rev|r)
+                [-_.]?
+                (?P<post_n2>[0-9]+)?
+            )
+        )?
+        (?P<dev>
+            [-_.]?
+            dev
+            [-_.]?
+            (?P<dev_n>[0-9]+)?
+        )?
+        (?:\+(?P<local>[a-z0-9]+(?:[-_.][a-z0-9]+)*))?
+        $",
+    )
+    .expect("static regex should compile")
+});
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum PreReleaseType {
+    Alpha,
+    Beta,
+    ReleaseCandidate,
+}
+
+impl PreReleaseType {
+    /// The normalized spelling.
+    fn as_str(&self) -> &'static str {
+        match self {
+            PreReleaseType::Alpha => "a",
+            PreReleaseType::Beta => "b",
+            PreReleaseType::ReleaseCandidate => "rc",
+        }
+    }
+}
+
+/// A segment of a local label. Alphanumeric segments sort before numeric
+/// ones.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum LocalSegment {
+    Alphanumeric(String),
+    Numeric(u64),
+}
+
+impl fmt::Display for LocalSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalSegment::Alphanumeric(s) => write!(f, "{s}"),
+            LocalSegment::Numeric(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// Where a missing part of a version sorts, relative to present ones.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Bound<T> {
+    Before,
+    Value(T),
+    After,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Pep440Version {
+    epoch: u64,
+    release: Vec<u64>,
+    pre: Option<(PreReleaseType, u64)>,
+    post: Option<u64>,
+    dev: Option<u64>,
+    local: Option<Vec<LocalSegment>>,
+}
+
+type SortKey<'a> = (
+    u64,
+    &'a [u64],
+    Bound<(PreReleaseType, u64)>,
+    Bound<u64>,
+    Bound<u64>,
+    Bound<&'a [LocalSegment]>,
+);
+
+impl Pep440Version {
+    /// `packaging`'s `_cmpkey`.
+    fn key(&self) -> SortKey<'_> {
+        let zeros = self.release.iter().rev().take_while(|n| **n == 0).count();
+        let release = &self.release[..self.release.len() - zeros];
+        let pre = match (self.pre, self.post, self.dev) {
+            // `1.0.dev0` sorts before `1.0a0`.
+            (None, None, Some(_)) => Bound::Before,
+            (None, _, _) => Bound::After,
+            (Some(pre), _, _) => Bound::Value(pre),
+        };
+        let post = self.post.map_or(Bound::Before, Bound::Value);
+        let dev = self.dev.map_or(Bound::After, Bound::Value);
+        let local = self.local.as_deref().map_or(Bound::Before, Bound::Value);
+        (self.epoch, release, pre, post, dev, local)
+    }
+
+    pub(crate) fn is_prerelease(&self) -> bool {
+        self.pre.is_some() || self.dev.is_some()
+    }
+
+    fn is_postrelease(&self) -> bool {
+        self.post.is_some()
+    }
+
+    /// The version without a local label.
+    fn public(&self) -> Self {
+        Self {
+            local: None,
+            ..self.clone()
+        }
+    }
+
+    /// Only the epoch and release.
+    fn base(&self) -> Self {
+        Self {
+            epoch: self.epoch,
+            release: self.release.clone(),
+            pre: None,
+            post: None,
+            dev: None,
+            local: None,
+        }
+    }
+}
+
+impl PartialEq for Pep440Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pep440Version {}
+
+impl PartialOrd for Pep440Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pep440Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// The normalized form.
+impl fmt::Display for Pep440Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.epoch != 0 {
+            write!(f, "{}!", self.epoch)?;
+        }
+        let release: Vec<String> = self.release.iter().map(u64::to_string).collect();
+        write!(f, "{}", release.join("."))?;
+        if let Some((kind, n)) = self.pre {
+            write!(f, "{}{n}", kind.as_str())?;
+        }
+        if let Some(n) = self.post {
+            write!(f, ".post{n}")?;
+        }
+        if let Some(n) = self.dev {
+            write!(f, ".dev{n}")?;
+        }
+        if let Some(local) = &self.local {
+            let local: Vec<String> = local.iter().map(LocalSegment::to_string).collect();
+            write!(f, "+{}", local.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+fn number(part: &'static str, digits: &str) -> Result<u64, SchemeError> {
+    digits.parse::<u64>().map_err(|_| SchemeError::Malformed {
+        part,
+        reason: "is too big".to_string(),
+    })
+}
+
+impl VersionScheme for Pep440 {
+    type Version = Pep440Version;
+    type Requirement = SpecifierSet;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        let input = input.trim().to_ascii_lowercase();
+        if input.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        let captures = VERSION
+            .captures(&input)
+            .ok_or_else(|| SchemeError::Malformed {
+                part: "version",
+                reason: "is not a valid PEP 440 version".to_string(),
+            })?;
+        let optional_number = |name: &str, part: &'static str| match captures.name(name) {
+            Some(digits) => number(part, digits.as_str()).map(Some),
+            None => Ok(None),
+        };
+
+        let epoch = optional_number("epoch", "epoch")?.unwrap_or(0);
+        let release = captures["release"]
+            .split('.')
+            .map(|n| number("release", n))
+            .collect::<Result<_, _>>()?;
+        let pre = match captures.name("pre_l") {
+            Some(label) => {
+                let kind = match label.as_str() {
+                    "a" | "alpha" => PreReleaseType::Alpha,
+                    "b" | "beta" => PreReleaseType::Beta,
+                    _ => PreReleaseType::ReleaseCandidate,
+                };
+                Some((kind, optional_number("pre_n", "pre-release")?.unwrap_or(0)))
+            }
+            None => None,
+        };
+        let post = match captures.name("post") {
+            Some(_) => Some(
+                optional_number("post_n1", "post-release")?
+                    .or(optional_number("post_n2", "post-release")?)
+                    .unwrap_or(0),
+            ),
+            None => None,
+        };
+        let dev = match captures.name("dev") {
+            Some(_) => Some(optional_number("dev_n", "dev-release")?.unwrap_or(0)),
+            None => None,
+        };
+        let local = captures.name("local").map(|local| {
+            local
+                .as_str()
+                .split(['-', '_', '.'])
+                .map(|segment| match segment.parse::<u64>() {
+                    Ok(n) if segment.bytes().all(|c| c.is_ascii_digit()) => {
+                        LocalSegment::Numeric(n)
+                    }
+                    _ => LocalSegment::Alphanumeric(segment.to_string()),
+                })
+                .collect()
+        });
+
+        Ok(Pep440Version {
+            epoch,
+            release,
+            pre,
+            post,
+            dev,
+            local,
+        })
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Pep440(Pep440Explanation::from(version)).into()
+    }
+
+    fn parse_requirement(input: &str) -> Result<Self::Requirement, SchemeError> {
+        SpecifierSet::parse(input)
+    }
+
+    fn matches(requirement: &Self::Requirement, version: &Self::Version) -> bool {
+        requirement.contains(version)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Compatible,
+    Equal,
+    NotEqual,
+    LessEq,
+    GreaterEq,
+    Less,
+    Greater,
+    Arbitrary,
+}
+
+/// A single clause of a [SpecifierSet].
+#[derive(Debug)]
+struct Specifier {
+    operator: Operator,
+    /// Unset for `===`, which compares strings.
+    version: Option<Pep440Version>,
+    /// The version as written, for `===`.
+    spelling: String,
+    wildcard: bool,
+}
+
+static SPECIFIER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[ \t]*(~=|===|==|!=|<=|>=|<|>)[ \t]*([^ \t]+)[ \t]*$")
+        .expect("static regex should compile")
+});
+
+impl Specifier {
+    fn parse(input: &str) -> Result<Self, SchemeError> {
+        let malformed = |reason: &str| SchemeError::Malformed {
+            part: "specifier",
+            reason: format!("{input:?} {reason}"),
+        };
+        let captures = SPECIFIER
+            .captures(input)
+            .ok_or_else(|| malformed("is not an operator followed by a version"))?;
+        let operator = match &captures[1] {
+            "~=" => Operator::Compatible,
+            "==" => Operator::Equal,
+            "!=" => Operator::NotEqual,
+            "<=" => Operator::LessEq,
+            ">=" => Operator::GreaterEq,
+            "<" => Operator::Less,
+            ">" => Operator::Greater,
+            _ => Operator::Arbitrary,
+        };
+        let spelling = captures[2].to_string();
+        if operator == Operator::Arbitrary {
+            return Ok(Self {
+                operator,
+                version: None,
+                spelling,
+                wildcard: false,
+            });
+        }
+
+        let (written, wildcard) = match spelling.strip_suffix(".*") {
+            Some(prefix) => (prefix, true),
+            None => (spelling.as_str(), false),
+        };
+        let version = Pep440::parse(written).map_err(|e| malformed(&format!("has {e}")))?;
+
+        if wildcard {
+            if !matches!(operator, Operator::Equal | Operator::NotEqual) {
+                return Err(malformed("only allows a wildcard with == or !="));
+            }
+            if version != version.base() || version.local.is_some() {
+                return Err(malformed("only allows a wildcard after a release"));
+            }
+        }
+        if version.local.is_some() && !matches!(operator, Operator::Equal | Operator::NotEqual) {
+            return Err(malformed("only allows a local label with == or !="));
+        }
+        if operator == Operator::Compatible && version.release.len() < 2 {
+            return Err(malformed("needs at least 2 release segments with ~="));
+        }
+
+        Ok(Self {
+            operator,
+            version: Some(version),
+            spelling,
+            wildcard,
+        })
+    }
+
+    /// If this clause allows pre-releases, by naming one.
+    fn names_prerelease(&self) -> bool {
+        self.operator != Operator::NotEqual
+            && self.version.as_ref().is_some_and(|v| v.is_prerelease())
+    }
+
+    fn contains(&self, candidate: &Pep440Version) -> bool {
+        let Some(spec) = &self.version else {
+            // Like pip, compare against the normalized version.
+            return candidate.to_string() == self.spelling.to_ascii_lowercase();
+        };
+        match self.operator {
+            Operator::Compatible => {
+                let prefix = &spec.release[..spec.release.len() - 1];
+                candidate >= spec && prefix_matches(spec.epoch, prefix, candidate)
+            }
+            Operator::Equal => self.equal(spec, candidate),
+            Operator::NotEqual => !self.equal(spec, candidate),
+            Operator::LessEq => candidate.public() <= *spec,
+            Operator::GreaterEq => candidate.public() >= *spec,
+            // `<1.0` doesn't allow `1.0rc1`, unless the version is itself a
+            // pre-release.
+            Operator::Less => {
+                candidate < spec
+                    && (spec.is_prerelease()
+                        || !candidate.is_prerelease()
+                        || candidate.base() != spec.base())
+            }
+            // `>1.0` doesn't allow `1.0.post1` (unless the version is itself
+            // a post release), nor `1.0+local`.
+            Operator::Greater => {
+                candidate > spec
+                    && (spec.is_postrelease()
+                        || !candidate.is_postrelease()
+                        || candidate.base() != spec.base())
+                    && (candidate.local.is_none() || candidate.base() != spec.base())
+            }
+            Operator::Arbitrary => unreachable!("=== has no parsed version"),
+        }
+    }
+
+    fn equal(&self, spec: &Pep440Version, candidate: &Pep440Version) -> bool {
+        match (self.wildcard, &spec.local) {
+            (true, _) => prefix_matches(spec.epoch, &spec.release, candidate),
+            // Without a local label, any local label matches.
+            (false, None) => candidate.public() == *spec,
+            (false, Some(_)) => candidate == spec,
+        }
+    }
+}
+
+/// If a candidate's release starts with `prefix` (padding the candidate's
+/// release with zeros, as needed).
+fn prefix_matches(epoch: u64, prefix: &[u64], candidate: &Pep440Version) -> bool {
+    epoch == candidate.epoch
+        && prefix
+            .iter()
+            .enumerate()
+            .all(|(i, n)| candidate.release.get(i).unwrap_or(&0) == n)
+}
+
+/// Comma separated [Specifier]s, which must all match.
+///
+/// Like pip, pre-releases (and dev releases) only match when a clause names
+/// one, as in `>=1.0rc1`.
+#[derive(Debug)]
+pub(crate) struct SpecifierSet {
+    specifiers: Vec<Specifier>,
+}
+
+impl SpecifierSet {
+    fn parse(input: &str) -> Result<Self, SchemeError> {
+        let input = input.to_ascii_lowercase();
+        if input.trim().is_empty() {
+            return Err(SchemeError::Empty { part: "specifier" });
+        }
+        let specifiers = input
+            .split(',')
+            .map(Specifier::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { specifiers })
+    }
+
+    fn contains(&self, candidate: &Pep440Version) -> bool {
+        if candidate.is_prerelease() && !self.specifiers.iter().any(Specifier::names_prerelease) {
+            return false;
+        }
+        self.specifiers.iter().all(|s| s.contains(candidate))
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) enum ReleaseSegmentType {
+    Major,
+    Minor,
+    Micro,
+    /// Any segment after MAJOR.MINOR.MICRO.
+    Extra,
+}
+
+impl fmt::Display for ReleaseSegmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseSegmentType::Major => write!(f, "Major"),
+            ReleaseSegmentType::Minor => write!(f, "Minor"),
+            ReleaseSegmentType::Micro => write!(f, "Micro"),
+            ReleaseSegmentType::Extra => write!(f, "Extra"),
+        }
+    }
+}
+
+/// A component of the release segment, like `2` in `1.2.3`.
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct ReleaseSegment {
+    kind: ReleaseSegmentType,
+    value: u64,
+}
+
+impl fmt::Display for ReleaseSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.value, self.kind)
+    }
+}
+
+/// A pre-release, like `rc1`.
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct PreRelease {
+    kind: PreReleaseType,
+    number: u64,
+}
+
+/// Descriptive information about a PEP 440 version.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct Pep440Explanation {
+    normalized: String,
+    epoch: u64,
+    release: Vec<ReleaseSegment>,
+    prerelease: Option<PreRelease>,
+    post_release: Option<u64>,
+    dev_release: Option<u64>,
+    local_string: String,
+    local: Vec<PreMetaSegment>,
+    /// Excluded from specifiers, unless a clause names a pre-release.
+    is_prerelease: bool,
+}
+
+impl From<&Pep440Version> for Pep440Explanation {
+    fn from(value: &Pep440Version) -> Self {
+        let local_string = match &value.local {
+            Some(local) => local
+                .iter()
+                .map(LocalSegment::to_string)
+                .collect::<Vec<_>>()
+                .join("."),
+            None => String::new(),
+        };
+        Self {
+            normalized: value.to_string(),
+            epoch: value.epoch,
+            release: value
+                .release
+                .iter()
+                .enumerate()
+                .map(|(i, value)| ReleaseSegment {
+                    kind: match i {
+                        0 => ReleaseSegmentType::Major,
+                        1 => ReleaseSegmentType::Minor,
+                        2 => ReleaseSegmentType::Micro,
+                        _ => ReleaseSegmentType::Extra,
+                    },
+                    value: *value,
+                })
+                .collect(),
+            prerelease: value.pre.map(|(kind, number)| PreRelease { kind, number }),
+            post_release: value.post,
+            dev_release: value.dev,
+            local: match value.local {
+                Some(_) => local_string.split('.').map(PreMetaSegment::from).collect(),
+                None => Vec::new(),
+            },
+            local_string,
+            is_prerelease: value.is_prerelease(),
+        }
+    }
+}
+
+impl fmt::Display for Pep440Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let optional = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_default();
+        writeln!(f, "Normalized: {}", self.normalized)?;
+        writeln!(f, "Epoch: {}", self.epoch)?;
+        writeln!(f, "Release:")?;
+        for i in self.release.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        match &self.prerelease {
+            Some(pre) => writeln!(f, "PreRelease: {} ({:?})", pre.number, pre.kind)?,
+            None => writeln!(f, "PreRelease: ")?,
+        }
+        writeln!(f, "PostRelease: {}", optional(self.post_release))?;
+        writeln!(f, "DevRelease: {}", optional(self.dev_release))?;
+        writeln!(f, "Local: {}", self.local_string)?;
+        for i in self.local.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Pep440Version {
+        Pep440::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        for (input, normalized) in [
+            ("1.2.3", "1.2.3"),
+            ("v1.2.3", "1.2.3"),
+            ("1!2.0", "1!2.0"),
+            ("1.2.3a1", "1.2.3a1"),
+            ("1.2.3-ALPHA.1", "1.2.3a1"),
+            ("1.2.3beta", "1.2.3b0"),
+            ("1.2.3c2", "1.2.3rc2"),
+            ("1.2.3pre2", "1.2.3rc2"),
+            ("1.2.3.post2", "1.2.3.post2"),
+            ("1.2.3-2", "1.2.3.post2"),
+            ("1.2.3rev", "1.2.3.post0"),
+            ("1.2.3.dev4+local", "1.2.3.dev4+local"),
+            ("1.2.3.DEV4+Ubuntu-1_a", "1.2.3.dev4+ubuntu.1.a"),
+            (" 1.0 ", "1.0"),
+        ] {
+            assert_eq!(parse(input).to_string(), normalized, "{input}");
+        }
+
+        for input in ["", "1.2.3-rc.1+", "a.b.c", "1.2.3.x", "1..2", "1.0+"] {
+            assert!(Pep440::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        // From PEP 440's example of the overall ordering.
+        let ordered = [
+            "1.dev0",
+            "1.0.dev456",
+            "1.0a1",
+            "1.0a2.dev456",
+            "1.0a12.dev456",
+            "1.0a12",
+            "1.0b1.dev456",
+            "1.0b2",
+            "1.0b2.post345.dev456",
+            "1.0b2.post345",
+            "1.0rc1.dev456",
+            "1.0rc1",
+            "1.0",
+            "1.0+abc.5",
+            "1.0+abc.7",
+            "1.0+5",
+            "1.0.post456.dev34",
+            "1.0.post456",
+            "1.0.15",
+            "1.1.dev1",
+            "1!0.1",
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(
+                parse(pair[0]).cmp(&parse(pair[1])),
+                Ordering::Less,
+                "{pair:?}"
+            );
+        }
+
+        assert_eq!(parse("1.0").cmp(&parse("1.0.0")), Ordering::Equal);
+        assert_eq!(parse("1.0").cmp(&parse("1.0.post0")), Ordering::Less);
+    }
+
+    #[test]
+    fn test_specifiers() {
+        for (specifier, version, expected) in [
+            ("~=2.2", "2.3", true),
+            ("~=2.2", "3.0", false),
+            ("~=2.2", "2.1", false),
+            ("~=1.4.5", "1.4.9", true),
+            ("~=1.4.5", "1.5.0", false),
+            ("~=2.2.post3", "2.9", true),
+            ("~=2.2.post3", "2.2", false),
+            ("==1.1", "1.1.0", true),
+            ("==1.1", "1.1+local", true),
+            ("==1.1+local", "1.1", false),
+            ("==1.1.*", "1.1.9", true),
+            ("==1.1.*", "1.1.post1", true),
+            ("==1.1.*", "1.10", false),
+            ("==1.*", "1", true),
+            ("!=1.1.*", "1.2", true),
+            ("!=1.1.*", "1.1.2", false),
+            ("!=1.1", "1.1.1", true),
+            ("===1.0", "1.0", true),
+            ("===1.0", "1.0.0", false),
+            ("<=1.0", "1.0+local", true),
+            (">=1.0", "1.0", true),
+            ("<2.0", "2.0rc1", false),
+            ("<2.0rc2", "2.0rc1", true),
+            (">1.7", "1.7.post1", false),
+            (">1.7.post1", "1.7.post2", true),
+            (">1.7", "1.7+local", false),
+            (">1.7", "1.7.1", true),
+            (">= 1.0, != 1.3.*, < 2.0", "1.3.4", false),
+            (">= 1.0, != 1.3.*, < 2.0", "1.4", true),
+            // Pre-releases only match when a clause names one.
+            (">=1.0", "2.0a1", false),
+            (">=1.0a1", "2.0a1", true),
+            ("!=2.0a1", "2.0a2", false),
+            (">=1.0", "1.1.dev1", false),
+        ] {
+            let set = SpecifierSet::parse(specifier).unwrap();
+            assert_eq!(
+                set.contains(&parse(version)),
+                expected,
+                "{specifier} {version}"
+            );
+        }
+
+        for specifier in [
+            "",
+            "1.0",
+            "~=1",
+            ">=1.0.*",
+            "==1.0a1.*",
+            "<1.0+local",
+            "== 1.0 foo",
+            ">=1.0,",
+        ] {
+            assert!(SpecifierSet::parse(specifier).is_err(), "{specifier:?}");
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            Pep440Explanation::from(&parse("1!1.2.3.4rc1.post2.dev3+Ubuntu-1")).to_string(),
+            "Normalized: 1!1.2.3.4rc1.post2.dev3+ubuntu.1\n\
+             Epoch: 1\n\
+             Release:\n\
+             - 1 (Major)\n\
+             - 2 (Minor)\n\
+             - 3 (Micro)\n\
+             - 4 (Extra)\n\
+             PreRelease: 1 (ReleaseCandidate)\n\
+             PostRelease: 2\n\
+             DevRelease: 3\n\
+             Local: ubuntu.1\n\
+             - ubuntu (Ascii)\n\
+             - 1 (Numeric)\n"
+        );
+    }
+}
@@ -0,0 +1,431 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! RPM package versions, parsed and compared the way rpm does.
+//!
+//! `[epoch:]version[-release]`
+//!
+//! - The epoch is a number (0 if absent), and is compared first.
+//! - The version runs up to the last hyphen, and the release comes after it.
+//!   A version without a release sorts before the same version with any
+//!   release. (rpm's dependency matching instead lets a missing release
+//!   match any release, which can't be sorted.)
+//!
+//! Versions & releases are compared by `rpmvercmp`, as segments of letters
+//! or digits. Anything else only separates segments, so `1.0` and `1_0` are
+//! equal. Numeric segments compare numerically, and sort after alphabetic
+//! ones. `~` sorts before anything, even the end of the version (for
+//! pre-releases), and `^` sorts after the end of the version, but before
+//! anything else (for snapshots of an upcoming release).
+//! So `1.0~rc1` < `1.0` < `1.0^git1` < `1.0a` < `1.0.1`.
+//!
+//! References:
+//! - https://rpm-software-management.github.io/rpm/manual/dependencies.html
+//! - https://github.com/rpm-software-management/rpm/blob/master/rpmio/rpmvercmp.cc
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+
+use super::{check_characters, Explanation, Scheme, SchemeError, SchemeExplanation, VersionScheme};
+
+pub(crate) struct Rpm;
+
+#[derive(Debug, Clone)]
+pub(crate) struct RpmVersion {
+    epoch: u64,
+    version: String,
+    release: Option<String>,
+}
+
+impl PartialEq for RpmVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RpmVersion {}
+
+impl PartialOrd for RpmVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RpmVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| rpmvercmp(&self.version, &other.version))
+            .then_with(|| {
+                rpmvercmp(
+                    self.release.as_deref().unwrap_or_default(),
+                    other.release.as_deref().unwrap_or_default(),
+                )
+            })
+    }
+}
+
+/// Characters that only separate segments.
+fn is_separator(c: &u8) -> bool {
+    !c.is_ascii_alphanumeric() && *c != b'~' && *c != b'^'
+}
+
+/// The end of the segment starting at `start`: a run of digits if
+/// `numeric`, otherwise of letters.
+fn segment_end(value: &[u8], start: usize, numeric: bool) -> usize {
+    value[start..]
+        .iter()
+        .position(|c| match numeric {
+            true => !c.is_ascii_digit(),
+            false => !c.is_ascii_alphabetic(),
+        })
+        .map_or(value.len(), |len| start + len)
+}
+
+/// rpm's `rpmvercmp`, for versions & releases.
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        while a.get(i).is_some_and(is_separator) {
+            i += 1;
+        }
+        while b.get(j).is_some_and(is_separator) {
+            j += 1;
+        }
+        let (x, y) = (a.get(i), b.get(j));
+
+        // `~` sorts before anything, even the end of the version.
+        if x == Some(&b'~') || y == Some(&b'~') {
+            if x != Some(&b'~') {
+                return Ordering::Greater;
+            }
+            if y != Some(&b'~') {
+                return Ordering::Less;
+            }
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        // `^` sorts after the end of the version, but before anything else.
+        if x == Some(&b'^') || y == Some(&b'^') {
+            if x.is_none() {
+                return Ordering::Less;
+            }
+            if y.is_none() {
+                return Ordering::Greater;
+            }
+            if x != Some(&b'^') {
+                return Ordering::Greater;
+            }
+            if y != Some(&b'^') {
+                return Ordering::Less;
+            }
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        if x.is_none() || y.is_none() {
+            break;
+        }
+
+        let numeric = a[i].is_ascii_digit();
+        let (end_a, end_b) = (segment_end(a, i, numeric), segment_end(b, j, numeric));
+        // Segments of different types: numeric segments are newer.
+        if end_b == j {
+            return match numeric {
+                true => Ordering::Greater,
+                false => Ordering::Less,
+            };
+        }
+
+        let (mut segment_a, mut segment_b) = (&a[i..end_a], &b[j..end_b]);
+        let order = match numeric {
+            true => {
+                while segment_a.first() == Some(&b'0') {
+                    segment_a = &segment_a[1..];
+                }
+                while segment_b.first() == Some(&b'0') {
+                    segment_b = &segment_b[1..];
+                }
+                // Without leading zeros, the longer number is greater.
+                segment_a
+                    .len()
+                    .cmp(&segment_b.len())
+                    .then(segment_a.cmp(segment_b))
+            }
+            false => segment_a.cmp(segment_b),
+        };
+        if order != Ordering::Equal {
+            return order;
+        }
+        i = end_a;
+        j = end_b;
+    }
+
+    // Whichever still has segments left is greater.
+    match (i < a.len(), j < b.len()) {
+        (false, false) => Ordering::Equal,
+        (false, true) => Ordering::Less,
+        (true, _) => Ordering::Greater,
+    }
+}
+
+impl VersionScheme for Rpm {
+    type Version = RpmVersion;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        if input.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        check_characters("version", input, "._+~^:-")?;
+
+        // Like rpm, only leading digits followed by a colon are an epoch
+        // (an empty one being 0). Other colons are invalid characters.
+        let digits = input.bytes().take_while(u8::is_ascii_digit).count();
+        let (epoch, rest) = match input[digits..].strip_prefix(':') {
+            Some(rest) => match input[..digits].parse::<u32>() {
+                Ok(epoch) => (u64::from(epoch), rest),
+                Err(_) if digits == 0 => (0, rest),
+                // rpm stores epochs as 32 bit integers.
+                Err(_) => {
+                    return Err(SchemeError::Malformed {
+                        part: "epoch",
+                        reason: "is too big".to_string(),
+                    })
+                }
+            },
+            None => (0, input),
+        };
+
+        let (version, release) = match rest.rsplit_once('-') {
+            Some((_, "")) => return Err(SchemeError::Empty { part: "release" }),
+            Some((version, release)) => (version, Some(release)),
+            None => (rest, None),
+        };
+        if version.is_empty() {
+            return Err(SchemeError::Empty { part: "version" });
+        }
+        check_characters("version", version, "._+~^")?;
+        if let Some(release) = release {
+            check_characters("release", release, "._+~^")?;
+        }
+
+        Ok(RpmVersion {
+            epoch,
+            version: version.to_string(),
+            release: release.map(str::to_string),
+        })
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Rpm(RpmExplanation::from(version)).into()
+    }
+
+    type Requirement = Infallible;
+
+    fn parse_requirement(_: &str) -> Result<Self::Requirement, SchemeError> {
+        Err(SchemeError::Unsupported {
+            scheme: Scheme::Rpm,
+            operation: "requirements",
+        })
+    }
+
+    fn matches(requirement: &Self::Requirement, _: &Self::Version) -> bool {
+        match *requirement {}
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) enum RpmSegmentType {
+    Numeric,
+    Alpha,
+    Tilde,
+    Caret,
+}
+
+impl fmt::Display for RpmSegmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpmSegmentType::Numeric => write!(f, "Numeric"),
+            RpmSegmentType::Alpha => write!(f, "Alpha"),
+            RpmSegmentType::Tilde => write!(f, "Tilde"),
+            RpmSegmentType::Caret => write!(f, "Caret"),
+        }
+    }
+}
+
+/// A segment of a version or release, as compared by `rpmvercmp`.
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct RpmSegment {
+    kind: RpmSegmentType,
+    value: String,
+}
+
+impl fmt::Display for RpmSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.value, self.kind)
+    }
+}
+
+/// Split into the segments that are compared in turn, without separators.
+fn segments(value: &str) -> Vec<RpmSegment> {
+    let bytes = value.as_bytes();
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let (kind, end) = match bytes[i] {
+            b'~' => (RpmSegmentType::Tilde, i + 1),
+            b'^' => (RpmSegmentType::Caret, i + 1),
+            c if c.is_ascii_digit() => (RpmSegmentType::Numeric, segment_end(bytes, i, true)),
+            c if c.is_ascii_alphabetic() => (RpmSegmentType::Alpha, segment_end(bytes, i, false)),
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        segments.push(RpmSegment {
+            kind,
+            value: value[i..end].to_string(),
+        });
+        i = end;
+    }
+    segments
+}
+
+/// Descriptive information about an RPM version.
+#[derive(Serialize, PartialEq, Debug)]
+pub(crate) struct RpmExplanation {
+    epoch: u64,
+    version_string: String,
+    version: Vec<RpmSegment>,
+    release_string: String,
+    release: Vec<RpmSegment>,
+}
+
+impl From<&RpmVersion> for RpmExplanation {
+    fn from(value: &RpmVersion) -> Self {
+        let release = value.release.clone().unwrap_or_default();
+        Self {
+            epoch: value.epoch,
+            version: segments(&value.version),
+            version_string: value.version.clone(),
+            release: segments(&release),
+            release_string: release,
+        }
+    }
+}
+
+impl fmt::Display for RpmExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Epoch: {}", self.epoch)?;
+        writeln!(f, "Version: {}", self.version_string)?;
+        for i in self.version.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        writeln!(f, "Release: {}", self.release_string)?;
+        for i in self.release.iter() {
+            writeln!(f, "- {i}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> RpmVersion {
+        Rpm::parse(input).unwrap()
+    }
+
+    /// The cases shipped in `example-data`, from rpm's own test suite.
+    #[test]
+    fn test_rpmvercmp_cases() {
+        let cases = include_str!("../../example-data/rpmvercmp-cases.txt");
+        let mut count = 0;
+        for line in cases.lines() {
+            if line.starts_with('#') || line.is_empty() {
+                continue;
+            }
+            let case: Vec<&str> = line.split_whitespace().collect();
+            let [a, b, expected] = case[..] else {
+                panic!("malformed case: {line}");
+            };
+            let expected = match expected {
+                "-1" => Ordering::Less,
+                "0" => Ordering::Equal,
+                "1" => Ordering::Greater,
+                _ => panic!("malformed case: {line}"),
+            };
+            assert_eq!(parse(a).cmp(&parse(b)), expected, "{line}");
+            count += 1;
+        }
+        assert!(count > 100);
+    }
+
+    #[test]
+    fn test_parse() {
+        let version = parse("2:1.0~rc1-1.el9");
+        assert_eq!(version.epoch, 2);
+        assert_eq!(version.version, "1.0~rc1");
+        assert_eq!(version.release.as_deref(), Some("1.el9"));
+        assert_eq!(parse(":1.0").epoch, 0);
+
+        for (input, error) in [
+            ("", "version is empty"),
+            ("a:1.0", "invalid character ':' in version"),
+            ("4294967296:1.0", "epoch is too big"),
+            ("1:", "version is empty"),
+            ("-1", "version is empty"),
+            ("1.0-", "release is empty"),
+            ("1.0-1-1", "invalid character '-' in version"),
+            ("1:1.0:1", "invalid character ':' in version"),
+            ("1.0-1:1", "invalid character ':' in release"),
+            ("1.0 1", "invalid character ' ' in version"),
+        ] {
+            assert_eq!(Rpm::parse(input).unwrap_err().to_string(), error, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            RpmExplanation::from(&parse("1:1.0~rc1^git2-3.el9_2")).to_string(),
+            "Epoch: 1\n\
+             Version: 1.0~rc1^git2\n\
+             - 1 (Numeric)\n\
+             - 0 (Numeric)\n\
+             - ~ (Tilde)\n\
+             - rc (Alpha)\n\
+             - 1 (Numeric)\n\
+             - ^ (Caret)\n\
+             - git (Alpha)\n\
+             - 2 (Numeric)\n\
+             Release: 3.el9_2\n\
+             - 3 (Numeric)\n\
+             - el (Alpha)\n\
+             - 9 (Numeric)\n\
+             - 2 (Numeric)\n"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Semantic Versioning, as a [VersionScheme].
+//!
+//! Versions are valid by the specification's regex, so numbers may be
+//! larger than the semver crate can parse (`validate --small` is stricter).
+//! Versions of equal precedence (differing by build metadata) are ordered
+//! lexically as the semver crate orders them, and grouped by the version
+//! without build metadata. Requirements are in the `cargo` dialect.
+use regex::Regex;
+use semver::{Version, VersionReq};
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+use super::{Explanation, SchemeError, SchemeExplanation, VersionScheme};
+use crate::constraint::Filter;
+use crate::regex::SEMVER_REGEX;
+use crate::results::{version_without_build_metadata, VersionExplanation};
+
+static SEMVER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!("^{SEMVER_REGEX}$")).expect("static regex should compile")
+});
+
+pub(crate) struct Semver;
+
+/// A Semantic Version, ordered by precedence (ignoring build metadata).
+#[derive(Clone, Debug)]
+pub(crate) struct Precedence(pub(crate) Version);
+
+impl PartialEq for Precedence {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Precedence {}
+
+impl PartialOrd for Precedence {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Precedence {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_precedence(&other.0)
+    }
+}
+
+impl VersionScheme for Semver {
+    type Version = Precedence;
+
+    fn parse(input: &str) -> Result<Self::Version, SchemeError> {
+        Ok(Precedence(Version::parse(input)?))
+    }
+
+    fn is_valid(input: &str) -> bool {
+        SEMVER.is_match(input)
+    }
+
+    fn cmp_lexical(a: &Self::Version, b: &Self::Version, _written: (&str, &str)) -> Ordering {
+        a.0.cmp(&b.0)
+    }
+
+    fn precedence_key(version: &Self::Version, _written: &str) -> String {
+        version_without_build_metadata(&version.0).to_string()
+    }
+
+    fn explain(version: &Self::Version) -> SchemeExplanation {
+        Explanation::Semver(VersionExplanation::from(&version.0)).into()
+    }
+
+    type Requirement = Filter;
+
+    fn parse_requirement(input: &str) -> Result<Self::Requirement, SchemeError> {
+        Ok(VersionReq::parse(input)?.into())
+    }
+
+    fn matches(requirement: &Self::Requirement, version: &Self::Version) -> bool {
+        requirement.matches(&version.0)
+    }
+}
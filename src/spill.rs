@@ -0,0 +1,319 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! External memory sorting: versions are collected up to a memory budget,
+//! then sorted and spilled to a temporary file (a "run"). Once all of the
+//! input has been read, the runs are merged with [crate::merge::Merger].
+//! Only so many runs are merged at once, so while there are more than that,
+//! they are merged in passes, into fewer (longer) runs.
+//!
+//! If the budget is never exceeded, nothing touches the disk.
+use semver::Version;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::mem::size_of;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::merge::{MergeError, Merger};
+use crate::parallel;
+
+/// Distinguishes the temporary files of this process.
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// The most runs merged at once, keeping well within the limit on open
+/// files.
+const FAN_IN: usize = 64;
+
+/// A temporary file, removed when dropped.
+pub(crate) struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    fn create(dir: &Path) -> Result<(Self, File), MergeError> {
+        let run = RUNS.fetch_add(1, Ordering::Relaxed);
+        let path = dir.join(format!("sem-tool-{}-{run}.sort", process::id()));
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|err| MergeError::Io {
+                file: path.display().to_string(),
+                err,
+            })?;
+        Ok((Self { path }, file))
+    }
+
+    /// Write versions into a new file, in the order given.
+    fn write(
+        dir: &Path,
+        versions: impl Iterator<Item = Result<Version, MergeError>>,
+    ) -> Result<Self, MergeError> {
+        let (spill_file, file) = Self::create(dir)?;
+        let io_error = |err| MergeError::Io {
+            file: spill_file.path.display().to_string(),
+            err,
+        };
+        let mut out = BufWriter::new(file);
+        for version in versions {
+            writeln!(out, "{}", version?).map_err(io_error)?;
+        }
+        out.flush().map_err(io_error)?;
+        Ok(spill_file)
+    }
+
+    /// Open the file for merging.
+    pub(crate) fn open(&self) -> Result<(String, Box<dyn BufRead>), MergeError> {
+        let name = self.path.display().to_string();
+        match File::open(&self.path) {
+            Ok(file) => Ok((name, Box::new(BufReader::new(file)))),
+            Err(err) => Err(MergeError::Io { file: name, err }),
+        }
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        // Nothing more can be done, if this fails.
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Where the sorted versions ended up.
+pub(crate) enum Spilled {
+    /// Everything fit within the budget.
+    InMemory(Vec<Version>),
+    /// Sorted runs, to be merged.
+    OnDisk(Vec<SpillFile>),
+}
+
+/// Collects versions, spilling sorted runs to disk past a memory budget.
+pub(crate) struct Spiller {
+    dir: PathBuf,
+    budget: usize,
+    used: usize,
+    reverse: bool,
+    threads: usize,
+    fan_in: usize,
+    chunk: Vec<Version>,
+    runs: Vec<SpillFile>,
+}
+
+impl Spiller {
+    /// `budget` is in (approximate) bytes. Each run is sorted on `threads`
+    /// threads.
+    pub(crate) fn new(dir: PathBuf, budget: usize, reverse: bool, threads: usize) -> Self {
+        Self {
+            dir,
+            budget,
+            used: 0,
+            reverse,
+            threads,
+            fan_in: FAN_IN,
+            chunk: Vec::new(),
+            runs: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, version: Version) -> Result<(), MergeError> {
+        self.used += size_of::<Version>() + version.pre.len() + version.build.len();
+        self.chunk.push(version);
+        if self.used > self.budget {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Sort the current chunk, ascending (or descending, if `reverse`).
+    fn sort_chunk(&mut self) {
+        parallel::sort(&mut self.chunk, self.threads);
+        if self.reverse {
+            self.chunk.reverse();
+        }
+    }
+
+    fn spill(&mut self) -> Result<(), MergeError> {
+        self.sort_chunk();
+        let run = SpillFile::write(&self.dir, self.chunk.drain(..).map(Ok))?;
+        self.runs.push(run);
+        self.used = 0;
+        Ok(())
+    }
+
+    /// Merge each `fan_in` consecutive runs into one, so ties still go to
+    /// the earlier run.
+    fn merge_pass(&self, runs: Vec<SpillFile>) -> Result<Vec<SpillFile>, MergeError> {
+        runs.chunks(self.fan_in)
+            .map(|group| {
+                let inputs = group
+                    .iter()
+                    .map(SpillFile::open)
+                    .collect::<Result<_, _>>()?;
+                let merged = Merger::new(inputs, self.reverse)?
+                    .map(|entry| entry.map(|entry| entry.version().clone()));
+                SpillFile::write(&self.dir, merged)
+            })
+            .collect()
+    }
+
+    /// Finish collecting versions.
+    pub(crate) fn finish(mut self) -> Result<Spilled, MergeError> {
+        if self.runs.is_empty() {
+            self.sort_chunk();
+            return Ok(Spilled::InMemory(self.chunk));
+        }
+        if !self.chunk.is_empty() {
+            self.spill()?;
+        }
+        let mut runs = std::mem::take(&mut self.runs);
+        while runs.len() > self.fan_in {
+            runs = self.merge_pass(runs)?;
+        }
+        Ok(Spilled::OnDisk(runs))
+    }
+}
+
+/// Parse a memory size, in bytes, with an optional `K`, `M` or `G` suffix
+/// (powers of 1024), like `sort -S`.
+pub(crate) fn memory_size(input: &str) -> Result<usize, String> {
+    let (digits, multiplier) = match input.char_indices().last() {
+        Some((i, 'k' | 'K')) => (&input[..i], 1 << 10),
+        Some((i, 'm' | 'M')) => (&input[..i], 1 << 20),
+        Some((i, 'g' | 'G')) => (&input[..i], 1 << 30),
+        _ => (input, 1),
+    };
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .filter(|n| *n > 0)
+        .ok_or_else(|| format!("expected a size like 64M, found {input:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(input: &[&str]) -> Vec<Version> {
+        input.iter().map(|v| Version::parse(v).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_memory_size() {
+        assert_eq!(memory_size("100"), Ok(100));
+        assert_eq!(memory_size("2K"), Ok(2048));
+        assert_eq!(memory_size("1m"), Ok(1 << 20));
+        assert_eq!(memory_size("1G"), Ok(1 << 30));
+        assert!(memory_size("0").is_err());
+        assert!(memory_size("M").is_err());
+        assert!(memory_size("1T").is_err());
+        assert!(memory_size("-1").is_err());
+    }
+
+    #[test]
+    fn test_spiller() {
+        let input = versions(&[
+            "2.0.0",
+            "0.1.0",
+            "1.0.0+b",
+            "3.0.0",
+            "1.0.0+a",
+            "1.0.0-rc.1",
+        ]);
+
+        for reverse in [false, true] {
+            let mut expected = input.clone();
+            expected.sort();
+            if reverse {
+                expected.reverse();
+            }
+
+            let mut spiller = Spiller::new(std::env::temp_dir(), usize::MAX, reverse, 1);
+            for version in input.clone() {
+                spiller.push(version).unwrap();
+            }
+            match spiller.finish().unwrap() {
+                Spilled::InMemory(versions) => assert_eq!(versions, expected),
+                Spilled::OnDisk(_) => panic!("expected to fit in memory"),
+            }
+
+            // Spill every version, into its own run.
+            let mut spiller = Spiller::new(std::env::temp_dir(), 1, reverse, 2);
+            for version in input.clone() {
+                spiller.push(version).unwrap();
+            }
+            let runs = match spiller.finish().unwrap() {
+                Spilled::OnDisk(runs) => runs,
+                Spilled::InMemory(_) => panic!("expected to spill"),
+            };
+            assert_eq!(runs.len(), input.len());
+            let paths: Vec<PathBuf> = runs.iter().map(|run| run.path.clone()).collect();
+
+            let inputs = runs.iter().map(SpillFile::open).collect::<Result<_, _>>();
+            let merged: Vec<Version> = Merger::new(inputs.unwrap(), reverse)
+                .unwrap()
+                .map(|entry| entry.unwrap().version().clone())
+                .collect();
+            // Ties between runs go to the earlier run, so this is stable.
+            let mut by_precedence = input.clone();
+            by_precedence.sort_by(|a, b| match reverse {
+                false => a.cmp_precedence(b),
+                true => b.cmp_precedence(a),
+            });
+            assert_eq!(merged, by_precedence);
+
+            drop(runs);
+            assert!(paths.iter().all(|path| !path.exists()));
+        }
+    }
+
+    #[test]
+    fn test_spiller_merge_passes() {
+        let input: Vec<Version> = (0..20)
+            .map(|i| Version::new(i % 7, i, 0))
+            .chain(versions(&["1.0.0+b", "1.0.0+a"]))
+            .collect();
+
+        for reverse in [false, true] {
+            // Spill every version into its own run, and merge 3 at a time,
+            // so it takes 2 passes (22, 8, then 3 runs) to merge them all at
+            // once.
+            let mut spiller = Spiller::new(std::env::temp_dir(), 1, reverse, 1);
+            spiller.fan_in = 3;
+            for version in input.clone() {
+                spiller.push(version).unwrap();
+            }
+            let runs = match spiller.finish().unwrap() {
+                Spilled::OnDisk(runs) => runs,
+                Spilled::InMemory(_) => panic!("expected to spill"),
+            };
+            assert_eq!(runs.len(), 3);
+
+            let inputs = runs.iter().map(SpillFile::open).collect::<Result<_, _>>();
+            let merged: Vec<Version> = Merger::new(inputs.unwrap(), reverse)
+                .unwrap()
+                .map(|entry| entry.unwrap().version().clone())
+                .collect();
+            let mut by_precedence = input.clone();
+            by_precedence.sort_by(|a, b| match reverse {
+                false => a.cmp_precedence(b),
+                true => b.cmp_precedence(a),
+            });
+            assert_eq!(merged, by_precedence);
+        }
+    }
+}
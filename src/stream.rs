@@ -0,0 +1,252 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Streaming output, for when versions arrive already in order, and holding
+//! them all in an [crate::results::OrderedVersionMap] isn't an option.
+//!
+//! The output is byte for byte what `sort` would have written, had it held
+//! everything in memory.
+use indexmap::IndexMap;
+use semver::Version;
+use std::io::{self, Write};
+
+use crate::constraint::Filter;
+use crate::misc::OutputFormat;
+use crate::results::{order_group, version_without_build_metadata, SortEntry};
+
+/// Writes groups of versions sharing a precedence key, one at a time.
+pub(crate) struct GroupWriter<W: Write> {
+    out: W,
+    format: OutputFormat,
+    flatten: bool,
+    written: usize,
+}
+
+impl<W: Write> GroupWriter<W> {
+    pub(crate) fn new(out: W, format: OutputFormat, flatten: bool) -> Self {
+        Self {
+            out,
+            format,
+            flatten,
+            written: 0,
+        }
+    }
+
+    /// Write the next group.
+    pub(crate) fn write_group(&mut self, key: &Version, group: &[SortEntry]) -> io::Result<()> {
+        match (&self.format, self.flatten) {
+            (OutputFormat::Text, false) => {
+                writeln!(self.out, "{key}:")?;
+                for entry in group {
+                    writeln!(self.out, "\t- {entry}")?;
+                }
+            }
+            (OutputFormat::Text, true) => {
+                for entry in group {
+                    writeln!(self.out, "{entry}")?;
+                }
+            }
+            (OutputFormat::Yaml, flatten) => {
+                if self.written == 0 {
+                    write!(self.out, "---\nversions:\n")?;
+                }
+                if flatten {
+                    // Sequences under a mapping key aren't indented.
+                    self.out.write_all(to_yaml(&group)?.as_bytes())?;
+                } else {
+                    // Mappings under a mapping key are.
+                    for line in to_yaml(&IndexMap::from([(key, group)]))?.lines() {
+                        writeln!(self.out, "  {line}")?;
+                    }
+                }
+            }
+            (OutputFormat::Json, flatten) => {
+                match (self.written, flatten) {
+                    (0, true) => write!(self.out, "{{\"versions\":[")?,
+                    (0, false) => write!(self.out, "{{\"versions\":{{")?,
+                    _ => write!(self.out, ",")?,
+                }
+                if flatten {
+                    let items: Vec<String> = group.iter().map(to_json).collect::<Result<_, _>>()?;
+                    write!(self.out, "{}", items.join(","))?;
+                } else {
+                    write!(self.out, "{}:{}", to_json(key)?, to_json(&group)?)?;
+                }
+            }
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Finish writing, once all groups have been written.
+    pub(crate) fn finish(mut self, potentially_ambiguous: bool) -> io::Result<()> {
+        match (&self.format, self.flatten, self.written) {
+            (OutputFormat::Text, _, _) => {}
+            (OutputFormat::Yaml, flatten, written) => {
+                if written == 0 {
+                    let empty = if flatten { "[]" } else { "{}" };
+                    write!(self.out, "---\nversions: {empty}\n")?;
+                }
+                writeln!(self.out, "potentially_ambiguous: {potentially_ambiguous}")?;
+            }
+            (OutputFormat::Json, flatten, written) => {
+                match (written, flatten) {
+                    (0, true) => write!(self.out, "{{\"versions\":[")?,
+                    (0, false) => write!(self.out, "{{\"versions\":{{")?,
+                    _ => {}
+                }
+                let close = if flatten { "]" } else { "}" };
+                writeln!(
+                    self.out,
+                    "{close},\"potentially_ambiguous\":{potentially_ambiguous}}}"
+                )?;
+            }
+        }
+        self.out.flush()
+    }
+}
+
+fn to_yaml<T: serde::Serialize>(value: &T) -> io::Result<String> {
+    serde_yaml::to_string(value).map_err(io::Error::other)
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> io::Result<String> {
+    serde_json::to_string(value).map_err(io::Error::other)
+}
+
+/// Group entries (already in precedence order) by their precedence key,
+/// ordering each group the same way as [crate::results::OrderedVersionMap],
+/// and write them out.
+///
+/// Returns if the output was potentially ambiguous.
+pub(crate) fn write_grouped<I, E, W>(
+    entries: I,
+    filter: &Option<Filter>,
+    lexical_sorting: bool,
+    reverse: bool,
+    writer: &mut GroupWriter<W>,
+) -> Result<bool, E>
+where
+    I: Iterator<Item = Result<SortEntry, E>>,
+    E: From<io::Error>,
+    W: Write,
+{
+    let mut potentially_ambiguous = false;
+    let mut key: Option<Version> = None;
+    let mut group: Vec<SortEntry> = Vec::new();
+
+    let mut flush = |key: &Version, group: &mut Vec<SortEntry>| -> io::Result<()> {
+        order_group(group, lexical_sorting, reverse);
+        if group.len() > 1 {
+            potentially_ambiguous = true;
+        }
+        writer.write_group(key, group)?;
+        group.clear();
+        Ok(())
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        if filter
+            .as_ref()
+            .is_some_and(|filter| !filter.matches(entry.version()))
+        {
+            continue;
+        }
+
+        let entry_key = version_without_build_metadata(entry.version());
+        if key.as_ref() != Some(&entry_key) {
+            if let Some(key) = &key {
+                flush(key, &mut group)?;
+            }
+            key = Some(entry_key);
+        }
+        group.push(entry);
+    }
+    if let Some(key) = &key {
+        flush(key, &mut group)?;
+    }
+
+    Ok(potentially_ambiguous)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::{FlatVersionsList, OrderedVersionMap};
+
+    fn stream(versions: &[Version], format: OutputFormat, flatten: bool) -> String {
+        let mut out = Vec::new();
+        let mut writer = GroupWriter::new(&mut out, format, flatten);
+        let entries = versions
+            .iter()
+            .cloned()
+            .map(|v| Ok::<_, io::Error>(SortEntry::from(v)));
+        let potentially_ambiguous =
+            write_grouped(entries, &None, true, false, &mut writer).unwrap();
+        writer.finish(potentially_ambiguous).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    // The streamed output must match what main would print.
+    #[test]
+    fn test_write_grouped_matches_in_memory() {
+        for versions in [
+            vec![],
+            vec!["1.0.0"],
+            vec![
+                "0.1.0",
+                "1.0.0-rc-2+aaa",
+                "1.0.0-rc-2+bbb",
+                "1.0.0",
+                "99.0.0",
+            ],
+        ] {
+            let mut versions: Vec<Version> = versions
+                .iter()
+                .map(|v| Version::parse(v).unwrap())
+                .collect();
+            versions.sort();
+
+            let mut map = OrderedVersionMap::new(&versions, &None, true, false);
+            assert_eq!(
+                stream(&versions, OutputFormat::Text, false),
+                map.to_string()
+            );
+            assert_eq!(
+                stream(&versions, OutputFormat::Yaml, false),
+                format!("---\n{}", serde_yaml::to_string(&map).unwrap())
+            );
+            assert_eq!(
+                stream(&versions, OutputFormat::Json, false),
+                format!("{}\n", serde_json::to_string(&map).unwrap())
+            );
+
+            let flat = FlatVersionsList::from(&mut map);
+            assert_eq!(
+                stream(&versions, OutputFormat::Text, true),
+                flat.to_string()
+            );
+            assert_eq!(
+                stream(&versions, OutputFormat::Yaml, true),
+                format!("---\n{}", serde_yaml::to_string(&flat).unwrap())
+            );
+            assert_eq!(
+                stream(&versions, OutputFormat::Json, true),
+                format!("{}\n", serde_json::to_string(&flat).unwrap())
+            );
+        }
+    }
+}
@@ -0,0 +1,816 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+//!
+//! Translation of requirements between ecosystems.
+//!
+//! Every supported input dialect is first lowered into a list of primitive
+//! [Bound]s (all of which must hold), and then rendered into the target
+//! syntax. Anything that can't survive that trip is reported as a note, and
+//! marks the [Translation] as lossy.
+//!
+//! NOTE(canardleteer): Pre-release handling is where almost every ecosystem
+//!                     disagrees, so most of the notes are about that.
+use std::cmp::Ordering;
+use std::fmt;
+
+use clap::ValueEnum;
+use semver::{Comparator, Op, Prerelease, Version, VersionReq};
+use serde::Serialize;
+
+use crate::constraint::{Filter, PessimisticComparator, PessimisticOp, PessimisticReq};
+
+/// Requirement syntaxes we know how to render.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RangeSyntax {
+    /// The semver crate's `VersionReq` (Cargo).
+    #[default]
+    Cargo,
+    /// node-semver ranges (npm).
+    Npm,
+    /// Maven version ranges (`[1.2.3,2.0.0)`).
+    Maven,
+    /// Python PEP 440 version specifiers.
+    Pep440,
+    /// Terraform / RubyGems constraints.
+    Terraform,
+    /// Go modules minimum version (`go.mod` `require`).
+    Go,
+}
+
+impl fmt::Display for RangeSyntax {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeSyntax::Cargo => write!(f, "cargo"),
+            RangeSyntax::Npm => write!(f, "npm"),
+            RangeSyntax::Maven => write!(f, "maven"),
+            RangeSyntax::Pep440 => write!(f, "pep440"),
+            RangeSyntax::Terraform => write!(f, "terraform"),
+            RangeSyntax::Go => write!(f, "go"),
+        }
+    }
+}
+
+/// The outcome of translating a requirement between dialects.
+pub(crate) struct Translation {
+    pub(crate) translation: Option<String>,
+    pub(crate) lossy: bool,
+    pub(crate) notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BoundOp {
+    Exact,
+    NotEqual,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+impl BoundOp {
+    fn as_str(&self) -> &'static str {
+        match self {
+            BoundOp::Exact => "=",
+            BoundOp::NotEqual => "!=",
+            BoundOp::Greater => ">",
+            BoundOp::GreaterEq => ">=",
+            BoundOp::Less => "<",
+            BoundOp::LessEq => "<=",
+        }
+    }
+
+    fn is_lower(&self) -> bool {
+        matches!(self, BoundOp::Greater | BoundOp::GreaterEq)
+    }
+
+    fn is_upper(&self) -> bool {
+        matches!(self, BoundOp::Less | BoundOp::LessEq)
+    }
+}
+
+/// A primitive comparison against a fully specified version.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Bound {
+    pub(crate) op: BoundOp,
+    pub(crate) version: Version,
+}
+
+impl Bound {
+    fn new(op: BoundOp, version: Version) -> Self {
+        Bound { op, version }
+    }
+
+    fn release(op: BoundOp, major: u64, minor: u64, patch: u64) -> Self {
+        Bound::new(op, Version::new(major, minor, patch))
+    }
+
+    /// Whether `version` is on the right side of this bound, by precedence
+    /// alone (ignoring any pre-release rules).
+    fn allows(&self, version: &Version) -> bool {
+        let ordering = version.cmp_precedence(&self.version);
+        match self.op {
+            BoundOp::Exact => ordering == Ordering::Equal,
+            BoundOp::NotEqual => ordering != Ordering::Equal,
+            BoundOp::Greater => ordering == Ordering::Greater,
+            BoundOp::GreaterEq => ordering != Ordering::Less,
+            BoundOp::Less => ordering == Ordering::Less,
+            BoundOp::LessEq => ordering != Ordering::Greater,
+        }
+    }
+}
+
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.op.as_str(), self.version)
+    }
+}
+
+/// A requirement lowered into [Bound]s, all of which must hold.
+#[derive(Debug, Default)]
+pub(crate) struct Lowered {
+    pub(crate) bounds: Vec<Bound>,
+    lossy: bool,
+    notes: Vec<String>,
+    /// Where Terraform's pre-release matching differs from every other
+    /// syntax, which makes rendering into any of them lossy.
+    terraform_prereleases: Vec<String>,
+}
+
+impl Lowered {
+    fn lossy(&mut self, note: String) {
+        self.lossy = true;
+        self.notes.push(note);
+    }
+
+    fn has_prerelease(&self) -> bool {
+        self.bounds.iter().any(|b| !b.version.pre.is_empty())
+    }
+
+    /// The tightest lower bound, if any.
+    fn lower(&self) -> Option<&Bound> {
+        self.bounds
+            .iter()
+            .filter(|b| b.op.is_lower())
+            .max_by(|a, b| {
+                a.version
+                    .cmp(&b.version)
+                    .then_with(|| (a.op == BoundOp::Greater).cmp(&(b.op == BoundOp::Greater)))
+            })
+    }
+
+    /// The tightest upper bound, if any.
+    pub(crate) fn upper(&self) -> Option<&Bound> {
+        self.bounds
+            .iter()
+            .filter(|b| b.op.is_upper())
+            .min_by(|a, b| {
+                a.version
+                    .cmp(&b.version)
+                    .then_with(|| (a.op == BoundOp::LessEq).cmp(&(b.op == BoundOp::LessEq)))
+            })
+    }
+
+    fn exact(&self) -> Option<&Bound> {
+        self.bounds.iter().find(|b| b.op == BoundOp::Exact)
+    }
+
+    fn not_equal(&self) -> impl Iterator<Item = &Bound> {
+        self.bounds.iter().filter(|b| b.op == BoundOp::NotEqual)
+    }
+}
+
+/// Lower any supported requirement.
+pub(crate) fn lower(filter: &Filter) -> Lowered {
+    match filter {
+        Filter::Cargo(req) => lower_cargo(req),
+        Filter::Terraform(req) => lower_terraform(req),
+    }
+}
+
+fn lower_cargo(req: &VersionReq) -> Lowered {
+    let mut lowered = Lowered::default();
+    for c in req.comparators.iter() {
+        match cargo_comparator_bounds(c) {
+            Some(mut bounds) => lowered.bounds.append(&mut bounds),
+            None => lowered.lossy(format!(
+                "`{c}` uses an operator we don't understand, and was dropped"
+            )),
+        }
+    }
+    lowered
+}
+
+/// Expand a Cargo comparator into primitive bounds, following the cargo book.
+fn cargo_comparator_bounds(c: &Comparator) -> Option<Vec<Bound>> {
+    use BoundOp::*;
+
+    let major = c.major;
+    let full = |minor: u64, patch: u64| {
+        let mut v = Version::new(major, minor, patch);
+        v.pre = c.pre.clone();
+        v
+    };
+
+    let bounds = match (c.op, c.minor, c.patch) {
+        (Op::Exact, Some(minor), Some(patch)) => vec![Bound::new(Exact, full(minor, patch))],
+        (Op::Exact | Op::Wildcard | Op::Tilde, Some(minor), None) => vec![
+            Bound::release(GreaterEq, major, minor, 0),
+            Bound::release(Less, major, minor + 1, 0),
+        ],
+        (Op::Exact | Op::Wildcard | Op::Tilde | Op::Caret, None, _) => vec![
+            Bound::release(GreaterEq, major, 0, 0),
+            Bound::release(Less, major + 1, 0, 0),
+        ],
+        (Op::Greater, Some(minor), Some(patch)) => vec![Bound::new(Greater, full(minor, patch))],
+        (Op::Greater, Some(minor), None) => vec![Bound::release(GreaterEq, major, minor + 1, 0)],
+        (Op::Greater, None, _) => vec![Bound::release(GreaterEq, major + 1, 0, 0)],
+        (Op::GreaterEq, Some(minor), Some(patch)) => {
+            vec![Bound::new(GreaterEq, full(minor, patch))]
+        }
+        (Op::GreaterEq, minor, _) => {
+            vec![Bound::release(GreaterEq, major, minor.unwrap_or(0), 0)]
+        }
+        (Op::Less, Some(minor), Some(patch)) => vec![Bound::new(Less, full(minor, patch))],
+        (Op::Less, minor, _) => vec![Bound::release(Less, major, minor.unwrap_or(0), 0)],
+        (Op::LessEq, Some(minor), Some(patch)) => vec![Bound::new(LessEq, full(minor, patch))],
+        (Op::LessEq, Some(minor), None) => vec![Bound::release(Less, major, minor + 1, 0)],
+        (Op::LessEq, None, _) => vec![Bound::release(Less, major + 1, 0, 0)],
+        (Op::Tilde, Some(minor), Some(patch)) => vec![
+            Bound::new(GreaterEq, full(minor, patch)),
+            Bound::release(Less, major, minor + 1, 0),
+        ],
+        (Op::Caret, Some(minor), patch) => {
+            let lower = match patch {
+                Some(patch) => Bound::new(GreaterEq, full(minor, patch)),
+                None => Bound::release(GreaterEq, major, minor, 0),
+            };
+            let upper = match (major, minor, patch) {
+                (0, 0, Some(patch)) => Bound::release(Less, 0, 0, patch + 1),
+                (0, minor, _) => Bound::release(Less, 0, minor + 1, 0),
+                _ => Bound::release(Less, major + 1, 0, 0),
+            };
+            vec![lower, upper]
+        }
+        _ => return None,
+    };
+    Some(bounds)
+}
+
+fn lower_terraform(req: &PessimisticReq) -> Lowered {
+    let mut lowered = Lowered::default();
+    for c in req.comparators.iter() {
+        let v = c.version.clone();
+        match c.op {
+            PessimisticOp::Exact => lowered.bounds.push(Bound::new(BoundOp::Exact, v)),
+            PessimisticOp::NotEqual => lowered.bounds.push(Bound::new(BoundOp::NotEqual, v)),
+            PessimisticOp::Greater => lowered.bounds.push(Bound::new(BoundOp::Greater, v)),
+            PessimisticOp::GreaterEq => lowered.bounds.push(Bound::new(BoundOp::GreaterEq, v)),
+            PessimisticOp::Less => lowered.bounds.push(Bound::new(BoundOp::Less, v)),
+            PessimisticOp::LessEq => lowered.bounds.push(Bound::new(BoundOp::LessEq, v)),
+            PessimisticOp::Pessimistic => {
+                let upper = c.pessimistic_upper_bound();
+                if c.specified == 2 {
+                    lowered.notes.push(format!(
+                        "`{c}` allows `>={v}, <{upper}`, while Cargo's `~{}.{}` would only allow `>={v}, <{}.{}.0`",
+                        v.major,
+                        v.minor,
+                        v.major,
+                        v.minor + 1,
+                    ));
+                }
+                if !v.pre.is_empty() {
+                    lowered.lossy(format!(
+                        "`{c}` rejects the `{}.{}.{}` release itself, the translation accepts it",
+                        v.major, v.minor, v.patch
+                    ));
+                }
+                lowered.bounds.push(Bound::new(BoundOp::GreaterEq, v));
+                lowered.bounds.push(Bound::new(BoundOp::Less, upper));
+            }
+        }
+
+        if !c.version.pre.is_empty()
+            && matches!(c.op, PessimisticOp::Exact | PessimisticOp::NotEqual)
+        {
+            lowered.terraform_prereleases.push(format!(
+                "`{c}` ignores pre-release matching rules in Terraform, Cargo only matches pre-releases of the same MAJOR.MINOR.PATCH"
+            ));
+        }
+    }
+
+    // Terraform checks pre-releases against each comparator on its own, so
+    // a pre-release only matches when every comparator names one of the
+    // same MAJOR.MINOR.PATCH. Elsewhere, naming it once is enough.
+    let ranges: Vec<&PessimisticComparator> = req
+        .comparators
+        .iter()
+        .filter(|c| !matches!(c.op, PessimisticOp::Exact | PessimisticOp::NotEqual))
+        .collect();
+    for c in ranges.iter().filter(|c| !c.version.pre.is_empty()) {
+        let v = &c.version;
+        if let Some(other) = ranges.iter().find(|other| {
+            other.version.pre.is_empty()
+                || (
+                    other.version.major,
+                    other.version.minor,
+                    other.version.patch,
+                ) != (v.major, v.minor, v.patch)
+        }) {
+            lowered.terraform_prereleases.push(format!(
+                "`{other}` rejects the `{}.{}.{}` pre-releases `{c}` accepts in Terraform, the translation accepts them",
+                v.major, v.minor, v.patch
+            ));
+        }
+    }
+    lowered
+}
+
+/// Render a lowered requirement into the target syntax.
+pub(crate) fn render(mut lowered: Lowered, to: RangeSyntax) -> Translation {
+    if to != RangeSyntax::Terraform {
+        std::mem::take(&mut lowered.terraform_prereleases)
+            .into_iter()
+            .for_each(|note| lowered.lossy(note));
+    }
+    let translation = match to {
+        RangeSyntax::Cargo => render_cargo(&mut lowered),
+        RangeSyntax::Npm => render_npm(&mut lowered),
+        RangeSyntax::Maven => render_maven(&mut lowered),
+        RangeSyntax::Pep440 => render_pep440(&mut lowered),
+        RangeSyntax::Terraform => render_terraform(&mut lowered),
+        RangeSyntax::Go => render_go(&mut lowered),
+    };
+
+    Translation {
+        translation,
+        lossy: lowered.lossy,
+        notes: lowered.notes,
+    }
+}
+
+fn render_cargo(lowered: &mut Lowered) -> Option<String> {
+    let mut terms = Vec::new();
+    let mut dropped = Vec::new();
+    for b in lowered.bounds.iter() {
+        match b.op {
+            BoundOp::NotEqual => dropped.push(b.clone()),
+            _ => terms.push(b.to_string()),
+        }
+    }
+    for b in dropped {
+        lowered.lossy(format!(
+            "`{b}` has no Cargo equivalent (VersionReq has no `!=` or `||`), and was dropped"
+        ));
+    }
+    if terms.is_empty() {
+        // Nothing survived, so everything is allowed.
+        terms.push("*".to_string());
+    }
+    Some(terms.join(", "))
+}
+
+fn render_npm(lowered: &mut Lowered) -> Option<String> {
+    // `!=` becomes a pair of `||` alternatives, which need to be distributed
+    // over the rest of the comparators.
+    let mut sets: Vec<Vec<String>> = vec![Vec::new()];
+    for b in lowered.bounds.iter() {
+        match b.op {
+            BoundOp::NotEqual => {
+                sets = sets
+                    .into_iter()
+                    .flat_map(|set| {
+                        let mut below = set.clone();
+                        below.push(format!("<{}", b.version));
+                        let mut above = set;
+                        above.push(format!(">{}", b.version));
+                        [below, above]
+                    })
+                    .collect();
+            }
+            BoundOp::Exact => sets.iter_mut().for_each(|s| s.push(b.version.to_string())),
+            _ => sets.iter_mut().for_each(|s| s.push(b.to_string())),
+        }
+    }
+
+    Some(
+        sets.into_iter()
+            .map(|set| match set.is_empty() {
+                true => "*".to_string(),
+                false => set.join(" "),
+            })
+            .collect::<Vec<String>>()
+            .join(" || "),
+    )
+}
+
+fn render_maven(lowered: &mut Lowered) -> Option<String> {
+    // Maven only has unions of intervals, so reduce everything down to a
+    // single interval, and then punch holes for `!=`.
+    let intervals = match lowered.exact().cloned() {
+        Some(exact) => {
+            if let Some(b) = lowered
+                .bounds
+                .iter()
+                .find(|b| !b.allows(&exact.version))
+                .cloned()
+            {
+                lowered.lossy(format!(
+                    "`{exact}` does not satisfy `{b}`, so nothing matches, which Maven ranges can't express"
+                ));
+                return None;
+            }
+            if lowered.bounds.len() > 1 {
+                lowered.notes.push(format!(
+                    "only `{exact}` was kept, other comparators are redundant"
+                ));
+            }
+            vec![format!("[{}]", exact.version)]
+        }
+        None => {
+            let lower_bound = lowered.lower().cloned();
+            let upper_bound = lowered.upper().cloned();
+            let mut lower = match &lower_bound {
+                Some(b) if b.op == BoundOp::Greater => format!("({}", b.version),
+                Some(b) => format!("[{}", b.version),
+                None => "[0".to_string(),
+            };
+            let mut upper = match &upper_bound {
+                Some(b) if b.op == BoundOp::Less => format!("{})", b.version),
+                Some(b) => format!("{}]", b.version),
+                None => ")".to_string(),
+            };
+
+            // Intervals must be in order, so punch holes from the lowest up.
+            let mut holes: Vec<Version> = lowered.not_equal().map(|b| b.version.clone()).collect();
+            holes.sort_by(|a, b| a.cmp_precedence(b));
+            holes.dedup_by(|a, b| a.cmp_precedence(b) == Ordering::Equal);
+
+            let mut intervals = Vec::new();
+            for hole in holes {
+                let compare = |bound: &Option<Bound>| {
+                    bound
+                        .as_ref()
+                        .map(|bound| hole.cmp_precedence(&bound.version))
+                };
+                match (compare(&lower_bound), compare(&upper_bound)) {
+                    (Some(Ordering::Less), _) | (_, Some(Ordering::Greater)) => {
+                        lowered.notes.push(format!(
+                            "`!={hole}` is outside of the interval, and was dropped"
+                        ));
+                    }
+                    // Excluding a bound only makes it exclusive.
+                    (Some(Ordering::Equal), _) => lower = format!("({hole}"),
+                    (_, Some(Ordering::Equal)) => upper = format!("{hole})"),
+                    _ => {
+                        intervals.push(format!("{lower},{hole})"));
+                        lower = format!("({hole}");
+                    }
+                }
+            }
+            intervals.push(format!("{lower},{upper}"));
+
+            lowered.lossy(
+                "Maven ranges include qualifier (pre-release) versions inside of the interval, such as `2.0.0-rc.1` in `[1.0.0,2.0.0)`, which Cargo excludes".to_string(),
+            );
+            intervals
+        }
+    };
+
+    if lowered.has_prerelease() {
+        lowered.lossy(
+            "Maven orders qualifiers (`alpha < beta < milestone < rc < snapshot`) differently than Semantic Versioning pre-releases".to_string(),
+        );
+    }
+    Some(intervals.join(","))
+}
+
+/// Map a Semantic Versioning pre-release into a PEP 440 pre-release
+/// (`a`, `b`, `rc`), if there is an obvious mapping.
+///
+/// - `alpha`, `alpha.1`, `alpha1`, `a.1` => `a0`, `a1`, `a1`, `a1`
+/// - `beta.2` => `b2`
+/// - `rc.1`, `c.1`, `pre.1`, `preview.1` => `rc1`
+pub(crate) fn pep440_prerelease(pre: &Prerelease) -> Option<String> {
+    let mut parts = pre.as_str().split('.');
+    let first = parts.next()?.to_ascii_lowercase();
+    let split = first
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(first.len());
+    let (label, number) = first.split_at(split);
+
+    let label = match label {
+        "a" | "alpha" => "a",
+        "b" | "beta" => "b",
+        "c" | "rc" | "pre" | "preview" => "rc",
+        _ => return None,
+    };
+
+    let number = match (number.is_empty(), parts.next()) {
+        (true, Some(n)) if n.chars().all(|c| c.is_ascii_digit()) => n.parse::<u64>().ok()?,
+        (true, None) => 0,
+        (false, None) => number.parse::<u64>().ok()?,
+        _ => return None,
+    };
+
+    match parts.next() {
+        Some(_) => None,
+        None => Some(format!("{label}{number}")),
+    }
+}
+
+fn render_pep440(lowered: &mut Lowered) -> Option<String> {
+    let mut terms = Vec::new();
+    let mut notes = Vec::new();
+    for b in lowered.bounds.iter() {
+        let release = format!(
+            "{}.{}.{}",
+            b.version.major, b.version.minor, b.version.patch
+        );
+        let version = match b.version.pre.is_empty() {
+            true => release,
+            false => match pep440_prerelease(&b.version.pre) {
+                Some(pre) => format!("{release}{pre}"),
+                None => {
+                    notes.push(format!(
+                        "`{b}` has a pre-release with no PEP 440 equivalent, and it was dropped from the version"
+                    ));
+                    release
+                }
+            },
+        };
+        let op = match b.op {
+            BoundOp::Exact => "==",
+            op => op.as_str(),
+        };
+        terms.push(format!("{op}{version}"));
+    }
+    notes.into_iter().for_each(|n| lowered.lossy(n));
+
+    if lowered.has_prerelease() {
+        lowered.lossy(
+            "PEP 440 accepts pre-releases of any version once a specifier names a pre-release, Cargo only accepts those of the same MAJOR.MINOR.PATCH".to_string(),
+        );
+    }
+    if terms.is_empty() {
+        // PEP 440 has no "match everything" specifier.
+        terms.push(">=0".to_string());
+    }
+    Some(terms.join(","))
+}
+
+fn render_terraform(lowered: &mut Lowered) -> Option<String> {
+    let mut terms: Vec<String> = lowered
+        .bounds
+        .iter()
+        .map(|b| format!("{} {}", b.op.as_str(), b.version))
+        .collect();
+
+    if lowered.has_prerelease() {
+        lowered.lossy(
+            "Terraform only matches pre-releases against each comparator with a pre-release on the same MAJOR.MINOR.PATCH, Cargo only needs one".to_string(),
+        );
+    }
+    if terms.is_empty() {
+        terms.push(">= 0.0.0".to_string());
+    }
+    Some(terms.join(", "))
+}
+
+fn render_go(lowered: &mut Lowered) -> Option<String> {
+    // Go modules only have a minimum version, and Minimal Version Selection.
+    let minimum = match (lowered.exact(), lowered.lower()) {
+        (Some(exact), _) => {
+            let note = format!(
+                "`{exact}` is only a minimum in Go, Minimal Version Selection may choose a newer version"
+            );
+            let version = exact.version.clone();
+            lowered.lossy(note);
+            version
+        }
+        (None, Some(lower)) if lower.op == BoundOp::Greater => {
+            let note = format!("`{lower}` is exclusive, Go minimums are inclusive");
+            let version = lower.version.clone();
+            lowered.lossy(note);
+            version
+        }
+        (None, Some(lower)) => lower.version.clone(),
+        (None, None) => Version::new(0, 0, 0),
+    };
+
+    if lowered.exact().is_none() {
+        if let Some(upper) = lowered.upper().cloned() {
+            if minimum.major >= 1
+                && upper.op == BoundOp::Less
+                && upper.version == Version::new(minimum.major + 1, 0, 0)
+            {
+                lowered.notes.push(format!(
+                    "`{upper}` is implied by the module path major version suffix"
+                ));
+            } else {
+                lowered.lossy(format!(
+                    "`{upper}` has no Go equivalent, upper bounds can't be expressed in `go.mod`"
+                ));
+            }
+        }
+    }
+
+    let excluded: Vec<Bound> = lowered.not_equal().cloned().collect();
+    for b in excluded {
+        lowered.lossy(format!(
+            "`{b}` has no `require` equivalent, consider an `exclude v{}` directive",
+            b.version
+        ));
+    }
+
+    Some(format!("v{minimum}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraint::Dialect;
+
+    fn translate(dialect: Dialect, req: &str, to: RangeSyntax) -> Translation {
+        render(lower(&Filter::parse(dialect, req).unwrap()), to)
+    }
+
+    #[test]
+    fn test_lower_cargo() {
+        let cases = [
+            ("=1.2.3", vec!["=1.2.3"]),
+            ("=1.2", vec![">=1.2.0", "<1.3.0"]),
+            ("1.2.3", vec![">=1.2.3", "<2.0.0"]),
+            ("^0.2.3", vec![">=0.2.3", "<0.3.0"]),
+            ("^0.0.3", vec![">=0.0.3", "<0.0.4"]),
+            ("^0.0", vec![">=0.0.0", "<0.1.0"]),
+            ("^0", vec![">=0.0.0", "<1.0.0"]),
+            ("~1.2.3-rc.1", vec![">=1.2.3-rc.1", "<1.3.0"]),
+            ("~1", vec![">=1.0.0", "<2.0.0"]),
+            (">1.2", vec![">=1.3.0"]),
+            ("<=1.2", vec!["<1.3.0"]),
+            ("1.*", vec![">=1.0.0", "<2.0.0"]),
+            ("*", vec![]),
+        ];
+
+        for (req, expected) in cases {
+            let lowered = lower(&Filter::parse(Dialect::Cargo, req).unwrap());
+            let bounds: Vec<String> = lowered.bounds.iter().map(|b| b.to_string()).collect();
+            assert_eq!(bounds, expected, "{req}");
+        }
+    }
+
+    #[test]
+    fn test_terraform_to_cargo() {
+        let t = translate(Dialect::Terraform, "~> 1.2", RangeSyntax::Cargo);
+        assert_eq!(t.translation.as_deref(), Some(">=1.2.0, <2.0.0"));
+        assert!(!t.lossy);
+        assert!(!t.notes.is_empty());
+
+        let t = translate(Dialect::Terraform, "~> 1.2.3, = 1.2", RangeSyntax::Cargo);
+        assert_eq!(t.translation.as_deref(), Some(">=1.2.3, <1.3.0, =1.2.0"));
+
+        let t = translate(Dialect::Terraform, ">= 1.0, != 1.4.1", RangeSyntax::Cargo);
+        assert_eq!(t.translation.as_deref(), Some(">=1.0.0"));
+        assert!(t.lossy);
+
+        // Terraform checks pre-releases against each comparator, so `< 2`
+        // rejects every pre-release.
+        for to in [RangeSyntax::Cargo, RangeSyntax::Npm] {
+            let t = translate(Dialect::Terraform, ">= 1.2.3-beta, < 2", to);
+            assert!(t.lossy, "{to}");
+        }
+        let t = translate(
+            Dialect::Terraform,
+            ">= 1.2.3-beta, < 1.2.3-rc.2",
+            RangeSyntax::Cargo,
+        );
+        assert!(!t.lossy);
+        let t = translate(Dialect::Terraform, "!= 1.2.3-beta", RangeSyntax::Npm);
+        assert!(t.lossy);
+
+        // All translations should be accepted by the semver crate.
+        for req in ["~> 0.1", "> 1, < 3.2.1-rc.1", "!= 1.0.0", "~> 1.2.3-beta"] {
+            let t = translate(Dialect::Terraform, req, RangeSyntax::Cargo);
+            assert!(VersionReq::parse(&t.translation.unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_cargo_to_others() {
+        let t = translate(Dialect::Cargo, "^1.2.3", RangeSyntax::Npm);
+        assert_eq!(t.translation.as_deref(), Some(">=1.2.3 <2.0.0"));
+        assert!(!t.lossy);
+
+        let t = translate(Dialect::Cargo, "^1.2.3", RangeSyntax::Maven);
+        assert_eq!(t.translation.as_deref(), Some("[1.2.3,2.0.0)"));
+        assert!(t.lossy);
+
+        let t = translate(Dialect::Cargo, "=1.2.3", RangeSyntax::Maven);
+        assert_eq!(t.translation.as_deref(), Some("[1.2.3]"));
+
+        // Other comparators are only dropped when the exact version already
+        // satisfies them.
+        let t = translate(Dialect::Cargo, "=1.2.3, >1.0", RangeSyntax::Maven);
+        assert_eq!(t.translation.as_deref(), Some("[1.2.3]"));
+        assert!(!t.lossy);
+        for req in ["=1.2.3, >2", "=1.2.3, =1.2.4", "=1.2.3, <1.2.3"] {
+            let t = translate(Dialect::Cargo, req, RangeSyntax::Maven);
+            assert_eq!(t.translation, None, "{req}");
+            assert!(t.lossy, "{req}");
+        }
+
+        let t = translate(Dialect::Cargo, ">1.0.0", RangeSyntax::Maven);
+        assert_eq!(t.translation.as_deref(), Some("(1.0.0,)"));
+
+        let t = translate(Dialect::Cargo, "^1.2.3", RangeSyntax::Pep440);
+        assert_eq!(t.translation.as_deref(), Some(">=1.2.3,<2.0.0"));
+        assert!(!t.lossy);
+
+        let t = translate(Dialect::Cargo, "~1.2.3-rc.1", RangeSyntax::Pep440);
+        assert_eq!(t.translation.as_deref(), Some(">=1.2.3rc1,<1.3.0"));
+        assert!(t.lossy);
+
+        let t = translate(Dialect::Cargo, "^1.2.3", RangeSyntax::Terraform);
+        assert_eq!(t.translation.as_deref(), Some(">= 1.2.3, < 2.0.0"));
+        assert!(!t.lossy);
+
+        let t = translate(Dialect::Cargo, "^1.2.3", RangeSyntax::Go);
+        assert_eq!(t.translation.as_deref(), Some("v1.2.3"));
+        assert!(!t.lossy);
+
+        let t = translate(Dialect::Cargo, "~1.2.3", RangeSyntax::Go);
+        assert_eq!(t.translation.as_deref(), Some("v1.2.3"));
+        assert!(t.lossy);
+
+        let t = translate(Dialect::Cargo, "*", RangeSyntax::Npm);
+        assert_eq!(t.translation.as_deref(), Some("*"));
+    }
+
+    #[test]
+    fn test_terraform_not_equal_to_others() {
+        let req = ">= 1.0, != 1.4.1, < 2";
+
+        let t = translate(Dialect::Terraform, req, RangeSyntax::Npm);
+        assert_eq!(
+            t.translation.as_deref(),
+            Some(">=1.0.0 <1.4.1 <2.0.0 || >=1.0.0 >1.4.1 <2.0.0")
+        );
+        assert!(!t.lossy);
+
+        let t = translate(Dialect::Terraform, req, RangeSyntax::Maven);
+        assert_eq!(
+            t.translation.as_deref(),
+            Some("[1.0.0,1.4.1),(1.4.1,2.0.0)")
+        );
+
+        // Holes are punched in order, and only inside of the interval.
+        for (req, maven) in [
+            (
+                ">= 1.0, != 1.5.0, != 1.2.0, < 2",
+                "[1.0.0,1.2.0),(1.2.0,1.5.0),(1.5.0,2.0.0)",
+            ),
+            (">= 1.0, != 3.0.0, < 2", "[1.0.0,2.0.0)"),
+            (">= 1.0, != 1.0.0, != 0.5.0, <= 2", "(1.0.0,2.0.0]"),
+            (
+                "> 1.0, != 1.5.0, != 1.5.0, != 2.0.0, <= 2",
+                "(1.0.0,1.5.0),(1.5.0,2.0.0)",
+            ),
+        ] {
+            let t = translate(Dialect::Terraform, req, RangeSyntax::Maven);
+            assert_eq!(t.translation.as_deref(), Some(maven), "{req}");
+        }
+
+        let t = translate(Dialect::Terraform, req, RangeSyntax::Pep440);
+        assert_eq!(t.translation.as_deref(), Some(">=1.0.0,!=1.4.1,<2.0.0"));
+        assert!(!t.lossy);
+
+        let t = translate(Dialect::Terraform, req, RangeSyntax::Go);
+        assert!(t.lossy);
+    }
+
+    #[test]
+    fn test_pep440_prerelease() {
+        let pre = |s: &str| pep440_prerelease(&Prerelease::new(s).unwrap());
+        assert_eq!(pre("alpha").as_deref(), Some("a0"));
+        assert_eq!(pre("alpha.1").as_deref(), Some("a1"));
+        assert_eq!(pre("beta2").as_deref(), Some("b2"));
+        assert_eq!(pre("rc.1").as_deref(), Some("rc1"));
+        assert_eq!(pre("RC.1").as_deref(), Some("rc1"));
+        assert_eq!(pre("rc.1.2"), None);
+        assert_eq!(pre("nightly"), None);
+        assert_eq!(pre("rc.x"), None);
+    }
+}
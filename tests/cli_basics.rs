@@ -37,10 +37,10 @@ fn cli_all_sub_commands() {
         // All subcommands with no input, should fail, except those that do
         // something else reasonable.
         //
-        // 'sort' & 'generate' have behaviors that reasonably allow them to
-        // pass.
+        // 'sort', 'extract', 'key', 'decode-key' & 'generate' have behaviors
+        // that reasonably allow them to pass.
         match sub {
-            "sort" | "generate" => {
+            "sort" | "extract" | "key" | "decode-key" | "generate" => {
                 Command::cargo_bin(env!("CARGO_PKG_NAME"))
                     .unwrap()
                     .arg(sub)
@@ -67,3 +67,45 @@ fn cli_all_sub_commands() {
             .success();
     }
 }
+
+#[test]
+fn cli_global_scheme() {
+    // `--scheme` is accepted before, or after the subcommand.
+    for args in [
+        ["--scheme", "debian", COMMAND_COMPARE, "1.0", "1.00"],
+        [COMMAND_COMPARE, "--scheme", "debian", "1.0", "1.00"],
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg("-o").arg("text").args(args).assert();
+        assert
+            .append_context(COMMAND_COMPARE, "--scheme debian")
+            .success()
+            .stdout("Semantically: Equal\nLexically: Less\n");
+    }
+
+    // Only some subcommands support other schemes.
+    for sub in ALL_COMMANDS {
+        let supported = [
+            COMMAND_COMPARE,
+            COMMAND_EXPLAIN,
+            COMMAND_FILTER_TEST,
+            COMMAND_SORT,
+            COMMAND_VALIDATE,
+        ];
+        if supported.contains(&sub) {
+            continue;
+        }
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("--scheme")
+            .arg("rpm")
+            .arg(sub)
+            .arg("--help")
+            .assert();
+        assert.append_context(sub, "help with --scheme").success();
+
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg("--scheme").arg("rpm").arg(sub).assert();
+        assert.append_context(sub, "--scheme rpm").failure();
+    }
+}
@@ -0,0 +1,210 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+#[test]
+fn cli_calver_invalid_input() {
+    for (args, context) in [
+        (
+            vec![COMMAND_VALIDATE, "--format", "YYYY.YY", "2024.24"],
+            "two years",
+        ),
+        (
+            vec![COMMAND_VALIDATE, "--format", "YYYY.WW.DD", "2024.1.1"],
+            "week and day",
+        ),
+        (
+            vec![COMMAND_EXPLAIN, "--format", "YYYY.0M", "2024.1"],
+            "not zero-padded",
+        ),
+        (
+            vec![COMMAND_SORT, "--format", "YYYY.MM", "2024.1", "2024.01"],
+            "zero-padded",
+        ),
+        (vec![COMMAND_SORT, "2024.1", "v2024.2"], "not numbers"),
+        (
+            vec![COMMAND_FILTER_TEST, ">=2024.1", "2024.2"],
+            "no requirements",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg("--scheme").arg("calver").args(args).assert();
+        assert.append_context(COMMAND_CALVER, context).failure();
+    }
+
+    for (args, context) in [
+        (vec![COMMAND_CALVER, "next"], "missing --format"),
+        (
+            vec![
+                COMMAND_CALVER,
+                "next",
+                "--format",
+                "YYYY.0M",
+                "-d",
+                "2024-2-1",
+            ],
+            "bad date",
+        ),
+        (
+            vec![
+                COMMAND_CALVER,
+                "next",
+                "--format",
+                "YYYY.0M",
+                "-d",
+                "2024-02-01",
+                "2024.03",
+            ],
+            "later",
+        ),
+        (
+            vec![
+                COMMAND_CALVER,
+                "next",
+                "--format",
+                "YYYY.0M",
+                "-d",
+                "2024-02-01",
+                "2024.02",
+            ],
+            "same date",
+        ),
+        (
+            vec![COMMAND_VALIDATE, "--format", "YYYY.0M", "1.0.0"],
+            "--format without --scheme calver",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.args(args).assert();
+        assert.append_context(COMMAND_CALVER, context).code(2);
+    }
+}
+
+#[test]
+fn cli_calver_validate() {
+    for (format, version, valid) in [
+        ("YYYY.MM.DD", "2024.2.29", true),
+        ("YYYY.MM.DD", "2023.2.29", false),
+        ("YYYY.0M.0D", "2024.02.09", true),
+        ("YYYY.0M.0D", "2024.2.9", false),
+        ("YY.0M.MICRO", "24.04.1", true),
+        ("YY.0M.MICRO", "24.04.01", false),
+        ("YYYY.WW", "2024.53", true),
+        ("YYYY.WW", "2024.54", false),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("--scheme")
+            .arg("calver")
+            .arg("--format")
+            .arg(format)
+            .arg(COMMAND_VALIDATE)
+            .arg(version)
+            .assert();
+        let assert = assert.append_context(COMMAND_CALVER, version);
+        match valid {
+            true => assert.success(),
+            false => assert.failure(),
+        };
+    }
+}
+
+#[test]
+fn cli_calver_explain() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg("--scheme")
+        .arg("calver")
+        .arg("--format")
+        .arg("0Y.0W")
+        .arg(COMMAND_EXPLAIN)
+        .arg("06.09")
+        .assert();
+    assert
+        .append_context(COMMAND_CALVER, "0Y.0W")
+        .success()
+        .stdout(
+            "Format: 0Y.0W\n\
+             Year: 2006\n\
+             Week: 9\n\
+             Segments:\n\
+             - 06 (ZeroPaddedYear)\n\
+             - 09 (ZeroPaddedWeek)\n",
+        );
+}
+
+#[test]
+fn cli_calver_sort() {
+    for (format, versions, sorted) in [
+        (
+            Some("YY.MM.MICRO"),
+            vec!["24.10.0", "24.2.10", "106.1.0", "24.2.9"],
+            "24.2.9\n24.2.10\n24.10.0\n106.1.0\n",
+        ),
+        (
+            None,
+            vec!["2024.10", "2024.2.1", "2023-12-31", "2024.2"],
+            "2023-12-31\n2024.2\n2024.2.1\n2024.10\n",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-o")
+            .arg("text")
+            .arg("--scheme")
+            .arg("calver")
+            .args(format.iter().flat_map(|format| ["--format", format]))
+            .arg(COMMAND_SORT)
+            .arg("--flatten")
+            .args(versions)
+            .assert();
+        assert
+            .append_context(COMMAND_CALVER, format.unwrap_or("no format"))
+            .success()
+            .stdout(sorted);
+    }
+}
+
+#[test]
+fn cli_calver_next() {
+    for (format, date, current, next) in [
+        ("YYYY.0M.0D", "2024-02-09", None, "2024.02.09\n"),
+        ("YY.0M.MICRO", "2024-02-09", Some("24.01.3"), "24.02.0\n"),
+        ("YY.0M.MICRO", "2024-02-09", Some("24.02.3"), "24.02.4\n"),
+        ("YYYY.0W", "2024-12-31", None, "2024.53\n"),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-o")
+            .arg("text")
+            .arg(COMMAND_CALVER)
+            .arg("next")
+            .arg("--format")
+            .arg(format)
+            .arg("--date")
+            .arg(date)
+            .args(current)
+            .assert();
+        assert
+            .append_context(COMMAND_CALVER, format)
+            .success()
+            .stdout(next);
+    }
+}
@@ -30,6 +30,12 @@ fn cli_compare_invalid_input() {
     assert
         .append_context(COMMAND_COMPARE, "2 bad semver args")
         .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg(COMMAND_COMPARE).arg("x").arg("1.0.0").assert();
+    assert
+        .append_context(COMMAND_COMPARE, "1 bad semver arg, of 2")
+        .code(2);
 }
 
 /// NOTE(canardleteer): Since these codes are considered unstable for now,
@@ -150,3 +156,222 @@ fn cli_compare_basic_cases() {
         )
         .success();
 }
+
+#[test]
+fn cli_compare_debian() {
+    // Should be (sem: Equal, lex: Less) aka 110
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("-e")
+        .arg("1.0")
+        .arg("1.0-0")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme debian")
+        .code(110)
+        .stdout("---\nsemantic_ordering: Equal\nlexical_ordering: Less\n");
+
+    // Should be (sem: Less, lex: Less) aka 100
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("-e")
+        .arg("1.0~rc1")
+        .arg("1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme debian tilde")
+        .code(100);
+
+    // Should be (sem: Greater, lex: Greater) aka 122
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("-e")
+        .arg("1:0.1")
+        .arg("2.0")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme debian epoch")
+        .code(122);
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("1.0")
+        .arg("x:1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme debian invalid version")
+        .code(2);
+}
+
+/// Every case of the bundled rpm comparison vector, through the CLI.
+#[test]
+fn cli_compare_pep440() {
+    // Should be (sem: Equal, lex: Less) aka 110
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("pep440")
+        .arg("-e")
+        .arg("1.0")
+        .arg("1.0.0")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme pep440")
+        .code(110)
+        .stdout("---\nsemantic_ordering: Equal\nlexical_ordering: Less\n");
+
+    // Should be (sem: Less, lex: Less) aka 100
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("pep440")
+        .arg("-e")
+        .arg("1.0.dev1")
+        .arg("1.0a1")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme pep440 dev release")
+        .code(100);
+}
+
+#[test]
+fn cli_compare_go() {
+    // Build metadata is ignored, like `semver.Compare`.
+    // Should be (sem: Equal, lex: Less) aka 110
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("go")
+        .arg("-e")
+        .arg("v2")
+        .arg("v2.0.0+incompatible")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme go")
+        .code(110);
+
+    // Should be (sem: Less, lex: Less) aka 100
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_COMPARE)
+        .arg("--scheme")
+        .arg("go")
+        .arg("-e")
+        .arg("v1.2.4-0.20191109021931-daa7c04131f5")
+        .arg("v1.2.4")
+        .assert();
+    assert
+        .append_context(COMMAND_COMPARE, "--scheme go pseudo-version")
+        .code(100);
+}
+
+#[test]
+fn cli_compare_k8s_api() {
+    for (a, b, code) in [
+        // GA beats beta, even of a greater major version.
+        ("v1", "v2beta1", 122),
+        ("v1beta2", "v1beta10", 100),
+        ("v1alpha1", "v1beta1", 100),
+        // Non-matching strings are less, and lexically earlier ones greater.
+        ("foo", "v1alpha1", 100),
+        ("foo1", "foo10", 122),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_COMPARE)
+            .arg("--scheme")
+            .arg("k8s-api")
+            .arg("-e")
+            .arg(a)
+            .arg(b)
+            .assert();
+        assert
+            .append_context(COMMAND_COMPARE, format!("--scheme k8s-api {a} {b}"))
+            .code(code);
+    }
+}
+
+#[test]
+fn cli_compare_rpm_cases() {
+    let cases = std::fs::read_to_string("example-data/rpmvercmp-cases.txt").unwrap();
+    for line in cases.lines() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        let case: Vec<&str> = line.split_whitespace().collect();
+        let [a, b, expected] = case[..] else {
+            panic!("malformed case: {line}");
+        };
+        let semantic: i32 = expected.parse().unwrap();
+        // Ties are broken lexically.
+        let lexical = match semantic {
+            0 => a.cmp(b) as i32,
+            _ => semantic,
+        };
+
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_COMPARE)
+            .arg("--scheme")
+            .arg("rpm")
+            .arg("-e")
+            .arg(a)
+            .arg(b)
+            .assert();
+        let assert = assert.append_context(COMMAND_COMPARE, line.to_string());
+        match (semantic, lexical) {
+            (0, 0) => assert.success(),
+            _ => assert.code(100 + 10 * (semantic + 1) + (lexical + 1)),
+        };
+    }
+}
+
+#[test]
+fn cli_compare_maven_cases() {
+    let cases = std::fs::read_to_string("example-data/maven-comparableversion-cases.txt").unwrap();
+    for line in cases.lines() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        let case: Vec<&str> = line.split_whitespace().collect();
+        let [a, b, expected] = case[..] else {
+            panic!("malformed case: {line}");
+        };
+        let semantic: i32 = expected.parse().unwrap();
+        // Ties are broken lexically.
+        let lexical = match semantic {
+            0 => a.cmp(b) as i32,
+            _ => semantic,
+        };
+
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_COMPARE)
+            .arg("--scheme")
+            .arg("maven")
+            .arg("-e")
+            .arg(a)
+            .arg(b)
+            .assert();
+        let assert = assert.append_context(COMMAND_COMPARE, line.to_string());
+        match (semantic, lexical) {
+            (0, 0) => assert.success(),
+            _ => assert.code(100 + 10 * (semantic + 1) + (lexical + 1)),
+        };
+    }
+}
@@ -0,0 +1,135 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+#[test]
+fn cli_convert_invalid_input() {
+    for (args, context) in [
+        (vec!["1.2.3"], "missing --to"),
+        (vec!["--to", "conda", "1.2.3"], "unknown target"),
+        (vec!["--to", "pep440", "1.2"], "not a semantic version"),
+        (
+            vec!["--from", "debian", "--to", "rpm", "1.2.3"],
+            "not from semver",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg(COMMAND_CONVERT).args(args).assert();
+        assert.append_context(COMMAND_CONVERT, context).failure();
+    }
+}
+
+#[test]
+fn cli_convert_basic_cases() {
+    for (to, converted) in [
+        ("pep440", "1.2.3rc1\n1.2.3\n"),
+        ("debian", "1.2.3~rc1\n1.2.3\n"),
+        ("rpm", "1.2.3-0.rc1\n1.2.3-1\n"),
+        ("maven", "1.2.3-rc1\n1.2.3\n"),
+        ("nuget", "1.2.3-rc.1\n1.2.3\n"),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-o")
+            .arg("text")
+            .arg(COMMAND_CONVERT)
+            .arg("--to")
+            .arg(to)
+            .arg("1.2.3-rc.1")
+            .arg("1.2.3")
+            .assert();
+        assert
+            .append_context(COMMAND_CONVERT, to)
+            .success()
+            .stdout(converted);
+    }
+}
+
+#[test]
+fn cli_convert_lossy() {
+    // Build metadata only survives into NuGet.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_CONVERT)
+        .arg("--to")
+        .arg("debian")
+        .arg("1.2.3+build.5")
+        .assert();
+    assert
+        .append_context(COMMAND_CONVERT, "build metadata")
+        .failure()
+        .stdout(
+            "1.2.3\n\
+             # 1.2.3+build.5: build metadata `build.5` has no debian equivalent, and was dropped\n",
+        );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_CONVERT)
+        .arg("--to")
+        .arg("nuget")
+        .arg("1.2.3+build.5")
+        .assert();
+    assert
+        .append_context(COMMAND_CONVERT, "build metadata")
+        .success()
+        .stdout("1.2.3+build.5\n");
+}
+
+#[test]
+fn cli_convert_order_not_preserved() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_CONVERT)
+        .arg("--to")
+        .arg("rpm")
+        .arg("1.0.0-alpha")
+        .arg("1.0.0-1")
+        .assert();
+    assert
+        .append_context(COMMAND_CONVERT, "numeric pre-release")
+        .failure()
+        .stdout(
+            "1.0.0-0.alpha\n\
+             1.0.0-0.1\n\
+             # order not preserved: `1.0.0-1` < `1.0.0-alpha`, but `1.0.0-0.1` > `1.0.0-0.alpha` in rpm\n",
+        );
+}
+
+#[test]
+fn cli_convert_from_stdin() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_CONVERT)
+        .arg("--to")
+        .arg("pep440")
+        .write_stdin("1.0.0-alpha.1\n1.0.0-beta.2\n")
+        .assert();
+    assert
+        .append_context(COMMAND_CONVERT, "stdin")
+        .success()
+        .stdout("1.0.0a1\n1.0.0b2\n");
+}
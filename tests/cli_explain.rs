@@ -35,3 +35,104 @@ fn cli_explain_basic_cases() {
         .assert();
     assert.append_context(COMMAND_EXPLAIN, "help").success();
 }
+
+#[test]
+fn cli_explain_debian() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_EXPLAIN)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("2:1.0~rc1-3")
+        .assert();
+    assert
+        .append_context(COMMAND_EXPLAIN, "--scheme debian")
+        .success()
+        .stdout("{\"epoch\":2,\"upstream_version_string\":\"1.0~rc1\",\"upstream-version\":[{\"kind\":\"Numeric\",\"value\":\"1\"},{\"kind\":\"Ascii\",\"value\":\".\"},{\"kind\":\"Numeric\",\"value\":\"0\"},{\"kind\":\"Ascii\",\"value\":\"~rc\"},{\"kind\":\"Numeric\",\"value\":\"1\"}],\"revision_string\":\"3\",\"revision\":[{\"kind\":\"Numeric\",\"value\":\"3\"}],\"native\":false}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_EXPLAIN)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("1.0.0:rc1")
+        .assert();
+    assert
+        .append_context(COMMAND_EXPLAIN, "--scheme debian invalid epoch")
+        .failure();
+}
+
+#[test]
+fn cli_explain_rpm() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_EXPLAIN)
+        .arg("--scheme")
+        .arg("rpm")
+        .arg("1.0^git2-3.el9")
+        .assert();
+    assert
+        .append_context(COMMAND_EXPLAIN, "--scheme rpm")
+        .success()
+        .stdout("{\"epoch\":0,\"version_string\":\"1.0^git2\",\"version\":[{\"kind\":\"Numeric\",\"value\":\"1\"},{\"kind\":\"Numeric\",\"value\":\"0\"},{\"kind\":\"Caret\",\"value\":\"^\"},{\"kind\":\"Alpha\",\"value\":\"git\"},{\"kind\":\"Numeric\",\"value\":\"2\"}],\"release_string\":\"3.el9\",\"release\":[{\"kind\":\"Numeric\",\"value\":\"3\"},{\"kind\":\"Alpha\",\"value\":\"el\"},{\"kind\":\"Numeric\",\"value\":\"9\"}]}\n");
+}
+
+#[test]
+fn cli_explain_pep440() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_EXPLAIN)
+        .arg("--scheme")
+        .arg("pep440")
+        .arg("1!1.2.3-RC.1.post2.dev3+ubuntu-1")
+        .assert();
+    assert
+        .append_context(COMMAND_EXPLAIN, "--scheme pep440")
+        .success()
+        .stdout("{\"normalized\":\"1!1.2.3rc1.post2.dev3+ubuntu.1\",\"epoch\":1,\"release\":[{\"kind\":\"Major\",\"value\":1},{\"kind\":\"Minor\",\"value\":2},{\"kind\":\"Micro\",\"value\":3}],\"prerelease\":{\"kind\":\"ReleaseCandidate\",\"number\":1},\"post_release\":2,\"dev_release\":3,\"local_string\":\"ubuntu.1\",\"local\":[{\"kind\":\"Ascii\",\"value\":\"ubuntu\"},{\"kind\":\"Numeric\",\"value\":\"1\"}],\"is_prerelease\":true}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_EXPLAIN)
+        .arg("--scheme")
+        .arg("pep440")
+        .arg("1.2.3+")
+        .assert();
+    assert
+        .append_context(COMMAND_EXPLAIN, "--scheme pep440 empty local")
+        .failure();
+}
+
+#[test]
+fn cli_explain_go() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_EXPLAIN)
+        .arg("--scheme")
+        .arg("go")
+        .arg("v2.0.1-0.20191109021931-daa7c04131f5+incompatible")
+        .assert();
+    assert
+        .append_context(COMMAND_EXPLAIN, "--scheme go")
+        .success()
+        .stdout("{\"canonical\":\"v2.0.1-0.20191109021931-daa7c04131f5+incompatible\",\"major\":2,\"minor\":0,\"patch\":1,\"prerelease_string\":\"0.20191109021931-daa7c04131f5\",\"prerelease\":[{\"kind\":\"Numeric\",\"value\":\"0\"},{\"kind\":\"Ascii\",\"value\":\"20191109021931-daa7c04131f5\"}],\"build_metadata_string\":\"incompatible\",\"build-metadata\":[{\"kind\":\"Ascii\",\"value\":\"incompatible\"}],\"incompatible\":true,\"major_version_suffix\":\"\",\"pseudo_version\":{\"base_version\":\"v2.0.0+incompatible\",\"timestamp\":\"2019-11-09T02:19:31Z\",\"revision\":\"daa7c04131f5\"}}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_EXPLAIN)
+        .arg("--scheme")
+        .arg("go")
+        .arg("1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_EXPLAIN, "--scheme go without v")
+        .failure();
+}
@@ -0,0 +1,59 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+#[test]
+fn cli_extract_invalid_input() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_EXTRACT)
+        .arg("example-data/does-not-exist.txt")
+        .assert();
+    assert
+        .append_context(COMMAND_EXTRACT, "missing file")
+        .failure();
+}
+
+#[test]
+fn cli_extract_basic_cases() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_EXTRACT)
+        .write_stdin("FROM rust:1.85.0-slim\nv2.0.0 and 1.2.3.4\n")
+        .assert();
+    assert
+        .append_context(COMMAND_EXTRACT, "2 lines from stdin")
+        .success()
+        .stdout("-:1:11: 1.85.0-slim\n-:2:1: 2.0.0\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_EXTRACT)
+        .arg("--flatten")
+        .arg("--small")
+        .write_stdin("1.2.3 99999999999999999999.0.0 (v1.0.0-rc.1)\n")
+        .assert();
+    assert
+        .append_context(COMMAND_EXTRACT, "--flatten --small")
+        .success()
+        .stdout("1.2.3\n1.0.0-rc.1\n");
+}
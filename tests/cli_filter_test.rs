@@ -58,3 +58,137 @@ fn cli_filter_test_basic_cases() {
 
     // NOTE(canardleteer): I should probably add some more complex filters.
 }
+
+#[test]
+fn cli_filter_test_terraform_dialect() {
+    // `~>` is only valid in the terraform dialect.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_FILTER_TEST)
+        .arg("~> 1.2")
+        .arg("1.9.0")
+        .assert();
+    assert
+        .append_context(COMMAND_FILTER_TEST, "~> in the cargo dialect")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_FILTER_TEST)
+        .arg("--dialect")
+        .arg("terraform")
+        .arg("~> 1.2")
+        .arg("1.9.0")
+        .assert();
+    assert
+        .append_context(COMMAND_FILTER_TEST, "~> 1.2 1.9.0")
+        .success();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_FILTER_TEST)
+        .arg("-d")
+        .arg("terraform")
+        .arg(">= 1.0, != 1.4.1")
+        .arg("1.4.1")
+        .assert();
+    assert
+        .append_context(COMMAND_FILTER_TEST, ">= 1.0, != 1.4.1 1.4.1")
+        .failure();
+}
+
+#[test]
+fn cli_filter_test_pep440_scheme() {
+    for (filter, version, pass) in [
+        ("~=1.4.5, !=1.4.7", "1.4.9", true),
+        ("~=1.4.5, !=1.4.7", "1.4.7", false),
+        ("~=1.4.5", "1.5.0", false),
+        ("==1.4.*", "1.4.2.post1", true),
+        ("!=1.4.*", "1.4.2", false),
+        ("===1.0", "1.0", true),
+        ("===1.0", "1.0.0", false),
+        (">=1.0", "2.0rc1", false),
+        (">=1.0rc1", "2.0rc1", true),
+        ("<2.0", "1!1.0", false),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_FILTER_TEST)
+            .arg("--scheme")
+            .arg("pep440")
+            .arg(filter)
+            .arg(version)
+            .assert();
+        let assert = assert.append_context(COMMAND_FILTER_TEST, format!("{filter} {version}"));
+        if pass {
+            assert.success();
+        } else {
+            assert.code(1);
+        }
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_FILTER_TEST)
+        .arg("--scheme")
+        .arg("pep440")
+        .arg(">=1.0.*")
+        .arg("1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_FILTER_TEST, "wildcard with >=")
+        .code(2);
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_FILTER_TEST)
+        .arg("--scheme")
+        .arg("debian")
+        .arg(">= 1.0")
+        .arg("1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_FILTER_TEST, "--scheme debian")
+        .code(2);
+}
+
+#[test]
+fn cli_filter_test_maven_scheme() {
+    for (filter, version, pass) in [
+        ("[1.0,2.0)", "1.0", true),
+        ("[1.0,2.0)", "2.0", false),
+        ("[1.0,2.0)", "2.0-SNAPSHOT", true),
+        ("(,1.0],[1.2,)", "1.1", false),
+        ("(,1.0],[1.2,)", "1.2-sp1", true),
+        ("[1.5]", "1.5.0", true),
+        // A soft requirement, which Maven satisfies with any version.
+        ("1.5", "3.0", true),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_FILTER_TEST)
+            .arg("--scheme")
+            .arg("maven")
+            .arg(filter)
+            .arg(version)
+            .assert();
+        let assert = assert.append_context(COMMAND_FILTER_TEST, format!("{filter} {version}"));
+        if pass {
+            assert.success();
+        } else {
+            assert.code(1);
+        }
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_FILTER_TEST)
+        .arg("--scheme")
+        .arg("maven")
+        .arg("[2.0,1.0]")
+        .arg("1.5")
+        .assert();
+    assert
+        .append_context(COMMAND_FILTER_TEST, "backwards range")
+        .code(2);
+}
@@ -0,0 +1,178 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+#[test]
+fn cli_four_part_invalid_input() {
+    for (args, context) in [
+        (vec!["normalize", "1.2.3.4.5"], "five parts"),
+        (vec!["normalize", "v1.2"], "prefix"),
+        (vec!["to-semver", "1.0-rc.01"], "leading zero"),
+        (vec!["to-semver", "--revision", "keep", "1.0"], "bad policy"),
+        (vec!["from-semver", "1.2.3.4"], "not semver"),
+        (vec!["validate", "--profile", "msix", "1.0"], "bad profile"),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg(COMMAND_FOUR_PART).args(args).assert();
+        assert.append_context(COMMAND_FOUR_PART, context).failure();
+    }
+}
+
+#[test]
+fn cli_four_part_validate() {
+    for (profile, version, valid) in [
+        (None, "1.2.3.4", true),
+        (None, "1.2.3.4.5", false),
+        (None, "1.0.0-RC.1+abc", true),
+        (Some("msi"), "255.255.65535.65535", true),
+        (Some("msi"), "256.0.0", false),
+        (Some("msi"), "1.0.65536", false),
+        (Some("msi"), "1.0.0-rc", false),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_FOUR_PART)
+            .arg("validate")
+            .args(profile.map(|profile| format!("--profile={profile}")))
+            .arg(version)
+            .assert();
+        let assert = assert.append_context(COMMAND_FOUR_PART, version);
+        match valid {
+            true => assert.success(),
+            false => assert.failure(),
+        };
+    }
+}
+
+#[test]
+fn cli_four_part_normalize() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_FOUR_PART)
+        .arg("normalize")
+        .write_stdin("1.01.0.0-RC.1+abc\n1\n1.2.3.4\n")
+        .assert();
+    assert
+        .append_context(COMMAND_FOUR_PART, "normalize")
+        .success()
+        .stdout("1.1.0-RC.1\n1.0.0\n1.2.3.4\n");
+}
+
+#[test]
+fn cli_four_part_to_semver() {
+    for (revision, versions, success, stdout) in [
+        ("reject", vec!["1.2", "1.2.3.0-rc.1"], true, "1.2.0\n1.2.3-rc.1\n"),
+        (
+            "reject",
+            vec!["1.2.3.4"],
+            false,
+            "# no conversion of 1.2.3.4 to semver\n\
+             # 1.2.3.4: the revision `4` has no Semantic Versioning equivalent\n",
+        ),
+        (
+            "drop",
+            vec!["1.2.3.4"],
+            false,
+            "1.2.3\n# 1.2.3.4: the revision `4` was dropped\n",
+        ),
+        ("build", vec!["1.2.3.4+abc"], true, "1.2.3+abc.revision.4\n"),
+        (
+            "build",
+            vec!["1.2.3.5", "1.2.3.4"],
+            false,
+            "1.2.3+revision.5\n\
+             1.2.3+revision.4\n\
+             # order not preserved: `1.2.3.4` < `1.2.3.5`, but `1.2.3+revision.4` = `1.2.3+revision.5` in semver\n",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-o")
+            .arg("text")
+            .arg(COMMAND_FOUR_PART)
+            .arg("to-semver")
+            .arg("--revision")
+            .arg(revision)
+            .args(&versions)
+            .assert();
+        let assert = assert.append_context(COMMAND_FOUR_PART, revision);
+        match success {
+            true => assert.success(),
+            false => assert.failure(),
+        }
+        .stdout(stdout);
+    }
+}
+
+#[test]
+fn cli_four_part_from_semver() {
+    for (args, success, stdout) in [
+        (vec!["1.2.3", "1.2.3-rc.1"], true, "1.2.3.0\n1.2.3.0-rc.1\n"),
+        (
+            vec!["--revision", "build", "1.2.3+abc.revision.4"],
+            true,
+            "1.2.3.4+abc\n",
+        ),
+        (
+            vec!["--profile", "msi", "0.1.7", "256.0.0"],
+            false,
+            "0.1.7.0\n\
+             # no conversion of 256.0.0 to nuget\n\
+             # 256.0.0: major is greater than 255, the most Windows Installer allows\n",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-o")
+            .arg("text")
+            .arg(COMMAND_FOUR_PART)
+            .arg("from-semver")
+            .args(&args)
+            .assert();
+        let assert = assert.append_context(COMMAND_FOUR_PART, args.join(" "));
+        match success {
+            true => assert.success(),
+            false => assert.failure(),
+        }
+        .stdout(stdout);
+    }
+}
+
+#[test]
+fn cli_four_part_scheme() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg("--scheme")
+        .arg("nuget")
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("1.0.0.1")
+        .arg("1.0.0-rc.2")
+        .arg("1.0")
+        .arg("1.0.0-rc.10")
+        .arg("0.9")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme nuget")
+        .success()
+        .stdout("0.9\n1.0.0-rc.2\n1.0.0-rc.10\n1.0\n1.0.0.1\n");
+}
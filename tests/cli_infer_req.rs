@@ -0,0 +1,80 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+#[test]
+fn cli_infer_req_invalid_input() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg(COMMAND_INFER_REQ).arg("a.b.c").assert();
+    assert
+        .append_context(COMMAND_INFER_REQ, "1 bad semver args")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_INFER_REQ)
+        .arg("-x")
+        .arg("a.b.c")
+        .arg("1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_INFER_REQ, "1 bad semver exclude")
+        .failure();
+}
+
+#[test]
+fn cli_infer_req_basic_cases() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_INFER_REQ)
+        .arg("1.2.3")
+        .arg("1.4.0")
+        .assert();
+    assert
+        .append_context(COMMAND_INFER_REQ, "2 items")
+        .success()
+        .stdout("^1.2.3\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_INFER_REQ)
+        .write_stdin("1.2.3\n1.4.0\n")
+        .arg("--exclude")
+        .arg("1.5.0")
+        .assert();
+    assert
+        .append_context(COMMAND_INFER_REQ, "2 items from stdin, 1 excluded")
+        .success()
+        .stdout(">=1.2.3, <=1.4.0\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_INFER_REQ)
+        .arg("--style")
+        .arg("tilde")
+        .arg("1.2.3")
+        .arg("1.4.0")
+        .assert();
+    assert
+        .append_context(COMMAND_INFER_REQ, "2 items, --style tilde")
+        .failure();
+}
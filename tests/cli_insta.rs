@@ -74,6 +74,271 @@ fn cli_insta() {
         vec![COMMAND_COMPARE, "-e", "-s", "1.2.3+0", "1.2.3+1"],
         vec![COMMAND_COMPARE, "-e", "-s", "1.2.2", "1.2.3+1"],
         vec![COMMAND_COMPARE, "-s", "1.2.4+0", "1.2.3+1"],
+        vec![COMMAND_FILTER_TEST, "-d", "terraform", "~> 1.2", "1.9.0"],
+        vec![COMMAND_FILTER_TEST, "~> 1.2", "1.9.0"],
+        vec![
+            COMMAND_SORT,
+            "-d",
+            "terraform",
+            "-f",
+            "~> 0.1",
+            "0.1.2",
+            "0.2.0",
+        ],
+        vec![
+            COMMAND_RANGE,
+            "translate",
+            "--from",
+            "terraform",
+            "~> 1.2, != 1.4.1",
+        ],
+        vec![
+            COMMAND_RANGE,
+            "translate",
+            "--from",
+            "cargo",
+            "--to",
+            "npm",
+            "^1.2.3",
+        ],
+        vec![
+            COMMAND_RANGE,
+            "translate",
+            "--from",
+            "cargo",
+            "--to",
+            "maven",
+            "^1.2.3",
+        ],
+        vec![
+            COMMAND_RANGE,
+            "translate",
+            "--from",
+            "cargo",
+            "--to",
+            "pep440",
+            "~1.2.3-rc.1",
+        ],
+        vec![
+            COMMAND_RANGE,
+            "translate",
+            "--from",
+            "cargo",
+            "--to",
+            "go",
+            "^1.2.3",
+        ],
+        vec![COMMAND_INFER_REQ, "1.2.3", "1.4.0"],
+        vec![COMMAND_INFER_REQ, "-x", "1.5.0", "1.2.3", "1.4.0"],
+        vec![
+            COMMAND_RELEASE_IMPACT,
+            "-l",
+            "1.4.2",
+            "-c",
+            "2.0.0",
+            "example-data/dependent-requirements.txt",
+        ],
+        vec![
+            COMMAND_KEY,
+            "1.0.0-alpha.1",
+            "18446744073709551616.0.0+build",
+        ],
+        vec![COMMAND_DECODE_KEY, "A11.A10.A10-:alpha!.A11!"],
+        vec![COMMAND_SORT, "--with-key", "10.0.0", "9.0.0", "1.0.0-rc.1"],
+        vec![COMMAND_EXTRACT, "example-data/extract-sample.txt"],
+        vec![
+            COMMAND_EXTRACT,
+            "--flatten",
+            "example-data/extract-sample.txt",
+        ],
+        vec![COMMAND_SORT, "--field", "2", "--delimiter", ",", "0.1.0"],
+        vec![
+            COMMAND_SORT,
+            "--check",
+            "1.0.0",
+            "1.2.0",
+            "1.1.0",
+            "1.1.0",
+            "1.3.0+a",
+            "1.3.0+b",
+        ],
+        vec![COMMAND_SORT, "--check", "-r", "1.1.0", "1.0.0"],
+        vec![
+            COMMAND_SORT,
+            "--lexical-sorting",
+            "--merge",
+            "example-data/sorted-versions-a.txt",
+            "example-data/sorted-versions-b.txt",
+        ],
+        vec![
+            COMMAND_SORT,
+            "--unique=precedence",
+            "--pick",
+            "max",
+            "1.0.0+b",
+            "0.1.0",
+            "1.0.0+a",
+            "0.1.0",
+        ],
+        vec![
+            COMMAND_SORT,
+            "--lexical-sorting",
+            "--stable-only",
+            "--latest-per",
+            "minor",
+            "--limit",
+            "2",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "1.1.1-rc.1",
+            "2.0.0+a",
+            "2.0.0+b",
+        ],
+        vec![
+            COMMAND_SORT,
+            "--tree",
+            "--lexical-sorting",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1+a",
+            "1.0.1+b",
+            "1.1.0",
+            "2.0.0-beta.1",
+        ],
+        vec![COMMAND_EXPLAIN, "--scheme", "debian", "1:2.30~rc1-1ubuntu1"],
+        vec![
+            COMMAND_SORT,
+            "--scheme",
+            "debian",
+            "--lexical-sorting",
+            "1.0",
+            "1.0~rc1",
+            "0:1.0",
+            "1:0.1",
+            "1.0+b1",
+            "1.0-1",
+        ],
+        vec![COMMAND_EXPLAIN, "--scheme", "rpm", "1:1.0~rc1^git2-3.el9_2"],
+        vec![
+            COMMAND_SORT,
+            "--scheme",
+            "rpm",
+            "--lexical-sorting",
+            "1.0",
+            "1.0~rc1",
+            "1.0^git1",
+            "1.0a",
+            "1.0.1",
+            "1_0",
+            "1:0.1",
+            "1.0-1",
+        ],
+        vec![
+            "explain",
+            "--scheme",
+            "pep440",
+            "1!2.0.0.1b2.post3.dev4+ubuntu-22.04",
+        ],
+        vec![
+            "sort",
+            "--scheme",
+            "pep440",
+            "--lexical-sorting",
+            "1.0",
+            "1.0.0",
+            "1.0a1",
+            "1.0.post1",
+            "1.0.dev1",
+            "1.0+local",
+            "1!0.1",
+        ],
+        vec![
+            "sort",
+            "--scheme",
+            "maven",
+            "--lexical-sorting",
+            "1.0",
+            "1.0.0",
+            "1-SNAPSHOT",
+            "1.0-alpha-1",
+            "1.0a2",
+            "1.0-M1",
+            "1.0-cr1",
+            "1.0-sp1",
+            "1.0-xyz",
+            "1.0.1",
+        ],
+        vec![
+            "explain",
+            "--scheme",
+            "go",
+            "v1.2.3-pre.0.20191109021931-daa7c04131f5",
+        ],
+        vec![
+            "sort",
+            "--scheme",
+            "go",
+            "--lexical-sorting",
+            "v1.2.4",
+            "v2",
+            "v2.0.0+incompatible",
+            "v1.2.4-0.20191109021931-daa7c04131f5",
+            "v1.2.3",
+            "v0.0.0-20191109021931-daa7c04131f5",
+        ],
+        vec![
+            "sort",
+            "--scheme",
+            "k8s-api",
+            "--reverse",
+            "v1alpha1",
+            "v1beta1",
+            "v1",
+            "v2beta1",
+            "v12alpha1",
+            "v10",
+            "foo10",
+            "foo1",
+        ],
+        vec![
+            "--scheme",
+            "calver",
+            "--format",
+            "YYYY.0M.0D",
+            "explain",
+            "2024.02.29",
+        ],
+        vec![
+            "--scheme",
+            "calver",
+            "--format",
+            "YY.0M.MICRO",
+            "sort",
+            "24.10.0",
+            "24.02.10",
+            "106.01.0",
+            "24.02.9",
+        ],
+        vec![
+            "convert",
+            "--to",
+            "rpm",
+            "1.0.0-alpha",
+            "1.0.0-1",
+            "1.0.0-rc.1",
+            "1.0.0+build.5",
+        ],
+        vec!["--scheme", "nuget", "explain", "1.02.3.4-RC.1+abc"],
+        vec![
+            "four-part",
+            "to-semver",
+            "--revision",
+            "build",
+            "1.2.3.4",
+            "1.2.3.5-beta",
+            "1.2.3",
+        ],
         // NOTE(canardleteer): For now, the `generate` command is omitted.
     ];
 
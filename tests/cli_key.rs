@@ -0,0 +1,89 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+#[test]
+fn cli_key_invalid_input() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg(COMMAND_KEY).arg("a.b.c").assert();
+    assert
+        .append_context(COMMAND_KEY, "1 bad semver arg")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg(COMMAND_KEY).write_stdin("1.2.3\na.b.c\n").assert();
+    assert
+        .append_context(COMMAND_KEY, "1 bad semver from stdin")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg(COMMAND_DECODE_KEY).arg("1.2.3").assert();
+    assert
+        .append_context(COMMAND_DECODE_KEY, "1 bad key arg")
+        .failure();
+}
+
+#[test]
+fn cli_key_round_trip() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_KEY)
+        .arg("1.2.3+build")
+        .arg("99999999999999999999999.0.0-rc.1")
+        .assert();
+    assert
+        .append_context(COMMAND_KEY, "2 items, 1 beyond u64")
+        .success()
+        .stdout("A11.A12.A13~\nB2399999999999999999999999.A10.A10-:rc!.A11!\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_DECODE_KEY)
+        .write_stdin("A11.A12.A13~\nB2399999999999999999999999.A10.A10-:rc!.A11!\n")
+        .assert();
+    assert
+        .append_context(COMMAND_DECODE_KEY, "2 items from stdin")
+        .success()
+        .stdout("1.2.3\n99999999999999999999999.0.0-rc.1\n");
+}
+
+#[test]
+fn cli_sort_with_key() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--with-key")
+        .arg("1.0.0")
+        .arg("1.0.0-beta.11")
+        .arg("1.0.0-beta.2")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "3 items, --with-key")
+        .success()
+        .stdout(
+            "A11.A10.A10-:beta!.A12!\t1.0.0-beta.2\n\
+             A11.A10.A10-:beta!.A211!\t1.0.0-beta.11\n\
+             A11.A10.A10~\t1.0.0\n",
+        );
+}
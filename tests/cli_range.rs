@@ -0,0 +1,223 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+#[test]
+fn cli_range_translate_invalid_input() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RANGE)
+        .arg("translate")
+        .arg("~> 1.2")
+        .assert();
+    assert
+        .append_context(COMMAND_RANGE, "missing --from")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RANGE)
+        .arg("translate")
+        .arg("--from")
+        .arg("cargo")
+        .arg("~> 1.2")
+        .assert();
+    assert
+        .append_context(COMMAND_RANGE, "terraform requirement as cargo")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RANGE)
+        .arg("translate")
+        .arg("--from")
+        .arg("cargo")
+        .arg("--to")
+        .arg("conda")
+        .arg("^1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_RANGE, "unknown target")
+        .failure();
+}
+
+#[test]
+fn cli_range_translate_basic_cases() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_RANGE)
+        .arg("translate")
+        .arg("--from")
+        .arg("terraform")
+        .arg("~> 1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_RANGE, "~> 1.2.3")
+        .success()
+        .stdout(">=1.2.3, <1.3.0\n");
+
+    // Lossy translations exit non-zero.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RANGE)
+        .arg("translate")
+        .arg("--from")
+        .arg("terraform")
+        .arg(">= 1.0, != 1.4.1")
+        .assert();
+    assert
+        .append_context(COMMAND_RANGE, ">= 1.0, != 1.4.1")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_RANGE)
+        .arg("translate")
+        .arg("--from")
+        .arg("cargo")
+        .arg("--to")
+        .arg("npm")
+        .arg("^1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_RANGE, "^1.2.3 to npm")
+        .success()
+        .stdout(">=1.2.3 <2.0.0\n");
+
+    // Maven includes pre-releases inside of ranges, so this is lossy.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RANGE)
+        .arg("translate")
+        .arg("--from")
+        .arg("cargo")
+        .arg("--to")
+        .arg("maven")
+        .arg("^1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_RANGE, "^1.2.3 to maven")
+        .failure();
+}
+
+#[test]
+fn cli_range_translate_maven_exclusions() {
+    for (requirement, maven) in [
+        (
+            ">= 1.0, != 1.5.0, != 1.2.0, < 2",
+            "[1.0.0,1.2.0),(1.2.0,1.5.0),(1.5.0,2.0.0)\n\
+             # Maven ranges include qualifier (pre-release) versions inside of the interval, such as `2.0.0-rc.1` in `[1.0.0,2.0.0)`, which Cargo excludes\n",
+        ),
+        (
+            ">= 1.0, != 3.0.0, < 2",
+            "[1.0.0,2.0.0)\n\
+             # `!=3.0.0` is outside of the interval, and was dropped\n\
+             # Maven ranges include qualifier (pre-release) versions inside of the interval, such as `2.0.0-rc.1` in `[1.0.0,2.0.0)`, which Cargo excludes\n",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-o")
+            .arg("text")
+            .arg(COMMAND_RANGE)
+            .arg("translate")
+            .arg("--from")
+            .arg("terraform")
+            .arg("--to")
+            .arg("maven")
+            .arg(requirement)
+            .assert();
+        // Maven includes pre-releases inside of ranges, so these are lossy.
+        assert
+            .append_context(COMMAND_RANGE, requirement)
+            .failure()
+            .stdout(maven);
+    }
+}
+
+#[test]
+fn cli_range_translate_terraform_prereleases() {
+    // Terraform's `< 2` rejects every pre-release, while Cargo and npm accept
+    // pre-releases of 1.2.3, since `>=1.2.3-beta` names one.
+    for to in ["cargo", "npm"] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_RANGE)
+            .arg("translate")
+            .arg("--from")
+            .arg("terraform")
+            .arg("--to")
+            .arg(to)
+            .arg(">= 1.2.3-beta, < 2")
+            .assert();
+        assert.append_context(COMMAND_RANGE, to).failure();
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_FILTER_TEST)
+        .arg("--dialect")
+        .arg("terraform")
+        .arg(">= 1.2.3-beta, < 2")
+        .arg("1.2.3-rc")
+        .assert();
+    assert
+        .append_context(COMMAND_FILTER_TEST, "1.2.3-rc")
+        .failure();
+}
+
+#[test]
+fn cli_range_translate_maven_exact() {
+    for (requirement, maven, exact) in [
+        (
+            "=1.2.3, >1.0",
+            "[1.2.3]\n\
+             # only `=1.2.3` was kept, other comparators are redundant\n",
+            true,
+        ),
+        (
+            "=1.2.3, >2",
+            "no translation from cargo to maven\n\
+             # `=1.2.3` does not satisfy `>=3.0.0`, so nothing matches, which Maven ranges can't express\n",
+            false,
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-o")
+            .arg("text")
+            .arg(COMMAND_RANGE)
+            .arg("translate")
+            .arg("--from")
+            .arg("cargo")
+            .arg("--to")
+            .arg("maven")
+            .arg(requirement)
+            .assert()
+            .append_context(COMMAND_RANGE, requirement);
+        match exact {
+            true => assert.success(),
+            false => assert.failure(),
+        }
+        .stdout(maven);
+    }
+}
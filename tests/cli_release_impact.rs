@@ -0,0 +1,106 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License");
+//! you may not use this file except in compliance with the License.
+//! You may obtain a copy of the License at
+//!
+//! http://www.apache.org/licenses/LICENSE-2.0
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS,
+//! WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//! See the License for the specific language governing permissions and
+//! limitations under the License.
+use assert_cmd::Command;
+
+mod common;
+use common::subcommands::*;
+
+const REQUIREMENTS: &str = "example-data/dependent-requirements.txt";
+
+#[test]
+fn cli_release_impact_invalid_input() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RELEASE_IMPACT)
+        .arg("-l")
+        .arg("1.4.2")
+        .arg(REQUIREMENTS)
+        .assert();
+    assert
+        .append_context(COMMAND_RELEASE_IMPACT, "missing candidate")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RELEASE_IMPACT)
+        .arg("-l")
+        .arg("1.4.2")
+        .arg("-c")
+        .arg("2.0.0")
+        .write_stdin("app-a: ^1.4\napp-b: ~> 1.4\n")
+        .assert();
+    assert
+        .append_context(COMMAND_RELEASE_IMPACT, "bad requirement")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RELEASE_IMPACT)
+        .arg("-l")
+        .arg("1.4.2")
+        .arg("-c")
+        .arg("2.0.0")
+        .arg("example-data/does-not-exist.txt")
+        .assert();
+    assert
+        .append_context(COMMAND_RELEASE_IMPACT, "missing file")
+        .failure();
+}
+
+#[test]
+fn cli_release_impact_basic_cases() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RELEASE_IMPACT)
+        .arg("-l")
+        .arg("1.4.2")
+        .arg("-c")
+        .arg("2.0.0")
+        .arg(REQUIREMENTS)
+        .assert();
+    assert
+        .append_context(COMMAND_RELEASE_IMPACT, "from file")
+        .success();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RELEASE_IMPACT)
+        .arg("-l")
+        .arg("1.4.2")
+        .arg("-c")
+        .arg("2.0.0")
+        .arg("--fail-if-pinned-out")
+        .arg(REQUIREMENTS)
+        .assert();
+    assert
+        .append_context(COMMAND_RELEASE_IMPACT, "--fail-if-pinned-out")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_RELEASE_IMPACT)
+        .arg("-l")
+        .arg("1.4.2")
+        .arg("-c")
+        .arg("1.4.3")
+        .arg("--fail-if-pinned-out")
+        .arg("-d")
+        .arg("terraform")
+        .write_stdin("app-a: ~> 1.4\n# comment\n\napp-b: >= 1.0\n")
+        .assert();
+    assert
+        .append_context(COMMAND_RELEASE_IMPACT, "terraform from stdin")
+        .success();
+}
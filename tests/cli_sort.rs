@@ -152,3 +152,793 @@ fn cli_sort_basic_cases() {
         .append_context(COMMAND_SORT, "2 items, --lexical-sorting, --flatten")
         .success();
 }
+
+#[test]
+fn cli_sort_records() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("--field")
+        .arg("2")
+        .arg("--delimiter")
+        .arg(",")
+        .write_stdin("b,1.10.0,x\na,1.9.0,y\n\nc,1.9.0-rc.1,z\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "3 records, --field 2 --delimiter ,")
+        .success()
+        .stdout("c,1.9.0-rc.1,z\na,1.9.0,y\nb,1.10.0,x\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("-r")
+        .arg("--key-regex")
+        .arg(r"myapp-(\d+\.\d+\.\d+(?:-rc\.\d+)?)-linux")
+        .write_stdin("myapp-1.2.3-linux.tar.gz 1\nmyapp-1.10.0-linux.tar.gz 2\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "2 records, --key-regex")
+        .success()
+        .stdout("myapp-1.10.0-linux.tar.gz 2\nmyapp-1.2.3-linux.tar.gz 1\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--field")
+        .arg("2")
+        .write_stdin("a 1.2.3\nb\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "record without a field")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--field")
+        .arg("2")
+        .arg("1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--field with versions")
+        .failure();
+}
+
+#[test]
+fn cli_sort_document() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("--path")
+        .arg(".releases[].version")
+        .write_stdin(r#"{"releases": [{"name": "b", "version": "1.10.0"}, {"name": "a", "version": "1.9.0"}]}"#)
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "JSON document, --path")
+        .success()
+        .stdout(
+            r#"{"versions":[{"name":"a","version":"1.9.0"},{"name":"b","version":"1.10.0"}],"potentially_ambiguous":false}
+"#,
+        );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("yaml")
+        .arg(COMMAND_SORT)
+        .arg("--lexical-sorting")
+        .arg("--path")
+        .arg(".[].version")
+        .write_stdin("- version: 1.0.0+b\n- version: 1.0.0+a\n  name: a\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "YAML document, --path")
+        .success()
+        .stdout(
+            "---\nversions:\n  1.0.0:\n  - version: 1.0.0+a\n    name: a\n  - version: 1.0.0+b\npotentially_ambiguous: true\n",
+        );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--path")
+        .arg(".releases[].version")
+        .write_stdin(r#"{"releases": [{"version": 1}]}"#)
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "version is not a string")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--path")
+        .arg(".releases.version")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "path without []")
+        .failure();
+}
+
+#[test]
+fn cli_sort_check() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--check")
+        .arg("0.1.0")
+        .arg("0.1.1")
+        .arg("1.0.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "3 sorted items, --check")
+        .success();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--check")
+        .write_stdin("0.1.0\n1.0.0\n0.1.1\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "3 unsorted items from stdin, --check")
+        .failure()
+        .stdout("sorted: false\nout of order: line 2: 1.0.0, line 3: 0.1.1 (Semantically: Greater, Lexically: Greater)\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--check")
+        .arg("--reverse")
+        .arg("1.0.0")
+        .arg("0.1.1")
+        .arg("0.1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "3 reverse sorted items, --check --reverse")
+        .success();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--check")
+        .arg("0.1.0")
+        .arg("0.1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "duplicate items, --check")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--check")
+        .arg("0.1.0+a")
+        .arg("0.1.0+b")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "ambiguous items, --check")
+        .failure();
+}
+
+#[test]
+fn cli_sort_merge() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("--lexical-sorting")
+        .arg("--merge")
+        .arg("example-data/sorted-versions-a.txt")
+        .arg("example-data/sorted-versions-b.txt")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "2 sorted files, --merge")
+        .success()
+        .stdout(
+            "0.1.0\n0.1.1\n0.2.0\n1.0.0-rc.1\n1.0.0+build.1\n1.0.0+build.2\n1.1.0\n1.2.0\n2.0.0\n",
+        );
+
+    // Merging must give the same output as sorting everything at once.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let sorted = cmd
+        .arg(COMMAND_SORT)
+        .arg("--lexical-sorting")
+        .write_stdin(
+            std::fs::read_to_string("example-data/sorted-versions-a.txt").unwrap()
+                + &std::fs::read_to_string("example-data/sorted-versions-b.txt").unwrap(),
+        )
+        .assert()
+        .success();
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--lexical-sorting")
+        .arg("--merge")
+        .arg("example-data/sorted-versions-a.txt")
+        .arg("-")
+        .write_stdin(std::fs::read_to_string("example-data/sorted-versions-b.txt").unwrap())
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "a sorted file and stdin, --merge")
+        .success()
+        .stdout(sorted.get_output().stdout.clone());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--merge")
+        .arg("example-data/sorted-versions-a.txt")
+        .arg("-")
+        .write_stdin("1.0.0\n0.1.0\n")
+        .assert();
+    let stderr = assert
+        .append_context(COMMAND_SORT, "an unsorted input, --merge")
+        .failure()
+        .get_output()
+        .stderr
+        .clone();
+    assert!(String::from_utf8(stderr)
+        .unwrap()
+        .starts_with("unable to merge: -: line 2: 0.1.0 is out of order, following 1.0.0\n"));
+
+    // Stdin can only be read once.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--merge")
+        .arg("-")
+        .arg("-")
+        .write_stdin("0.1.0\n1.0.0\n")
+        .timeout(std::time::Duration::from_secs(10))
+        .assert();
+    let stderr = assert
+        .append_context(COMMAND_SORT, "stdin twice, --merge")
+        .code(2)
+        .get_output()
+        .stderr
+        .clone();
+    assert!(String::from_utf8(stderr)
+        .unwrap()
+        .contains("can only read stdin ('-') once"));
+}
+
+#[test]
+fn cli_sort_buffer_size() {
+    let versions = std::fs::read_to_string("example-data/misc-good-versions.txt").unwrap();
+    for flatten in [false, true] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let mut args = vec![COMMAND_SORT, "--lexical-sorting", "--reverse"];
+        if flatten {
+            args.push("--flatten");
+        }
+        let sorted = cmd
+            .args(&args)
+            .write_stdin(versions.clone())
+            .assert()
+            .success();
+
+        // A tiny budget spills (nearly) every version to its own run.
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .args(&args)
+            .arg("--buffer-size")
+            .arg("1")
+            .write_stdin(versions.clone())
+            .assert();
+        assert
+            .append_context(COMMAND_SORT, "spilling to disk, --buffer-size")
+            .success()
+            .stdout(sorted.get_output().stdout.clone());
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--buffer-size")
+        .arg("1")
+        .arg("--fail-if-potentially-ambiguous")
+        .write_stdin("0.1.0+a\n0.1.0+b\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "ambiguous, --buffer-size")
+        .failure();
+
+    // More runs than are merged at once, so they are merged in passes.
+    let versions: String = (0..500)
+        .map(|i| format!("{}.{}.0-rc.{}\n", i % 7, i % 50, i))
+        .collect();
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let sorted = cmd
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .write_stdin(versions.clone())
+        .assert()
+        .success();
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("-S")
+        .arg("1")
+        .write_stdin(versions)
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "many runs, --buffer-size")
+        .success()
+        .stdout(sorted.get_output().stdout.clone());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg(COMMAND_SORT).arg("-S").arg("1X").assert();
+    assert
+        .append_context(COMMAND_SORT, "invalid --buffer-size")
+        .failure();
+}
+
+#[test]
+fn cli_sort_invalid_line_number() {
+    // Every way of reading stdin numbers lines from 1.
+    for args in [
+        vec![COMMAND_SORT],
+        vec!["--threads", "2", COMMAND_SORT],
+        vec![COMMAND_SORT, "--buffer-size", "1M"],
+        vec![COMMAND_SORT, "--merge", "-"],
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.args(&args).write_stdin("1.0.0\nbad\n").assert();
+        let stderr = assert
+            .append_context(COMMAND_SORT, format!("invalid version, {args:?}"))
+            .failure()
+            .get_output()
+            .stderr
+            .clone();
+        assert!(
+            String::from_utf8(stderr).unwrap().contains(
+                "line 2: bad: unexpected character 'b' while parsing major version number"
+            ),
+            "{args:?}"
+        );
+    }
+}
+
+#[test]
+fn cli_sort_threads() {
+    let versions = std::fs::read_to_string("example-data/misc-good-versions.txt").unwrap();
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let sorted = cmd
+        .arg(COMMAND_SORT)
+        .arg("--lexical-sorting")
+        .write_stdin(versions.clone())
+        .assert()
+        .success();
+
+    for threads in ["2", "3", "64"] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("--threads")
+            .arg(threads)
+            .arg(COMMAND_SORT)
+            .arg("--lexical-sorting")
+            .write_stdin(versions.clone())
+            .assert();
+        assert
+            .append_context(COMMAND_SORT, "parallel, --threads")
+            .success()
+            .stdout(sorted.get_output().stdout.clone());
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-j")
+        .arg("4")
+        .arg(COMMAND_SORT)
+        .write_stdin("1.0.0\n0.1.0\nnot-a-version\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "parallel, invalid version, --threads")
+        .failure()
+        .stderr(
+            "unable to parse an enumerated version: line 3: not-a-version: unexpected character 'n' while parsing major version number\nError: Error(\"unexpected character 'n' while parsing major version number\")\n",
+        );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg("-j").arg("0").arg(COMMAND_SORT).assert();
+    assert
+        .append_context(COMMAND_SORT, "zero threads, --threads")
+        .failure();
+}
+
+#[test]
+fn cli_sort_unique() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--unique")
+        .arg("--lexical-sorting")
+        .write_stdin("1.0.0\n0.1.0\n1.0.0\n1.0.0+b\n1.0.0+a\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "duplicates from stdin, --unique")
+        .success()
+        .stdout("0.1.0\n1.0.0\n1.0.0+a\n1.0.0+b\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--unique=precedence")
+        .arg("--pick")
+        .arg("min")
+        .arg("--count")
+        .write_stdin("1.0.0\n0.1.0\n1.0.0\n1.0.0+b\n1.0.0+a\n")
+        .assert();
+    assert
+        .append_context(
+            COMMAND_SORT,
+            "duplicates from stdin, --unique=precedence --count",
+        )
+        .success()
+        .stdout("      1 0.1.0\n      4 1.0.0\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_SORT)
+        .arg("--count")
+        .arg("1.0.0")
+        .arg("1.0.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "duplicate arguments, --count")
+        .success()
+        .stdout("{\"versions\":[{\"version\":\"1.0.0\",\"count\":2}],\"collapsed\":1,\"potentially_ambiguous\":false}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--unique=precedence")
+        .arg("--pick")
+        .arg("fail")
+        .arg("1.0.0+a")
+        .arg("1.0.0+b")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "differing duplicates, --pick fail")
+        .failure();
+}
+
+#[test]
+fn cli_sort_selection() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("--latest-per")
+        .arg("minor")
+        .write_stdin("1.0.0\n1.0.1\n1.1.0\n1.1.1-rc.1\n2.0.0\n")
+        .assert();
+    assert
+        .append_context(
+            COMMAND_SORT,
+            "latest patch of each minor, --latest-per minor",
+        )
+        .success()
+        .stdout("1.0.1\n1.1.1-rc.1\n2.0.0\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--flatten")
+        .arg("--reverse")
+        .arg("--stable-only")
+        .arg("--latest-per")
+        .arg("major")
+        .arg("--limit")
+        .arg("2")
+        .write_stdin("1.0.0\n1.0.1\n2.1.0\n2.0.0\n3.0.0-rc.1\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "newest stable of the latest 2 majors")
+        .success()
+        .stdout("2.1.0\n1.0.1\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--limit")
+        .arg("1")
+        .arg("--unique")
+        .arg("1.0.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--limit conflicts with --unique")
+        .failure();
+}
+
+#[test]
+fn cli_sort_tree() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_SORT)
+        .arg("--tree")
+        .arg("--stable-only")
+        .arg("1.0.0")
+        .arg("1.1.0-rc.1")
+        .arg("2.0.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--tree --stable-only")
+        .success()
+        .stdout("{\"count\":2,\"latest_stable\":\"2.0.0\",\"majors\":{\"1\":{\"count\":1,\"latest_stable\":\"1.0.0\",\"minors\":{\"0\":{\"count\":1,\"latest_stable\":\"1.0.0\",\"patches\":{\"0\":{\"count\":1,\"latest_stable\":\"1.0.0\",\"variants\":[\"1.0.0\"]}}}}},\"2\":{\"count\":1,\"latest_stable\":\"2.0.0\",\"minors\":{\"0\":{\"count\":1,\"latest_stable\":\"2.0.0\",\"patches\":{\"0\":{\"count\":1,\"latest_stable\":\"2.0.0\",\"variants\":[\"2.0.0\"]}}}}}},\"potentially_ambiguous\":false}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--tree")
+        .arg("--flatten")
+        .arg("1.0.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--tree conflicts with --flatten")
+        .failure();
+}
+
+#[test]
+fn cli_sort_debian() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("--lexical-sorting")
+        .write_stdin("1:0.9\n1.0\n1.0~rc1\n1.00-0\n1.0+b1\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme debian")
+        .success()
+        .stdout("{\"versions\":{\"1.0~rc1\":[\"1.0~rc1\"],\"1.0\":[\"1.0\",\"1.00-0\"],\"1.0+b1\":[\"1.0+b1\"],\"1:0.9\":[\"1:0.9\"]},\"potentially_ambiguous\":true}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("--reverse")
+        .arg("--flatten")
+        .arg("2.0-1")
+        .arg("2.0~beta")
+        .arg("2.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme debian --reverse --flatten")
+        .success()
+        .stdout("2.0-1\n2.0\n2.0~beta\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("--fail-if-potentially-ambiguous")
+        .arg("1.0")
+        .arg("0:1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme debian ambiguity")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("1.0")
+        .arg("1.0-")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme debian invalid version")
+        .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("debian")
+        .arg("--tree")
+        .arg("1.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme debian with a semver only option")
+        .failure();
+}
+
+#[test]
+fn cli_sort_rpm() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("rpm")
+        .arg("--flatten")
+        .write_stdin("1.0.1\n1.0^git1\n1:0.1\n1.0-1\n1.0~rc1\n1.0a\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme rpm")
+        .success()
+        .stdout("1.0~rc1\n1.0-1\n1.0^git1\n1.0a\n1.0.1\n1:0.1\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("rpm")
+        .arg("--lexical-sorting")
+        .arg("--reverse")
+        .arg("1.0")
+        .arg("1_0")
+        .arg("0:1.0")
+        .arg("2.0")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme rpm --reverse")
+        .success()
+        .stdout("{\"versions\":{\"2.0\":[\"2.0\"],\"0:1.0\":[\"1_0\",\"1.0\",\"0:1.0\"]},\"potentially_ambiguous\":true}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("rpm")
+        .arg("1.0-1-1")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme rpm invalid version")
+        .failure();
+}
+
+#[test]
+fn cli_sort_pep440() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("pep440")
+        .arg("--flatten")
+        .write_stdin("1!0.1\n1.0.post1\n1.0+local\n1.0\n1.0rc1\n1.0a1.dev2\n1.0.dev1\n1.0b1\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme pep440")
+        .success()
+        .stdout("1.0.dev1\n1.0a1.dev2\n1.0b1\n1.0rc1\n1.0\n1.0+local\n1.0.post1\n1!0.1\n");
+}
+
+#[test]
+fn cli_sort_maven() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("maven")
+        .arg("--lexical-sorting")
+        .write_stdin("1.0-sp1\n1.0\n1.0-SNAPSHOT\n1-ga\n1.0-rc1\n1.0-M1\n1.0-beta1\n1.0-alpha-1\n1.0.1\n1.0-foo\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme maven")
+        .success()
+        .stdout("{\"versions\":{\"1.0-alpha-1\":[\"1.0-alpha-1\"],\"1.0-beta1\":[\"1.0-beta1\"],\"1.0-M1\":[\"1.0-M1\"],\"1.0-rc1\":[\"1.0-rc1\"],\"1.0-SNAPSHOT\":[\"1.0-SNAPSHOT\"],\"1-ga\":[\"1-ga\",\"1.0\"],\"1.0-sp1\":[\"1.0-sp1\"],\"1.0-foo\":[\"1.0-foo\"],\"1.0.1\":[\"1.0.1\"]},\"potentially_ambiguous\":true}\n");
+}
+
+#[test]
+fn cli_sort_go() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("go")
+        .arg("--flatten")
+        .write_stdin("v1.2.4\nv2.0.0+incompatible\nv1.2.4-0.20191109021931-daa7c04131f5\nv1.2.3\nv0.0.0-20191109021931-daa7c04131f5\nv1.2.3-pre.0.20191109021931-daa7c04131f5\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme go")
+        .success()
+        .stdout("v0.0.0-20191109021931-daa7c04131f5\nv1.2.3-pre.0.20191109021931-daa7c04131f5\nv1.2.3\nv1.2.4-0.20191109021931-daa7c04131f5\nv1.2.4\nv2.0.0+incompatible\n");
+}
+
+#[test]
+fn cli_sort_k8s_api() {
+    // Kubernetes' priority order, with non-matching strings lexically last.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("text")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("k8s-api")
+        .arg("--reverse")
+        .arg("--flatten")
+        .write_stdin("v1alpha1\nfoo10\nv1beta1\nv1\nv2beta1\nfoo1\nv11alpha2\nv10\n")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme k8s-api --reverse")
+        .success()
+        .stdout("v10\nv1\nv2beta1\nv1beta1\nv11alpha2\nv1alpha1\nfoo1\nfoo10\n");
+
+    // Ascending is the exact reverse, so non-matching strings come first.
+    for (reverse, expected) in [
+        (false, "foo10\nfoo1\nbar\nv1alpha1\nv2beta1\nv1\n"),
+        (true, "v1\nv2beta1\nv1alpha1\nbar\nfoo1\nfoo10\n"),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        cmd.arg("-o")
+            .arg("text")
+            .arg(COMMAND_SORT)
+            .arg("--scheme")
+            .arg("k8s-api")
+            .arg("--flatten");
+        if reverse {
+            cmd.arg("--reverse");
+        }
+        let assert = cmd
+            .args(["v1", "foo10", "v2beta1", "foo1", "bar", "v1alpha1"])
+            .assert();
+        assert
+            .append_context(COMMAND_SORT, format!("--scheme k8s-api reverse={reverse}"))
+            .success()
+            .stdout(expected);
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg("-o")
+        .arg("json")
+        .arg(COMMAND_SORT)
+        .arg("--scheme")
+        .arg("k8s-api")
+        .arg("--lexical-sorting")
+        .arg("v1")
+        .arg("v01")
+        .arg("v1beta1")
+        .assert();
+    assert
+        .append_context(COMMAND_SORT, "--scheme k8s-api")
+        .success()
+        .stdout("{\"versions\":{\"v1beta1\":[\"v1beta1\"],\"v01\":[\"v01\",\"v1\"]},\"potentially_ambiguous\":true}\n");
+}
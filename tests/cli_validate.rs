@@ -24,6 +24,12 @@ fn cli_validate_invalid_input() {
     assert
         .append_context(COMMAND_VALIDATE, "1 bad semver args")
         .failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd.arg(COMMAND_VALIDATE).arg("a1.0.0b").assert();
+    assert
+        .append_context(COMMAND_VALIDATE, "semver within other characters")
+        .failure();
 }
 
 #[test]
@@ -37,3 +43,94 @@ fn cli_validate_basic_cases() {
         .append_context(COMMAND_VALIDATE, "1 valid semver arg")
         .success();
 }
+
+#[test]
+fn cli_validate_debian() {
+    for (version, valid) in [
+        ("1:2.30-1ubuntu1", true),
+        ("1.0~rc1+dfsg", true),
+        ("1.0_1", false),
+        ("1.0-", false),
+        ("a:1.0", false),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_VALIDATE)
+            .arg("--scheme")
+            .arg("debian")
+            .arg(version)
+            .assert();
+        let assert = assert.append_context(COMMAND_VALIDATE, version);
+        match valid {
+            true => assert.success(),
+            false => assert.failure(),
+        };
+    }
+}
+
+#[test]
+fn cli_validate_go() {
+    for (version, valid) in [
+        ("v1.2.3", true),
+        ("v1.2", true),
+        ("v3.0.0+incompatible", true),
+        ("v0.0.0-20191109021931-daa7c04131f5", true),
+        ("1.2.3", false),
+        ("v1.0.0+incompatible", false),
+        ("v1.2.3+build", false),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_VALIDATE)
+            .arg("--scheme")
+            .arg("go")
+            .arg(version)
+            .assert();
+        let assert = assert.append_context(COMMAND_VALIDATE, version);
+        match valid {
+            true => assert.success(),
+            false => assert.failure(),
+        };
+    }
+
+    for (module_path, version, valid) in [
+        ("example.com/mod", "v1.2.3", true),
+        ("example.com/mod", "v2.0.0", false),
+        ("example.com/mod", "v2.0.0+incompatible", true),
+        (
+            "example.com/mod/v2",
+            "v2.0.1-0.20191109021931-daa7c04131f5",
+            true,
+        ),
+        ("example.com/mod/v2", "v3.0.0", false),
+        ("example.com/mod/v2", "v2.0.0+incompatible", false),
+        ("example.com/mod/v1", "v1.0.0", false),
+        ("gopkg.in/yaml.v3", "v3.0.1", true),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg(COMMAND_VALIDATE)
+            .arg("--scheme")
+            .arg("go")
+            .arg("--module-path")
+            .arg(module_path)
+            .arg(version)
+            .assert();
+        let assert = assert.append_context(COMMAND_VALIDATE, format!("{module_path} {version}"));
+        match valid {
+            true => assert.success(),
+            false => assert.code(1),
+        };
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let assert = cmd
+        .arg(COMMAND_VALIDATE)
+        .arg("--module-path")
+        .arg("example.com/mod")
+        .arg("1.2.3")
+        .assert();
+    assert
+        .append_context(COMMAND_VALIDATE, "--module-path without --scheme go")
+        .code(2);
+}
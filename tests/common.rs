@@ -1,16 +1,34 @@
 #[allow(dead_code)]
 pub(crate) mod subcommands {
+    pub(crate) const COMMAND_CALVER: &str = "calver";
     pub(crate) const COMMAND_COMPARE: &str = "compare";
+    pub(crate) const COMMAND_CONVERT: &str = "convert";
+    pub(crate) const COMMAND_DECODE_KEY: &str = "decode-key";
     pub(crate) const COMMAND_EXPLAIN: &str = "explain";
+    pub(crate) const COMMAND_EXTRACT: &str = "extract";
     pub(crate) const COMMAND_FILTER_TEST: &str = "filter-test";
+    pub(crate) const COMMAND_FOUR_PART: &str = "four-part";
     pub(crate) const COMMAND_GENERATE: &str = "generate";
+    pub(crate) const COMMAND_INFER_REQ: &str = "infer-req";
+    pub(crate) const COMMAND_KEY: &str = "key";
+    pub(crate) const COMMAND_RANGE: &str = "range";
+    pub(crate) const COMMAND_RELEASE_IMPACT: &str = "release-impact";
     pub(crate) const COMMAND_SORT: &str = "sort";
     pub(crate) const COMMAND_VALIDATE: &str = "validate";
-    pub(crate) const ALL_COMMANDS: [&str; 6] = [
+    pub(crate) const ALL_COMMANDS: [&str; 15] = [
+        COMMAND_CALVER,
         COMMAND_COMPARE,
+        COMMAND_CONVERT,
+        COMMAND_DECODE_KEY,
         COMMAND_EXPLAIN,
+        COMMAND_EXTRACT,
         COMMAND_FILTER_TEST,
+        COMMAND_FOUR_PART,
         COMMAND_GENERATE,
+        COMMAND_INFER_REQ,
+        COMMAND_KEY,
+        COMMAND_RANGE,
+        COMMAND_RELEASE_IMPACT,
         COMMAND_SORT,
         COMMAND_VALIDATE,
     ];